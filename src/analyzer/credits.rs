@@ -0,0 +1,600 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::path::Path;
+
+use crate::config::ResolvedConfig;
+use crate::graph::ModuleGraph;
+use crate::plugins::PluginResults;
+use crate::resolver::ModuleResolver;
+use crate::{CreditSource, DependencyCredit};
+
+const KNOWN_BINARIES: &[(&str, &str)] = &[
+    ("tsc", "typescript"),
+    ("tsserver", "typescript"),
+    ("ng", "@angular/cli"),
+    ("vue-cli-service", "@vue/cli-service"),
+    ("nuxi", "nuxt"),
+    ("sb", "storybook"),
+    ("run-p", "npm-run-all"),
+    ("run-s", "npm-run-all"),
+    ("tsx", "tsx"),
+    ("swc", "@swc/cli"),
+    ("changeset", "@changesets/cli"),
+    ("playwright", "@playwright/test"),
+];
+
+const NODE_PRELOAD_FLAGS: &[&str] = &[
+    "-r",
+    "--require",
+    "--import",
+    "--loader",
+    "--experimental-loader",
+];
+
+#[derive(Debug, PartialEq, Eq)]
+enum ScriptReference {
+    Binary(String),
+    Package(String),
+}
+
+pub(super) fn collect_dependency_credits(
+    graph: &ModuleGraph,
+    config: &ResolvedConfig,
+    plugins: &PluginResults,
+    resolver: &ModuleResolver,
+) -> Vec<DependencyCredit> {
+    let Some(ref pkg) = config.package_json else {
+        return Vec::new();
+    };
+
+    let listed: HashSet<&str> = pkg
+        .dependencies
+        .keys()
+        .chain(pkg.dev_dependencies.keys())
+        .chain(pkg.peer_dependencies.keys())
+        .chain(pkg.optional_dependencies.keys())
+        .map(|s| s.as_str())
+        .collect();
+
+    let mut credits: BTreeSet<(String, CreditSource)> = BTreeSet::new();
+    let mut credit = |name: &str, source: CreditSource| {
+        if listed.contains(name) {
+            credits.insert((name.to_string(), source));
+        }
+    };
+
+    for (package_name, files) in &graph.external_imports {
+        for path in files {
+            credit(package_name, CreditSource::Import { path: path.clone() });
+        }
+    }
+
+    for (plugin, result) in plugins {
+        if plugin.has_config(&config.root) {
+            for name in &result.dependencies {
+                credit(
+                    name,
+                    CreditSource::Plugin {
+                        plugin: plugin.name().to_string(),
+                    },
+                );
+            }
+        }
+
+        for reference in &result.references {
+            credit(
+                &reference.name,
+                CreditSource::ConfigFile {
+                    path: reference.source.clone(),
+                    field: reference.field.clone(),
+                },
+            );
+        }
+    }
+
+    let binaries = build_binary_map(config, resolver, &listed);
+    let script_names: HashSet<&str> = pkg.scripts.keys().map(|s| s.as_str()).collect();
+
+    let mut scripts: Vec<(String, String)> = pkg
+        .scripts
+        .iter()
+        .map(|(name, script)| (name.clone(), script.clone()))
+        .collect();
+    scripts.extend(read_husky_hooks(&config.root));
+
+    for (script_name, script) in &scripts {
+        for reference in parse_script(script, &script_names) {
+            let (package, binary) = match reference {
+                ScriptReference::Binary(binary) => match binaries.get(&binary) {
+                    Some(package) => (package.clone(), binary),
+                    None => (binary.clone(), binary),
+                },
+                ScriptReference::Package(package) => (package.clone(), package),
+            };
+
+            credit(
+                &package,
+                CreditSource::Script {
+                    script: script_name.clone(),
+                    binary,
+                },
+            );
+        }
+    }
+
+    for tsconfig in config.tsconfigs() {
+        for package in tsconfig
+            .extends
+            .specifiers()
+            .into_iter()
+            .filter_map(ModuleResolver::get_package_name)
+        {
+            credit(
+                package,
+                CreditSource::TsConfig {
+                    field: "extends".to_string(),
+                },
+            );
+        }
+
+        for entry in &tsconfig.compiler_options.types {
+            let Some(package) = ModuleResolver::get_package_name(entry) else {
+                continue;
+            };
+            let types_package = ModuleResolver::get_types_package_name(package);
+            for name in [package.to_string(), types_package] {
+                credit(
+                    &name,
+                    CreditSource::TsConfig {
+                        field: "types".to_string(),
+                    },
+                );
+            }
+        }
+
+        if tsconfig.compiler_options.import_helpers {
+            credit(
+                "tslib",
+                CreditSource::TsConfig {
+                    field: "importHelpers".to_string(),
+                },
+            );
+        }
+    }
+
+    credits
+        .into_iter()
+        .map(|(name, source)| DependencyCredit { name, source })
+        .collect()
+}
+
+fn build_binary_map(
+    config: &ResolvedConfig,
+    resolver: &ModuleResolver,
+    listed: &HashSet<&str>,
+) -> HashMap<String, String> {
+    let mut binaries: HashMap<String, String> = KNOWN_BINARIES
+        .iter()
+        .filter(|(_, package)| listed.contains(package))
+        .map(|(binary, package)| (binary.to_string(), package.to_string()))
+        .collect();
+
+    let mut names: Vec<&str> = listed.iter().copied().collect();
+    names.sort_unstable();
+
+    for name in names {
+        let Some(package_dir) = resolver.find_package_dir(name, &config.root) else {
+            continue;
+        };
+        let Some(manifest) = resolver.read_package_json(&package_dir) else {
+            continue;
+        };
+
+        match manifest.get("bin") {
+            Some(serde_json::Value::String(_)) => {
+                let binary = name.rsplit('/').next().unwrap_or(name);
+                binaries.insert(binary.to_string(), name.to_string());
+            }
+            Some(serde_json::Value::Object(map)) => {
+                for binary in map.keys() {
+                    binaries.insert(binary.clone(), name.to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    binaries
+}
+
+fn read_husky_hooks(root: &Path) -> Vec<(String, String)> {
+    let Ok(entries) = std::fs::read_dir(root.join(".husky")) else {
+        return Vec::new();
+    };
+
+    let mut hooks: Vec<(String, String)> = entries
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().is_file())
+        .filter_map(|e| {
+            let name = e.file_name().to_string_lossy().to_string();
+            if name.starts_with('_') || name.starts_with('.') {
+                return None;
+            }
+            let content = std::fs::read_to_string(e.path()).ok()?;
+            let script = content
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                .collect::<Vec<_>>()
+                .join("\n");
+            Some((format!(".husky/{}", name), script))
+        })
+        .collect();
+
+    hooks.sort();
+    hooks
+}
+
+fn parse_script(script: &str, script_names: &HashSet<&str>) -> Vec<ScriptReference> {
+    let mut references = Vec::new();
+
+    for command in split_commands(script) {
+        parse_command(&tokenize(&command), script_names, &mut references);
+    }
+
+    references
+}
+
+fn parse_command(tokens: &[String], script_names: &HashSet<&str>, out: &mut Vec<ScriptReference>) {
+    let mut rest = tokens
+        .iter()
+        .skip_while(|t| is_env_assignment(t))
+        .map(|t| t.as_str());
+
+    let Some(mut program) = rest.next() else {
+        return;
+    };
+
+    loop {
+        match program {
+            "npx" | "pnpx" | "bunx" => {
+                let mut next = None;
+                while let Some(token) = rest.next() {
+                    if token == "-p" || token == "--package" {
+                        if let Some(package) =
+                            rest.next().and_then(ModuleResolver::get_package_name)
+                        {
+                            out.push(ScriptReference::Package(package.to_string()));
+                        }
+                    } else if !token.starts_with('-') {
+                        next = Some(token);
+                        break;
+                    }
+                }
+                match next {
+                    Some(token) => program = token,
+                    None => return,
+                }
+            }
+            "npm" | "pnpm" | "yarn" | "bun" => {
+                let Some(subcommand) = rest.next() else {
+                    return;
+                };
+                match subcommand {
+                    "exec" | "dlx" | "x" => match rest.find(|t| !t.starts_with('-')) {
+                        Some(token) => program = token,
+                        None => return,
+                    },
+                    _ if program != "npm"
+                        && !subcommand.starts_with('-')
+                        && !is_package_manager_command(subcommand)
+                        && !script_names.contains(subcommand) =>
+                    {
+                        program = subcommand;
+                    }
+                    _ => return,
+                }
+            }
+            "cross-env" | "cross-env-shell" => {
+                out.push(ScriptReference::Binary(program.to_string()));
+                match rest.find(|t| !is_env_assignment(t)) {
+                    Some(token) => program = token,
+                    None => return,
+                }
+            }
+            "node" => {
+                let args: Vec<&str> = rest.collect();
+                for (i, arg) in args.iter().enumerate() {
+                    let value = if NODE_PRELOAD_FLAGS.contains(arg) {
+                        args.get(i + 1).copied()
+                    } else {
+                        NODE_PRELOAD_FLAGS.iter().find_map(|flag| {
+                            arg.strip_prefix(flag).and_then(|v| v.strip_prefix('='))
+                        })
+                    };
+                    if let Some(package) = value.and_then(ModuleResolver::get_package_name) {
+                        out.push(ScriptReference::Package(package.to_string()));
+                    }
+                }
+                return;
+            }
+            _ => break,
+        }
+    }
+
+    if program.starts_with('.') || program.starts_with('/') || program.starts_with('$') {
+        return;
+    }
+
+    out.push(ScriptReference::Binary(program.to_string()));
+
+    let args: Vec<&str> = rest.collect();
+
+    if program == "concurrently" {
+        let commands = args
+            .iter()
+            .filter(|a| !a.starts_with('-') && !a.contains(':'));
+        for arg in commands {
+            for command in split_commands(arg) {
+                parse_command(&tokenize(&command), script_names, out);
+            }
+        }
+        return;
+    }
+
+    if let Some(separator) = args.iter().position(|a| *a == "--") {
+        let tokens: Vec<String> = args[separator + 1..]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        parse_command(&tokens, script_names, out);
+    }
+}
+
+fn is_env_assignment(token: &str) -> bool {
+    !token.starts_with('-')
+        && token.split_once('=').is_some_and(|(key, _)| {
+            !key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        })
+}
+
+fn is_package_manager_command(command: &str) -> bool {
+    matches!(
+        command,
+        "run"
+            | "install"
+            | "i"
+            | "add"
+            | "remove"
+            | "rm"
+            | "test"
+            | "start"
+            | "build"
+            | "publish"
+            | "pack"
+            | "link"
+            | "workspace"
+            | "workspaces"
+            | "why"
+            | "outdated"
+            | "update"
+            | "upgrade"
+            | "audit"
+            | "version"
+            | "config"
+            | "cache"
+            | "create"
+            | "init"
+    )
+}
+
+fn split_commands(script: &str) -> Vec<String> {
+    let mut commands = Vec::new();
+    let mut current = String::new();
+    let mut quote: Option<char> = None;
+
+    for c in script.chars() {
+        match quote {
+            Some(q) => {
+                if c == q {
+                    quote = None;
+                }
+                current.push(c);
+            }
+            None => match c {
+                '"' | '\'' => {
+                    quote = Some(c);
+                    current.push(c);
+                }
+                ';' | '|' | '&' | '\n' | '(' | ')' => {
+                    if !current.trim().is_empty() {
+                        commands.push(current.trim().to_string());
+                    }
+                    current.clear();
+                }
+                _ => current.push(c),
+            },
+        }
+    }
+
+    if !current.trim().is_empty() {
+        commands.push(current.trim().to_string());
+    }
+
+    commands
+}
+
+fn tokenize(command: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut quote: Option<char> = None;
+    let mut in_token = false;
+
+    for c in command.chars() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => current.push(c),
+            None => match c {
+                '"' | '\'' => {
+                    quote = Some(c);
+                    in_token = true;
+                }
+                c if c.is_whitespace() => {
+                    if in_token {
+                        tokens.push(std::mem::take(&mut current));
+                        in_token = false;
+                    }
+                }
+                _ => {
+                    current.push(c);
+                    in_token = true;
+                }
+            },
+        }
+    }
+
+    if in_token {
+        tokens.push(current);
+    }
+
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn binaries(script: &str) -> Vec<String> {
+        parse_script(script, &HashSet::from(["build", "lint"]))
+            .into_iter()
+            .map(|r| match r {
+                ScriptReference::Binary(name) => name,
+                ScriptReference::Package(name) => format!("pkg:{}", name),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_simple_scripts() {
+        assert_eq!(binaries("vite build"), vec!["vite"]);
+        assert_eq!(binaries("tsc --noEmit && eslint ."), vec!["tsc", "eslint"]);
+        assert_eq!(binaries("NODE_ENV=test jest --ci"), vec!["jest"]);
+        assert_eq!(binaries("./scripts/release.sh"), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_parse_wrapped_scripts() {
+        assert_eq!(
+            binaries("cross-env NODE_ENV=production webpack --mode production"),
+            vec!["cross-env", "webpack"]
+        );
+        assert_eq!(
+            binaries("npx --no -- commitlint --edit"),
+            vec!["commitlint"]
+        );
+        assert_eq!(binaries("pnpm exec prettier --write ."), vec!["prettier"]);
+        assert_eq!(binaries("yarn tsc"), vec!["tsc"]);
+        assert_eq!(binaries("yarn build && npm run lint"), Vec::<String>::new());
+        assert_eq!(
+            binaries("concurrently \"vite\" \"tsc -w\""),
+            vec!["concurrently", "vite", "tsc"]
+        );
+        assert_eq!(
+            binaries("dotenv -e .env.test -- vitest run"),
+            vec!["dotenv", "vitest"]
+        );
+    }
+
+    #[test]
+    fn test_parse_node_preloads() {
+        assert_eq!(
+            binaries("node -r ts-node/register --import=tsx ./src/server.ts"),
+            vec!["pkg:ts-node", "pkg:tsx"]
+        );
+    }
+
+    #[test]
+    fn test_collect_dependency_credits() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = temp.path();
+        std::fs::create_dir_all(root.join("src")).unwrap();
+        std::fs::write(root.join("src/index.ts"), "import React from 'react';").unwrap();
+        std::fs::write(
+            root.join("package.json"),
+            r#"{
+                "scripts": {"build": "tsc -p .", "lint": "eslint src"},
+                "dependencies": {"react": "18.0.0", "tslib": "2.0.0"},
+                "devDependencies": {
+                    "typescript": "5.0.0",
+                    "eslint": "9.0.0",
+                    "eslint-plugin-react": "7.0.0",
+                    "@tsconfig/strictest": "2.0.0",
+                    "prettier": "3.0.0"
+                }
+            }"#,
+        )
+        .unwrap();
+        std::fs::write(
+            root.join(".eslintrc.json"),
+            r#"{"plugins": ["react"]}"#,
+        )
+        .unwrap();
+        std::fs::write(
+            root.join("tsconfig.json"),
+            r#"{"extends": "@tsconfig/strictest/tsconfig.json", "compilerOptions": {"importHelpers": true}}"#,
+        )
+        .unwrap();
+
+        let config = crate::config::load_config(root, None).unwrap();
+        let graph =
+            crate::graph::build_graph_with_options(&config, Default::default()).unwrap();
+        let credits = collect_dependency_credits(
+            &graph,
+            &config,
+            &crate::plugins::run_project_plugins(&config, false),
+            &ModuleResolver::for_packages(root),
+        );
+        let credited: HashSet<&str> = credits.iter().map(|c| c.name.as_str()).collect();
+
+        for name in [
+            "react",
+            "tslib",
+            "typescript",
+            "eslint",
+            "eslint-plugin-react",
+            "@tsconfig/strictest",
+        ] {
+            assert!(credited.contains(name), "{} should be credited", name);
+        }
+        assert!(!credited.contains("prettier"));
+
+        let typescript: Vec<&CreditSource> = credits
+            .iter()
+            .filter(|c| c.name == "typescript")
+            .map(|c| &c.source)
+            .collect();
+        assert!(typescript.contains(&&CreditSource::Script {
+            script: "build".to_string(),
+            binary: "tsc".to_string(),
+        }));
+    }
+
+    #[test]
+    fn test_read_husky_hooks() {
+        let temp = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir_all(temp.path().join(".husky/_")).unwrap();
+        std::fs::write(
+            temp.path().join(".husky/pre-commit"),
+            "#!/usr/bin/env sh\nnpx lint-staged\n",
+        )
+        .unwrap();
+
+        let hooks = read_husky_hooks(temp.path());
+        assert_eq!(
+            hooks,
+            vec![(
+                ".husky/pre-commit".to_string(),
+                "npx lint-staged".to_string()
+            )]
+        );
+    }
+}
@@ -0,0 +1,175 @@
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use crate::config::ResolvedConfig;
+use crate::graph::ModuleGraph;
+use crate::parser::css::is_css_module;
+use crate::parser::ExportKind;
+use crate::{UnknownCssClass, UnusedCssClass};
+
+enum ClassUsage<'a> {
+    All,
+    Names(HashSet<&'a str>),
+}
+
+pub fn find_css_class_issues(
+    graph: &ModuleGraph,
+    config: &ResolvedConfig,
+) -> (Vec<UnusedCssClass>, Vec<UnknownCssClass>) {
+    let mut usage: HashMap<&Path, ClassUsage> = HashMap::new();
+    let mut unknown = Vec::new();
+
+    for module in graph.modules.values() {
+        for import in &module.imports {
+            let Some(target) = import
+                .resolved_path
+                .as_deref()
+                .filter(|path| is_css_module(path))
+            else {
+                continue;
+            };
+            let Some(stylesheet) = graph.modules.get(target) else {
+                continue;
+            };
+
+            let entry = usage
+                .entry(target)
+                .or_insert_with(|| ClassUsage::Names(HashSet::new()));
+
+            for imported in &import.original.imported_names {
+                let members = match (imported.name.as_str(), &imported.members) {
+                    ("default" | "*", Some(members)) => members,
+                    ("default" | "*", None) => {
+                        *entry = ClassUsage::All;
+                        continue;
+                    }
+                    (name, _) => {
+                        if let ClassUsage::Names(names) = entry {
+                            names.insert(name);
+                        }
+                        continue;
+                    }
+                };
+
+                for member in members {
+                    let class = stylesheet.exports.iter().find(|export| {
+                        export.kind == ExportKind::CssClass
+                            && (export.name == member.name
+                                || camel_case(&export.name) == member.name)
+                    });
+
+                    match (class, &mut *entry) {
+                        (Some(class), ClassUsage::Names(names)) => {
+                            names.insert(class.name.as_str());
+                        }
+                        (Some(_), ClassUsage::All) => {}
+                        (None, _) => unknown.push(UnknownCssClass {
+                            path: module.path.clone(),
+                            stylesheet: target.to_path_buf(),
+                            name: member.name.clone(),
+                            line: member.line,
+                            col: member.col,
+                        }),
+                    }
+                }
+            }
+        }
+    }
+
+    let rules = &config.config.rules;
+    let mut unused = Vec::new();
+    if rules.css_classes.is_enabled() {
+        for (path, used) in &usage {
+            let ClassUsage::Names(names) = used else {
+                continue;
+            };
+            for export in &graph.modules[*path].exports {
+                if export.kind == ExportKind::CssClass && !names.contains(export.name.as_str()) {
+                    unused.push(UnusedCssClass {
+                        path: path.to_path_buf(),
+                        name: export.name.clone(),
+                        line: export.line,
+                        col: export.col,
+                    });
+                }
+            }
+        }
+    }
+    if !rules.unknown_css_classes.is_enabled() {
+        unknown.clear();
+    }
+
+    unused.sort_by(|a, b| (&a.path, a.line, a.col).cmp(&(&b.path, b.line, b.col)));
+    unknown.sort_by(|a, b| (&a.path, a.line, a.col).cmp(&(&b.path, b.line, b.col)));
+    (unused, unknown)
+}
+
+fn camel_case(class: &str) -> String {
+    let mut camel = String::with_capacity(class.len());
+    let mut upper = false;
+    for c in class.chars() {
+        if c == '-' || c == '_' {
+            upper = !camel.is_empty();
+        } else if upper {
+            camel.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            camel.push(c);
+        }
+    }
+    camel
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::write_tree;
+
+    #[test]
+    fn test_camel_case() {
+        assert_eq!(camel_case("primary-button"), "primaryButton");
+        assert_eq!(camel_case("card__title"), "cardTitle");
+        assert_eq!(camel_case("active"), "active");
+    }
+
+    #[test]
+    fn test_unused_and_unknown_classes() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = temp.path();
+        write_tree(
+            root,
+            &[
+                ("package.json", r#"{"name": "app"}"#),
+                (
+                    "src/index.tsx",
+                    "import styles from './Button.module.css';\nimport * as all from './Card.module.css';\nexport const el = <button className={styles.primaryButton + styles.missing}>{all}</button>;\n",
+                ),
+                (
+                    "src/Button.module.css",
+                    ".primary-button { composes: base; }\n.base { }\n.stale { }\n",
+                ),
+                ("src/Card.module.css", ".card { }\n"),
+            ],
+        );
+
+        let result = crate::analyze(root).unwrap();
+
+        let unused: Vec<(&str, u32)> = result
+            .issues
+            .css_classes
+            .iter()
+            .map(|c| (c.name.as_str(), c.line))
+            .collect();
+        assert_eq!(unused, vec![("stale", 3)]);
+
+        assert_eq!(result.issues.unknown_css_classes.len(), 1);
+        let unknown = &result.issues.unknown_css_classes[0];
+        assert_eq!(unknown.name, "missing");
+        assert_eq!((unknown.line, unknown.col), (3, 68));
+        assert!(result
+            .issues
+            .exports
+            .iter()
+            .all(|export| !export.path.to_string_lossy().ends_with(".css")));
+    }
+}
@@ -0,0 +1,161 @@
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use anyhow::Result;
+
+use crate::config::{load_config_with_tsconfig, ResolvedConfig, CONFIG_FILES};
+use crate::graph::{GraphUpdate, IncrementalGraph};
+use crate::plugins::{get_builtin_plugins, run_project_plugins, PluginResults};
+use crate::AnalysisResult;
+
+use super::{analyze_graph, build_options, AnalyzeOptions};
+
+const REBUILD_FILES: &[&str] = &["package.json", "tsconfig.json", "jsconfig.json"];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refresh {
+    Full,
+    Incremental(GraphUpdate),
+}
+
+pub struct IncrementalAnalyzer {
+    root: PathBuf,
+    config_path: Option<PathBuf>,
+    options: AnalyzeOptions,
+    state: Option<(ResolvedConfig, PluginResults, IncrementalGraph)>,
+}
+
+impl IncrementalAnalyzer {
+    pub fn new(root: &Path, config_path: Option<&Path>, options: AnalyzeOptions) -> Self {
+        Self {
+            root: root.to_path_buf(),
+            config_path: config_path.map(canonical),
+            options,
+            state: None,
+        }
+    }
+
+    pub fn analyze(&mut self, changed: &[PathBuf]) -> Result<(AnalysisResult, Refresh)> {
+        let start = Instant::now();
+        let changed: Vec<PathBuf> = changed.iter().map(|p| canonical(p)).collect();
+
+        let rebuild = self.requires_rebuild(&changed);
+        let refresh = match self.state {
+            Some((ref config, _, ref mut graph)) if !rebuild => {
+                Refresh::Incremental(graph.update(config, &changed)?)
+            }
+            _ => {
+                self.state = None;
+                let config = load_config_with_tsconfig(
+                    &self.root,
+                    self.config_path.as_deref(),
+                    self.options.tsconfig.as_deref(),
+                )?;
+                let plugins = run_project_plugins(&config, self.options.production);
+                let graph = IncrementalGraph::build(
+                    &config,
+                    build_options(&config, &self.options, &plugins)?,
+                )?;
+                self.state = Some((config, plugins, graph));
+                Refresh::Full
+            }
+        };
+        let parse_time = start.elapsed().as_millis() as u64;
+
+        let (config, plugins, graph) = self.state.as_ref().expect("graph was just built");
+        let mut result = analyze_graph(graph.graph(), config, &self.options, plugins);
+        result.stats.parse_time_ms = parse_time;
+        result.stats.duration_ms = start.elapsed().as_millis() as u64;
+
+        Ok((result, refresh))
+    }
+
+    fn requires_rebuild(&self, changed: &[PathBuf]) -> bool {
+        let plugins = get_builtin_plugins();
+        changed.iter().any(|path| {
+            if self.config_path.as_deref() == Some(path.as_path())
+                || self.options.tsconfig.as_deref().map(canonical).as_ref() == Some(path)
+            {
+                return true;
+            }
+
+            let name = path
+                .file_name()
+                .map(|n| n.to_string_lossy())
+                .unwrap_or_default();
+            REBUILD_FILES.contains(&name.as_ref())
+                || CONFIG_FILES.contains(&name.as_ref())
+                || (name.starts_with("tsconfig.") && name.ends_with(".json"))
+                || plugins
+                    .iter()
+                    .flat_map(|plugin| plugin.config_patterns())
+                    .any(|pattern| path.ends_with(pattern))
+        })
+    }
+}
+
+fn canonical(path: &Path) -> PathBuf {
+    if let Ok(path) = path.canonicalize() {
+        return path;
+    }
+
+    match (path.parent().map(Path::canonicalize), path.file_name()) {
+        (Some(Ok(parent)), Some(name)) => parent.join(name),
+        _ => path.to_path_buf(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_incremental_matches_full_analysis() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(root.join("package.json"), r#"{"name": "app"}"#).unwrap();
+        fs::write(
+            root.join("src/index.ts"),
+            "import { a } from './a';\nconsole.log(a);\n",
+        )
+        .unwrap();
+        fs::write(root.join("src/a.ts"), "export const a = 1;\n").unwrap();
+
+        let mut analyzer = IncrementalAnalyzer::new(root, None, AnalyzeOptions::default());
+        let (result, refresh) = analyzer.analyze(&[]).unwrap();
+        assert_eq!(refresh, Refresh::Full);
+        assert!(result.issues.exports.is_empty());
+
+        fs::write(
+            root.join("src/a.ts"),
+            "export const a = 1;\nexport const b = 2;\n",
+        )
+        .unwrap();
+        fs::write(root.join("src/orphan.ts"), "export const c = 3;\n").unwrap();
+        let (result, refresh) = analyzer
+            .analyze(&[root.join("src/a.ts"), root.join("src/orphan.ts")])
+            .unwrap();
+        assert!(matches!(
+            refresh,
+            Refresh::Incremental(GraphUpdate { parsed: 2, .. })
+        ));
+
+        let full = crate::analyze(root).unwrap();
+        assert_eq!(result.counters.total(), full.counters.total());
+        assert_eq!(result.issues.exports[0].name, "b");
+        assert_eq!(result.issues.files.len(), 1);
+
+        let (_, refresh) = analyzer.analyze(&[root.join("package.json")]).unwrap();
+        assert_eq!(refresh, Refresh::Full);
+
+        for config in ["pior.jsonc", "vite.config.ts"] {
+            fs::write(root.join(config), "{}").unwrap();
+            let (_, refresh) = analyzer.analyze(&[root.join(config)]).unwrap();
+            assert_eq!(refresh, Refresh::Full);
+            fs::remove_file(root.join(config)).unwrap();
+        }
+    }
+}
@@ -1,21 +1,36 @@
-use std::collections::HashSet;
-use std::path::PathBuf;
+mod credits;
+mod css_modules;
+mod incremental;
+
+pub use incremental::{IncrementalAnalyzer, Refresh};
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 use std::time::Instant;
 
+use ignore::WalkBuilder;
+
 use crate::cache::{create_cache, create_cache_with_dir};
 use crate::config::ResolvedConfig;
 use crate::graph::{build_graph_with_options, BuildOptions, ModuleGraph};
+use crate::parser::css::{is_asset, is_stylesheet};
+use crate::parser::Import;
+use crate::plugins::{get_builtin_plugins, run_project_plugins, PluginResults};
+use crate::resolver::{Builtins, ModuleResolver};
 use crate::{
-    AnalysisResult, Counters, Issues, Stats, TypeKind, UnlistedDependency, UnresolvedImport,
-    UnusedDependency, UnusedExport, UnusedFile, UnusedType,
+    AnalysisResult, Counters, DependencyCredit, Issues, MisplacedDependency, MissingTypesPackage,
+    Stats, TypeKind, TypeOnlyDependency, UnlistedDependency, UnpairedTypesPackage, UnresolvedImport,
+    UnusedAsset, UnusedDependency, UnusedExport, UnusedFile, UnusedType,
 };
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct AnalyzeOptions {
     pub cache: bool,
     pub cache_dir: Option<PathBuf>,
     pub production: bool,
     pub strict: bool,
+    pub tsconfig: Option<PathBuf>,
+    pub overlay: HashMap<PathBuf, String>,
 }
 
 pub fn analyze_project(config: &ResolvedConfig) -> anyhow::Result<AnalysisResult> {
@@ -28,43 +43,80 @@ pub fn analyze_project_with_options(
 ) -> anyhow::Result<AnalysisResult> {
     let start = Instant::now();
 
+    let plugins = run_project_plugins(config, options.production);
+    let build_options = build_options(config, &options, &plugins)?;
+
+    let parse_start = Instant::now();
+    let graph = build_graph_with_options(config, build_options)?;
+    let parse_time = parse_start.elapsed().as_millis() as u64;
+
+    Ok(analyze_built_graph(&graph, config, &options, &plugins, start, parse_time))
+}
+
+fn build_options(
+    config: &ResolvedConfig,
+    options: &AnalyzeOptions,
+    plugins: &PluginResults,
+) -> anyhow::Result<BuildOptions> {
     let cache = if let Some(ref cache_dir) = options.cache_dir {
         create_cache_with_dir(cache_dir.clone(), options.cache)?
     } else {
         create_cache(&config.root, options.cache)?
     };
 
-    let build_options = BuildOptions {
+    Ok(BuildOptions {
         cache,
         production: options.production,
         strict: options.strict,
-    };
+        overlay: options.overlay.clone(),
+        aliases: plugins
+            .iter()
+            .flat_map(|(_, result)| result.aliases.iter().cloned())
+            .collect(),
+    })
+}
 
-    let parse_start = Instant::now();
-    let graph = build_graph_with_options(config, build_options)?;
-    let parse_time = parse_start.elapsed().as_millis() as u64;
+pub fn analyze_graph(
+    graph: &ModuleGraph,
+    config: &ResolvedConfig,
+    options: &AnalyzeOptions,
+    plugins: &PluginResults,
+) -> AnalysisResult {
+    analyze_built_graph(graph, config, options, plugins, Instant::now(), 0)
+}
 
+fn analyze_built_graph(
+    graph: &ModuleGraph,
+    config: &ResolvedConfig,
+    options: &AnalyzeOptions,
+    plugins: &PluginResults,
+    start: Instant,
+    parse_time: u64,
+) -> AnalysisResult {
     let analysis_start = Instant::now();
-
-    let unused_files = find_unused_files(&graph, config);
-    let (unused_exports, unused_types) = find_unused_exports(&graph, config);
-    let (unused_deps, unused_dev_deps) = find_unused_dependencies(&graph, config, &options);
-    let unlisted_deps = find_unlisted_dependencies(&graph, config, &options);
-    let unresolved_imports = find_unresolved_imports(&graph, config);
+    let packages = ModuleResolver::for_packages(&config.root);
+
+    let dependency_credits =
+        credits::collect_dependency_credits(graph, config, plugins, &packages);
+
+    let unused_files = find_unused_files(graph, config);
+    let (unused_exports, unused_types, unused_ns_exports, unused_ns_types) =
+        find_unused_exports(graph, config);
+    let (unused_deps, unused_dev_deps) =
+        find_unused_dependencies(config, &dependency_credits, options);
+    let (misplaced_deps, misplaced_dev_deps) =
+        find_misplaced_dependencies(graph, config, &dependency_credits, options);
+    let type_only_deps = find_type_only_dependencies(graph, config);
+    let unpaired_types = find_unpaired_types(graph, config);
+    let missing_types = find_missing_types(graph, config, &packages);
+    let unlisted_deps = find_unlisted_dependencies(graph, config, options);
+    let unresolved_imports = find_unresolved_imports(graph, config);
+    let unused_assets = find_unused_assets(graph, config);
+    let (unused_css_classes, unknown_css_classes) =
+        css_modules::find_css_class_issues(graph, config);
 
     let analysis_time = analysis_start.elapsed().as_millis() as u64;
 
-    let counters = Counters {
-        files: unused_files.len(),
-        dependencies: unused_deps.len(),
-        dev_dependencies: unused_dev_deps.len(),
-        exports: unused_exports.len(),
-        types: unused_types.len(),
-        unlisted: unlisted_deps.len(),
-        unresolved: unresolved_imports.len(),
-        ..Default::default()
-    };
-
     let stats = Stats {
         files_analyzed: graph.modules.len(),
         duration_ms: start.elapsed().as_millis() as u64,
@@ -73,20 +125,33 @@ pub fn analyze_project_with_options(
         analysis_time_ms: analysis_time,
     };
 
-    Ok(AnalysisResult {
-        issues: Issues {
-            files: unused_files,
-            dependencies: unused_deps,
-            dev_dependencies: unused_dev_deps,
-            exports: unused_exports,
-            types: unused_types,
-            unlisted: unlisted_deps,
-            unresolved: unresolved_imports,
-            ..Default::default()
-        },
-        counters,
+    let issues = Issues {
+        files: unused_files,
+        dependencies: unused_deps,
+        dev_dependencies: unused_dev_deps,
+        misplaced_dependencies: misplaced_deps,
+        misplaced_dev_dependencies: misplaced_dev_deps,
+        type_only_dependencies: type_only_deps,
+        unpaired_types,
+        missing_types,
+        exports: unused_exports,
+        types: unused_types,
+        ns_exports: unused_ns_exports,
+        ns_types: unused_ns_types,
+        unlisted: unlisted_deps,
+        unresolved: unresolved_imports,
+        assets: unused_assets,
+        css_classes: unused_css_classes,
+        unknown_css_classes,
+        ..Default::default()
+    };
+
+    AnalysisResult {
+        counters: Counters::from_issues(&issues),
+        issues,
         stats,
-    })
+        dependency_credits,
+    }
 }
 
 fn find_unused_files(graph: &ModuleGraph, config: &ResolvedConfig) -> Vec<UnusedFile> {
@@ -101,7 +166,7 @@ fn find_unused_files(graph: &ModuleGraph, config: &ResolvedConfig) -> Vec<Unused
         .collect();
 
     for path in graph.modules.keys() {
-        if reachable.contains(path) {
+        if reachable.contains(path) || is_stylesheet(path) {
             continue;
         }
 
@@ -137,6 +202,55 @@ fn find_unused_files(graph: &ModuleGraph, config: &ResolvedConfig) -> Vec<Unused
     unused
 }
 
+fn find_unused_assets(graph: &ModuleGraph, config: &ResolvedConfig) -> Vec<UnusedAsset> {
+    if config.config.assets.is_empty() || !config.config.rules.assets.is_enabled() {
+        return Vec::new();
+    }
+
+    let referenced: HashSet<&Path> = graph
+        .modules
+        .values()
+        .flat_map(|module| {
+            let imports = module.imports.iter().map(|i| &i.resolved_path);
+            let re_exports = module.re_exports.iter().map(|r| &r.resolved_path);
+            imports.chain(re_exports)
+        })
+        .filter_map(|path| path.as_deref())
+        .collect();
+    let globs: Vec<_> = graph
+        .modules
+        .values()
+        .flat_map(|module| {
+            module
+                .glob_imports
+                .iter()
+                .map(|glob| glob.matcher(&module.path, &config.root))
+        })
+        .collect();
+
+    let mut unused = Vec::new();
+    for dir in &config.config.assets {
+        let walker = WalkBuilder::new(config.root.join(dir))
+            .hidden(false)
+            .git_ignore(true)
+            .build();
+
+        for path in walker.filter_map(Result::ok).map(|entry| entry.into_path()) {
+            if path.is_file()
+                && is_asset(&path)
+                && !referenced.contains(path.as_path())
+                && !globs.iter().any(|glob| glob.is_match(&path))
+            {
+                unused.push(UnusedAsset { path });
+            }
+        }
+    }
+
+    unused.sort_by(|a, b| a.path.cmp(&b.path));
+    unused.dedup_by(|a, b| a.path == b.path);
+    unused
+}
+
 fn is_test_file(path: &str) -> bool {
     path.contains(".test.")
         || path.contains(".spec.")
@@ -152,25 +266,66 @@ fn is_test_file(path: &str) -> bool {
         || path.ends_with(".spec.jsx")
 }
 
-fn find_unused_exports(
-    graph: &ModuleGraph,
-    config: &ResolvedConfig,
-) -> (Vec<UnusedExport>, Vec<UnusedType>) {
-    let used_exports = graph.get_used_exports();
+fn is_dev_only_file(path: &str) -> bool {
+    if is_test_file(path) {
+        return true;
+    }
+
+    let file_name = path.rsplit('/').next().unwrap_or(path);
+    let at_root = !path.contains('/');
+
+    path.contains(".stories.")
+        || path.contains(".story.")
+        || path.starts_with(".storybook/")
+        || path.contains("/.storybook/")
+        || path.starts_with("test/")
+        || path.starts_with("tests/")
+        || path.contains("/test/")
+        || path.contains("/tests/")
+        || (at_root && file_name.contains(".config."))
+        || (at_root && file_name.starts_with('.') && file_name.contains("rc."))
+        || is_tool_config(path)
+}
+
+fn is_tool_config(path: &str) -> bool {
+    get_builtin_plugins()
+        .iter()
+        .flat_map(|plugin| plugin.config_patterns())
+        .any(|pattern| path == *pattern || path.ends_with(&format!("/{}", pattern)))
+}
+
+type UnusedExports = (
+    Vec<UnusedExport>,
+    Vec<UnusedType>,
+    Vec<UnusedExport>,
+    Vec<UnusedType>,
+);
+
+fn find_unused_exports(graph: &ModuleGraph, config: &ResolvedConfig) -> UnusedExports {
+    let rules = &config.config.rules;
+    let track_members = rules.ns_exports.is_enabled() || rules.ns_types.is_enabled();
+    let used_exports = graph.get_used_exports(track_members);
+    let namespace_imported = match track_members {
+        true => graph.get_namespace_imported(),
+        false => HashSet::new(),
+    };
     let reachable = graph.get_reachable_files();
 
     let mut unused_exports = Vec::new();
     let mut unused_types = Vec::new();
+    let mut unused_ns_exports = Vec::new();
+    let mut unused_ns_types = Vec::new();
 
     let entry_points: HashSet<&PathBuf> = graph.entry_points.iter().collect();
 
     for (path, module) in &graph.modules {
-        if !reachable.contains(path) {
+        if !reachable.contains(path) || is_stylesheet(path) {
             continue;
         }
 
         let used_in_file = used_exports.get(path);
         let is_entry = entry_points.contains(path);
+        let is_namespace_imported = namespace_imported.contains(path);
 
         let relative = path
             .strip_prefix(&config.root)
@@ -230,7 +385,12 @@ fn find_unused_exports(
             }
 
             if export.is_type {
-                unused_types.push(UnusedType {
+                let types = match is_namespace_imported {
+                    false => &mut unused_types,
+                    true if rules.ns_types.is_enabled() => &mut unused_ns_types,
+                    true => continue,
+                };
+                types.push(UnusedType {
                     path: path.clone(),
                     name: export.name.clone(),
                     line: export.line,
@@ -243,7 +403,12 @@ fn find_unused_exports(
                     },
                 });
             } else {
-                unused_exports.push(UnusedExport {
+                let exports = match is_namespace_imported {
+                    false => &mut unused_exports,
+                    true if rules.ns_exports.is_enabled() => &mut unused_ns_exports,
+                    true => continue,
+                };
+                exports.push(UnusedExport {
                     path: path.clone(),
                     name: export.name.clone(),
                     line: export.line,
@@ -255,10 +420,14 @@ fn find_unused_exports(
         }
     }
 
-    unused_exports.sort_by(|a, b| (&a.path, a.line).cmp(&(&b.path, b.line)));
-    unused_types.sort_by(|a, b| (&a.path, a.line).cmp(&(&b.path, b.line)));
+    for exports in [&mut unused_exports, &mut unused_ns_exports] {
+        exports.sort_by(|a, b| (&a.path, a.line).cmp(&(&b.path, b.line)));
+    }
+    for types in [&mut unused_types, &mut unused_ns_types] {
+        types.sort_by(|a, b| (&a.path, a.line).cmp(&(&b.path, b.line)));
+    }
 
-    (unused_exports, unused_types)
+    (unused_exports, unused_types, unused_ns_exports, unused_ns_types)
 }
 
 fn convert_export_kind(kind: crate::parser::ExportKind) -> crate::ExportKind {
@@ -273,12 +442,14 @@ fn convert_export_kind(kind: crate::parser::ExportKind) -> crate::ExportKind {
         crate::parser::ExportKind::Enum => crate::ExportKind::Enum,
         crate::parser::ExportKind::Namespace => crate::ExportKind::Namespace,
         crate::parser::ExportKind::Default => crate::ExportKind::Default,
+        // Unreachable: stylesheet classes are reported as `UnusedCssClass`.
+        crate::parser::ExportKind::CssClass => crate::ExportKind::Variable,
     }
 }
 
 fn find_unused_dependencies(
-    graph: &ModuleGraph,
     config: &ResolvedConfig,
+    credits: &[DependencyCredit],
     options: &AnalyzeOptions,
 ) -> (Vec<UnusedDependency>, Vec<UnusedDependency>) {
     let mut unused_deps = Vec::new();
@@ -288,7 +459,7 @@ fn find_unused_dependencies(
         return (unused_deps, unused_dev_deps);
     };
 
-    let used_packages = graph.get_used_packages();
+    let credited: HashSet<&str> = credits.iter().map(|c| c.name.as_str()).collect();
     let package_json_path = config.root.join("package.json");
 
     let ignore_deps: HashSet<&str> = config
@@ -303,7 +474,7 @@ fn find_unused_dependencies(
             continue;
         }
 
-        if !used_packages.contains(dep_name) && !is_implicit_dependency(dep_name) {
+        if !credited.contains(dep_name.as_str()) && !dep_name.starts_with("@types/") {
             unused_deps.push(UnusedDependency {
                 name: dep_name.clone(),
                 package_json: package_json_path.clone(),
@@ -319,7 +490,7 @@ fn find_unused_dependencies(
                 continue;
             }
 
-            if !used_packages.contains(dep_name) && !is_dev_tool_dependency(dep_name) {
+            if !credited.contains(dep_name.as_str()) && !dep_name.starts_with("@types/") {
                 unused_dev_deps.push(UnusedDependency {
                     name: dep_name.clone(),
                     package_json: package_json_path.clone(),
@@ -336,37 +507,422 @@ fn find_unused_dependencies(
     (unused_deps, unused_dev_deps)
 }
 
-fn is_implicit_dependency(name: &str) -> bool {
+fn find_misplaced_dependencies(
+    graph: &ModuleGraph,
+    config: &ResolvedConfig,
+    credits: &[DependencyCredit],
+    options: &AnalyzeOptions,
+) -> (Vec<MisplacedDependency>, Vec<MisplacedDependency>) {
+    let mut misplaced_deps = Vec::new();
+    let mut misplaced_dev_deps = Vec::new();
+
+    let Some(ref pkg) = config.package_json else {
+        return (misplaced_deps, misplaced_dev_deps);
+    };
+
+    let rules = &config.config.rules;
+    if !rules.misplaced_dependencies.is_enabled() && !rules.misplaced_dev_dependencies.is_enabled() {
+        return (misplaced_deps, misplaced_dev_deps);
+    }
+
+    let package_json_path = config.root.join("package.json");
+
+    let ignore_deps: HashSet<&str> = config
+        .config
+        .ignore_dependencies
+        .iter()
+        .map(|s| s.as_str())
+        .collect();
+
+    let relative = |path: &Path| -> String {
+        path.strip_prefix(&config.root)
+            .unwrap_or(path)
+            .to_string_lossy()
+            .replace('\\', "/")
+    };
+
+    let credited_outside_imports: HashSet<&str> = credits
+        .iter()
+        .filter(|c| !c.source.is_import())
+        .map(|c| c.name.as_str())
+        .collect();
+
+    let production_reachable =
+        graph.get_reachable_files_where(|path| !is_dev_only_file(&relative(path)));
+
+    let mut production_usage: HashMap<&str, Vec<PathBuf>> = HashMap::new();
+    let mut dev_usage: HashMap<&str, Vec<PathBuf>> = HashMap::new();
+    let mut non_dev_packages: HashSet<&str> = HashSet::new();
+
+    for module in graph.modules.values() {
+        let dev_only = is_dev_only_file(&relative(&module.path));
+        let in_production = production_reachable.contains(&module.path);
+
+        for import in &module.imports {
+            let Some(ref package_name) = import.package_name else {
+                continue;
+            };
+
+            if dev_only {
+                dev_usage
+                    .entry(package_name.as_str())
+                    .or_default()
+                    .push(module.path.clone());
+                continue;
+            }
+
+            non_dev_packages.insert(package_name.as_str());
+
+            if in_production && !import.original.is_type_only {
+                production_usage
+                    .entry(package_name.as_str())
+                    .or_default()
+                    .push(module.path.clone());
+            }
+        }
+    }
+
+    if rules.misplaced_dev_dependencies.is_enabled() {
+        for (dep_name, version) in &pkg.dev_dependencies {
+            if ignore_deps.contains(dep_name.as_str())
+                || pkg.dependencies.contains_key(dep_name)
+                || pkg.peer_dependencies.contains_key(dep_name)
+                || pkg.optional_dependencies.contains_key(dep_name)
+            {
+                continue;
+            }
+
+            if let Some(used_in) = production_usage.get(dep_name.as_str()) {
+                misplaced_dev_deps.push(MisplacedDependency {
+                    name: dep_name.clone(),
+                    version: version.clone(),
+                    package_json: package_json_path.clone(),
+                    workspace: None,
+                    is_dev: true,
+                    used_in: sorted_unique(used_in),
+                });
+            }
+        }
+    }
+
+    if !options.production && rules.misplaced_dependencies.is_enabled() {
+        for (dep_name, version) in &pkg.dependencies {
+            if ignore_deps.contains(dep_name.as_str())
+                || non_dev_packages.contains(dep_name.as_str())
+                || credited_outside_imports.contains(dep_name.as_str())
+                || dep_name.starts_with("@types/")
+            {
+                continue;
+            }
+
+            if let Some(used_in) = dev_usage.get(dep_name.as_str()) {
+                misplaced_deps.push(MisplacedDependency {
+                    name: dep_name.clone(),
+                    version: version.clone(),
+                    package_json: package_json_path.clone(),
+                    workspace: None,
+                    is_dev: false,
+                    used_in: sorted_unique(used_in),
+                });
+            }
+        }
+    }
+
+    misplaced_deps.sort_by(|a, b| a.name.cmp(&b.name));
+    misplaced_dev_deps.sort_by(|a, b| a.name.cmp(&b.name));
+
+    (misplaced_deps, misplaced_dev_deps)
+}
+
+fn sorted_unique(paths: &[PathBuf]) -> Vec<PathBuf> {
+    let mut paths = paths.to_vec();
+    paths.sort();
+    paths.dedup();
+    paths
+}
+
+fn find_type_only_dependencies(
+    graph: &ModuleGraph,
+    config: &ResolvedConfig,
+) -> Vec<TypeOnlyDependency> {
+    let mut type_only = Vec::new();
+
+    let Some(ref pkg) = config.package_json else {
+        return type_only;
+    };
+    if !config.config.rules.type_only_dependencies.is_enabled() {
+        return type_only;
+    }
+
+    let package_json_path = config.root.join("package.json");
+
+    let ignore_deps: HashSet<&str> = config
+        .config
+        .ignore_dependencies
+        .iter()
+        .map(|s| s.as_str())
+        .collect();
+
+    let mut type_usage: HashMap<&str, Vec<PathBuf>> = HashMap::new();
+    let mut runtime_packages: HashSet<&str> = HashSet::new();
+
+    for module in graph.modules.values() {
+        for import in &module.imports {
+            let Some(ref package_name) = import.package_name else {
+                continue;
+            };
+
+            if is_type_only_import(&import.original) {
+                type_usage
+                    .entry(package_name.as_str())
+                    .or_default()
+                    .push(module.path.clone());
+            } else {
+                runtime_packages.insert(package_name.as_str());
+            }
+        }
+    }
+
+    for dep_name in pkg.dependencies.keys() {
+        if ignore_deps.contains(dep_name.as_str())
+            || runtime_packages.contains(dep_name.as_str())
+            || dep_name.starts_with("@types/")
+        {
+            continue;
+        }
+
+        if let Some(used_in) = type_usage.get(dep_name.as_str()) {
+            type_only.push(TypeOnlyDependency {
+                name: dep_name.clone(),
+                package_json: package_json_path.clone(),
+                workspace: None,
+                used_in: sorted_unique(used_in),
+            });
+        }
+    }
+
+    type_only.sort_by(|a, b| a.name.cmp(&b.name));
+    type_only
+}
+
+fn is_type_only_import(import: &Import) -> bool {
+    import.is_type_only
+        || (!import.is_side_effect
+            && !import.imported_names.is_empty()
+            && import.imported_names.iter().all(|name| name.is_type))
+}
+
+fn find_unpaired_types(graph: &ModuleGraph, config: &ResolvedConfig) -> Vec<UnpairedTypesPackage> {
+    let mut unpaired = Vec::new();
+
+    let Some(ref pkg) = config.package_json else {
+        return unpaired;
+    };
+    if !config.config.rules.unpaired_types.is_enabled() {
+        return unpaired;
+    }
+
+    let builtins = Builtins::new(&config.config.runtime);
+
+    let package_json_path = config.root.join("package.json");
+
+    let ignore_deps: HashSet<&str> = config
+        .config
+        .ignore_dependencies
+        .iter()
+        .map(|s| s.as_str())
+        .collect();
+
+    let listed: HashSet<&str> = pkg
+        .dependencies
+        .keys()
+        .chain(pkg.dev_dependencies.keys())
+        .chain(pkg.peer_dependencies.keys())
+        .chain(pkg.optional_dependencies.keys())
+        .map(|s| s.as_str())
+        .collect();
+
+    let global_types: HashSet<String> = config
+        .tsconfigs()
+        .flat_map(|ts| ts.compiler_options.types.iter())
+        .filter_map(|entry| {
+            let package_name = ModuleResolver::get_package_name(entry)?;
+            Some(types_package_target(package_name).unwrap_or_else(|| package_name.to_string()))
+        })
+        .collect();
+
+    for (deps, is_dev) in [(&pkg.dependencies, false), (&pkg.dev_dependencies, true)] {
+        for dep_name in deps.keys() {
+            let Some(package_name) = types_package_target(dep_name) else {
+                continue;
+            };
+
+            if ignore_deps.contains(dep_name.as_str())
+                || package_name == "node"
+                || listed.contains(package_name.as_str())
+                || global_types.contains(&package_name)
+                || graph.external_imports.contains_key(&package_name)
+                || builtins.contains(&package_name)
+            {
+                continue;
+            }
+
+            unpaired.push(UnpairedTypesPackage {
+                name: dep_name.clone(),
+                package_name,
+                package_json: package_json_path.clone(),
+                workspace: None,
+                is_dev,
+            });
+        }
+    }
+
+    unpaired.sort_by(|a, b| a.name.cmp(&b.name));
+    unpaired
+}
+
+fn find_missing_types(
+    graph: &ModuleGraph,
+    config: &ResolvedConfig,
+    resolver: &ModuleResolver,
+) -> Vec<MissingTypesPackage> {
+    let mut missing = Vec::new();
+
+    let Some(ref pkg) = config.package_json else {
+        return missing;
+    };
+    if !config.config.rules.missing_types.is_enabled() {
+        return missing;
+    }
+
+    let builtins = Builtins::new(&config.config.runtime);
+
+    let ignore_deps: HashSet<&str> = config
+        .config
+        .ignore_dependencies
+        .iter()
+        .map(|s| s.as_str())
+        .collect();
+
+    let listed: HashSet<&str> = pkg
+        .dependencies
+        .keys()
+        .chain(pkg.dev_dependencies.keys())
+        .chain(pkg.peer_dependencies.keys())
+        .chain(pkg.optional_dependencies.keys())
+        .map(|s| s.as_str())
+        .collect();
+
+    let mut usage: HashMap<&str, Vec<PathBuf>> = HashMap::new();
+
+    for module in graph.modules.values() {
+        if !is_typescript_file(&module.path) {
+            continue;
+        }
+
+        for import in &module.imports {
+            if import.original.imported_names.is_empty() {
+                continue;
+            }
+            if let Some(ref package_name) = import.package_name {
+                usage
+                    .entry(package_name.as_str())
+                    .or_default()
+                    .push(module.path.clone());
+            }
+        }
+    }
+
+    for (package_name, used_in) in usage {
+        if !listed.contains(package_name)
+            || ignore_deps.contains(package_name)
+            || package_name.starts_with("@types/")
+            || builtins.contains(package_name)
+        {
+            continue;
+        }
+
+        let types_package = ModuleResolver::get_types_package_name(package_name);
+        if listed.contains(types_package.as_str()) {
+            continue;
+        }
+
+        let from_dir = used_in
+            .first()
+            .and_then(|p| p.parent())
+            .unwrap_or(&config.root);
+        let Some(package_dir) = resolver.find_package_dir(package_name, from_dir) else {
+            continue;
+        };
+
+        if package_has_types(resolver, &package_dir) {
+            continue;
+        }
+
+        missing.push(MissingTypesPackage {
+            name: package_name.to_string(),
+            types_package,
+            used_in: sorted_unique(&used_in),
+        });
+    }
+
+    missing.sort_by(|a, b| a.name.cmp(&b.name));
+    missing
+}
+
+fn is_typescript_file(path: &Path) -> bool {
     matches!(
-        name,
-        "typescript" | "@types/node" | "tslib" | "core-js" | "regenerator-runtime"
+        path.extension().and_then(|e| e.to_str()),
+        Some("ts" | "tsx" | "mts" | "cts")
     )
 }
 
-fn is_dev_tool_dependency(name: &str) -> bool {
-    name.starts_with("@types/")
-        || name.starts_with("eslint")
-        || name.starts_with("prettier")
-        || matches!(
-            name,
-            "typescript"
-                | "jest"
-                | "vitest"
-                | "mocha"
-                | "chai"
-                | "ts-node"
-                | "ts-jest"
-                | "webpack"
-                | "vite"
-                | "rollup"
-                | "esbuild"
-                | "parcel"
-                | "babel"
-                | "swc"
-                | "husky"
-                | "lint-staged"
-                | "commitlint"
-        )
+fn types_package_target(name: &str) -> Option<String> {
+    let bare = name.strip_prefix("@types/")?;
+    if bare.is_empty() {
+        return None;
+    }
+
+    match bare.split_once("__") {
+        Some((scope, package)) => Some(format!("@{}/{}", scope, package)),
+        None => Some(bare.to_string()),
+    }
+}
+
+fn package_has_types(resolver: &ModuleResolver, package_dir: &Path) -> bool {
+    if let Some(pkg) = resolver.read_package_json(package_dir) {
+        if pkg.get("types").is_some()
+            || pkg.get("typings").is_some()
+            || pkg.get("typesVersions").is_some()
+        {
+            return true;
+        }
+
+        if pkg.get("exports").is_some_and(exports_have_types) {
+            return true;
+        }
+
+        if let Some(main) = pkg.get("main").and_then(|v| v.as_str()) {
+            if resolver.is_file(&package_dir.join(main).with_extension("d.ts")) {
+                return true;
+            }
+        }
+    }
+
+    resolver.is_file(&package_dir.join("index.d.ts"))
+}
+
+fn exports_have_types(exports: &serde_json::Value) -> bool {
+    match exports {
+        serde_json::Value::Object(map) => {
+            map.contains_key("types") || map.values().any(exports_have_types)
+        }
+        serde_json::Value::Array(items) => items.iter().any(exports_have_types),
+        serde_json::Value::String(target) => {
+            target.ends_with(".d.ts") || target.ends_with(".d.mts") || target.ends_with(".d.cts")
+        }
+        _ => false,
+    }
 }
 
 fn find_unlisted_dependencies(
@@ -380,6 +936,8 @@ fn find_unlisted_dependencies(
         return unlisted;
     };
 
+    let builtins = Builtins::new(&config.config.runtime);
+
     let all_deps: HashSet<&str> = if options.strict {
         pkg.dependencies.keys().map(|s| s.as_str()).collect()
     } else {
@@ -397,7 +955,7 @@ fn find_unlisted_dependencies(
             continue;
         }
 
-        if is_builtin_module(package_name) {
+        if builtins.contains(package_name) {
             continue;
         }
 
@@ -415,52 +973,6 @@ fn find_unlisted_dependencies(
     unlisted
 }
 
-fn is_builtin_module(name: &str) -> bool {
-    matches!(
-        name,
-        "assert"
-            | "buffer"
-            | "child_process"
-            | "cluster"
-            | "console"
-            | "constants"
-            | "crypto"
-            | "dgram"
-            | "dns"
-            | "domain"
-            | "events"
-            | "fs"
-            | "http"
-            | "http2"
-            | "https"
-            | "inspector"
-            | "module"
-            | "net"
-            | "os"
-            | "path"
-            | "perf_hooks"
-            | "process"
-            | "punycode"
-            | "querystring"
-            | "readline"
-            | "repl"
-            | "stream"
-            | "string_decoder"
-            | "sys"
-            | "timers"
-            | "tls"
-            | "trace_events"
-            | "tty"
-            | "url"
-            | "util"
-            | "v8"
-            | "vm"
-            | "wasi"
-            | "worker_threads"
-            | "zlib"
-    ) || name.starts_with("node:")
-}
-
 fn find_unresolved_imports(
     graph: &ModuleGraph,
     config: &ResolvedConfig,
@@ -471,6 +983,8 @@ fn find_unresolved_imports(
         return unresolved;
     };
 
+    let builtins = Builtins::new(&config.config.runtime);
+
     let all_deps: HashSet<&str> = pkg
         .dependencies
         .keys()
@@ -494,10 +1008,10 @@ fn find_unresolved_imports(
                     });
                 }
             } else if let Some(ref pkg_name) = import.package_name {
-                if !all_deps.contains(pkg_name.as_str()) && !is_builtin_module(pkg_name) {
+                if !all_deps.contains(pkg_name.as_str()) && !builtins.contains(pkg_name) {
                     continue;
                 }
-                if import.resolved_path.is_none() && !is_builtin_module(pkg_name) {
+                if import.resolved_path.is_none() && !builtins.contains(pkg_name) {
                     unresolved.push(UnresolvedImport {
                         path: module.path.clone(),
                         specifier: specifier.clone(),
@@ -512,3 +1026,411 @@ fn find_unresolved_imports(
     unresolved.sort_by(|a, b| (&a.path, a.line).cmp(&(&b.path, b.line)));
     unresolved
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::write_tree;
+
+    #[test]
+    fn test_types_package_pairing() {
+        assert_eq!(types_package_target("@types/react"), Some("react".to_string()));
+        assert_eq!(
+            types_package_target("@types/babel__core"),
+            Some("@babel/core".to_string())
+        );
+        assert_eq!(types_package_target("react"), None);
+        assert_eq!(
+            ModuleResolver::get_types_package_name("react"),
+            "@types/react"
+        );
+        assert_eq!(
+            ModuleResolver::get_types_package_name("@babel/core"),
+            "@types/babel__core"
+        );
+    }
+
+    #[test]
+    fn test_is_type_only_import() {
+        let parsed = crate::parser::parse_source(
+            "import type { A } from 'a';\nimport { type B, type C } from 'b';\nimport { type D, e } from 'd';\nimport 'side';",
+            Path::new("test.ts"),
+        )
+        .unwrap();
+
+        let flags: Vec<bool> = parsed.imports.iter().map(is_type_only_import).collect();
+        assert_eq!(flags, vec![true, true, false, false]);
+    }
+
+    #[test]
+    fn test_package_has_types() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let typed = temp.path().join("typed");
+        let exported = temp.path().join("exported");
+        let untyped = temp.path().join("untyped");
+        let versioned = temp.path().join("versioned");
+        for dir in [&typed, &exported, &untyped, &versioned] {
+            std::fs::create_dir_all(dir).unwrap();
+        }
+
+        std::fs::write(typed.join("package.json"), r#"{"types": "index.d.ts"}"#).unwrap();
+        std::fs::write(
+            exported.join("package.json"),
+            r#"{"exports": {".": {"types": "./dist/index.d.ts", "default": "./dist/index.js"}}}"#,
+        )
+        .unwrap();
+        std::fs::write(untyped.join("package.json"), r#"{"main": "lib/index.js"}"#).unwrap();
+        std::fs::write(
+            versioned.join("package.json"),
+            r#"{"main": "lib/index.js", "typesVersions": {"*": {"*": ["types/*"]}}}"#,
+        )
+        .unwrap();
+
+        let resolver = ModuleResolver::new(temp.path().to_path_buf());
+        assert!(package_has_types(&resolver, &typed));
+        assert!(package_has_types(&resolver, &exported));
+        assert!(!package_has_types(&resolver, &untyped));
+        assert!(package_has_types(&resolver, &versioned));
+    }
+
+    #[test]
+    fn test_missing_types_skips_side_effect_imports() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = temp.path();
+        write_tree(
+            root,
+            &[
+                (
+                    "package.json",
+                    r#"{"name": "app", "dependencies": {"polyfill": "1.0.0", "untyped": "1.0.0"}}"#,
+                ),
+                (
+                    "src/index.ts",
+                    "import 'polyfill';\nimport untyped from 'untyped';\nuntyped();\n",
+                ),
+            ],
+        );
+        for name in ["polyfill", "untyped"] {
+            write_tree(
+                root,
+                &[
+                    (
+                        format!("node_modules/{}/package.json", name).as_str(),
+                        r#"{"main": "index.js"}"#,
+                    ),
+                    (format!("node_modules/{}/index.js", name).as_str(), ""),
+                ],
+            );
+        }
+
+        let result = crate::analyze(root).unwrap();
+        let missing: Vec<&str> = result
+            .issues
+            .missing_types
+            .iter()
+            .map(|m| m.name.as_str())
+            .collect();
+        assert_eq!(missing, vec!["untyped"]);
+    }
+
+    #[test]
+    fn test_types_rules_off() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = temp.path();
+        write_tree(
+            root,
+            &[
+                (
+                    "package.json",
+                    r#"{"name": "app", "dependencies": {"schema": "1.0.0", "untyped": "1.0.0"}, "devDependencies": {"@types/gone": "1.0.0"}}"#,
+                ),
+                (
+                    "src/index.ts",
+                    "import type { Schema } from 'schema';\nimport untyped from 'untyped';\nuntyped({} as Schema);\n",
+                ),
+                ("node_modules/untyped/package.json", r#"{"main": "index.js"}"#),
+                ("node_modules/untyped/index.js", ""),
+            ],
+        );
+
+        let result = crate::analyze(root).unwrap();
+        assert_eq!(result.issues.type_only_dependencies.len(), 1);
+        assert_eq!(result.issues.unpaired_types.len(), 1);
+        assert_eq!(result.issues.missing_types.len(), 1);
+
+        write_tree(
+            root,
+            &[(
+                "pior.json",
+                r#"{"rules": {"typeOnlyDependencies": "off", "unpairedTypes": "off", "missingTypes": "off"}}"#,
+            )],
+        );
+        let result = crate::analyze(root).unwrap();
+        assert!(result.issues.type_only_dependencies.is_empty());
+        assert!(result.issues.unpaired_types.is_empty());
+        assert!(result.issues.missing_types.is_empty());
+    }
+
+    #[test]
+    fn test_is_dev_only_file() {
+        assert!(is_dev_only_file("src/button.test.tsx"));
+        assert!(is_dev_only_file("src/button.stories.tsx"));
+        assert!(is_dev_only_file(".storybook/preview.ts"));
+        assert!(is_dev_only_file("tests/setup.ts"));
+        assert!(is_dev_only_file("vite.config.ts"));
+        assert!(is_dev_only_file(".eslintrc.js"));
+        assert!(is_dev_only_file("packages/web/vite.config.ts"));
+        assert!(!is_dev_only_file("src/app.config.ts"));
+        assert!(!is_dev_only_file("src/firebase.config.ts"));
+        assert!(!is_dev_only_file("src/index.ts"));
+        assert!(!is_dev_only_file("src/config/index.ts"));
+        assert!(!is_dev_only_file("src/testing-utils.ts"));
+    }
+
+    #[test]
+    fn test_misplaced_dependency_rules() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = temp.path();
+        write_tree(
+            root,
+            &[
+                (
+                    "package.json",
+                    r#"{"name": "app", "dependencies": {"chai": "1.0.0"}, "devDependencies": {"lodash": "1.0.0"}}"#,
+                ),
+                ("src/index.ts", "import lodash from 'lodash';
+lodash();
+"),
+                ("src/app.config.ts", "export const port = 1;
+"),
+                ("src/index.test.ts", "import { expect } from 'chai';
+expect(1);
+"),
+            ],
+        );
+
+        let names = |deps: &[MisplacedDependency]| -> Vec<String> {
+            deps.iter().map(|d| d.name.clone()).collect()
+        };
+
+        let result = crate::analyze(root).unwrap();
+        assert_eq!(names(&result.issues.misplaced_dependencies), vec!["chai"]);
+        assert_eq!(names(&result.issues.misplaced_dev_dependencies), vec!["lodash"]);
+
+        write_tree(
+            root,
+            &[(
+                "pior.json",
+                r#"{"rules": {"misplacedDependencies": "off", "misplacedDevDependencies": "off"}}"#,
+            )],
+        );
+        let result = crate::analyze(root).unwrap();
+        assert!(result.issues.misplaced_dependencies.is_empty());
+        assert!(result.issues.misplaced_dev_dependencies.is_empty());
+    }
+
+    #[test]
+    fn test_stylesheets_and_unused_assets() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = temp.path();
+        write_tree(
+            root,
+            &[
+                ("package.json", r#"{"name": "app"}"#),
+                ("pior.json", r#"{"assets": ["src/assets"]}"#),
+                (
+                    "src/index.ts",
+                    "import './styles/main.scss';\nimport logo from './assets/logo.svg?url';\nconsole.log(logo);\n",
+                ),
+                (
+                    "src/styles/main.scss",
+                    "@use 'variables';\n.hero { background: url(../assets/hero.png); }\n",
+                ),
+                (
+                    "src/styles/_variables.scss",
+                    "@font-face { src: url('../assets/inter.woff2'); }\n",
+                ),
+            ],
+        );
+        for asset in ["logo.svg", "hero.png", "inter.woff2", "unused.png", "old.css"] {
+            write_tree(root, &[(format!("src/assets/{}", asset).as_str(), "")]);
+        }
+
+        let result = crate::analyze(root).unwrap();
+        assert!(result.issues.unresolved.is_empty());
+        assert!(result.issues.unlisted.is_empty());
+        assert!(result.issues.files.is_empty());
+
+        let assets: Vec<String> = result
+            .issues
+            .assets
+            .iter()
+            .map(|a| a.path.file_name().unwrap().to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(assets, vec!["old.css", "unused.png"]);
+    }
+
+    #[test]
+    fn test_namespace_member_usage() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = temp.path();
+        write_tree(
+            root,
+            &[
+                ("package.json", r#"{"name": "app"}"#),
+                (
+                    "src/index.ts",
+                    "import * as utils from './utils';\nimport * as all from './all';\nconst { format } = utils;\nexport const run = (opts: utils.Options) => utils.parse(format(opts));\nregister(all);\n",
+                ),
+                (
+                    "src/utils.ts",
+                    "export const parse = (s: string) => s;\nexport const format = (o: Options) => '';\nexport const stale = 1;\nexport interface Options {}\nexport type Legacy = string;\n",
+                ),
+                ("src/all.ts", "export const a = 1;\nexport const b = 2;\n"),
+            ],
+        );
+
+        let names = |result: &AnalysisResult| -> (Vec<String>, Vec<String>) {
+            (
+                result.issues.ns_exports.iter().map(|e| e.name.clone()).collect(),
+                result.issues.ns_types.iter().map(|t| t.name.clone()).collect(),
+            )
+        };
+
+        let result = crate::analyze(root).unwrap();
+        assert_eq!(names(&result), (vec![], vec![]));
+        assert!(result
+            .issues
+            .exports
+            .iter()
+            .all(|e| e.path.file_name().unwrap() == "index.ts"));
+
+        write_tree(
+            root,
+            &[
+                (
+                    "pior.json",
+                    r#"{"ignoreExportsUsedInFile": false, "rules": {"nsExports": "warn", "nsTypes": "error"}}"#,
+                ),
+            ],
+        );
+        let result = crate::analyze(root).unwrap();
+        assert_eq!(
+            names(&result),
+            (vec!["stale".to_string()], vec!["Legacy".to_string()])
+        );
+        write_tree(
+            root,
+            &[
+                (
+                    "pior.json",
+                    r#"{"ignoreExportsUsedInFile": false, "rules": {"nsExports": "warn"}}"#,
+                ),
+            ],
+        );
+        let result = crate::analyze(root).unwrap();
+        assert_eq!(names(&result), (vec!["stale".to_string()], vec![]));
+        assert!(result.issues.types.iter().all(|t| t.name != "Legacy"));
+    }
+
+    #[test]
+    fn test_glob_imports_make_files_reachable() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = temp.path();
+        write_tree(
+            root,
+            &[
+                ("package.json", r#"{"name": "app"}"#),
+                ("pior.json", r#"{"assets": ["src/icons"], "ignoreExportsUsedInFile": false}"#),
+                (
+                    "src/index.ts",
+                    "export const pages = import.meta.glob('./pages/*.ts', { import: 'default' });\nexport const icons = require.context('./icons', true, /\\.svg$/);\nexport const locale = (lang: string) => import(`./locales/${lang}.ts`);\n",
+                ),
+                ("src/pages/home.ts", "export default 1;\nexport const meta = {};\n"),
+                ("src/locales/en.ts", "export const hello = 'hi';\n"),
+                ("src/icons/logo.svg", ""),
+                ("src/orphan.ts", "export const x = 1;\n"),
+            ],
+        );
+
+        let result = crate::analyze(root).unwrap();
+        let files: Vec<String> = result
+            .issues
+            .files
+            .iter()
+            .map(|f| f.path.file_name().unwrap().to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(files, vec!["orphan.ts"]);
+        assert!(result.issues.assets.is_empty());
+
+        let exports: Vec<&str> = result
+            .issues
+            .exports
+            .iter()
+            .filter(|e| e.path.ends_with("src/pages/home.ts"))
+            .map(|e| e.name.as_str())
+            .collect();
+        assert_eq!(exports, vec!["meta"]);
+    }
+
+    #[test]
+    fn test_typescript_import_forms_make_files_reachable() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = temp.path();
+        write_tree(
+            root,
+            &[
+                ("package.json", r#"{"name": "app", "devDependencies": {"@types/node": "1.0.0"}}"#),
+                (
+                    "pior.json",
+                    r#"{"ignoreExportsUsedInFile": false, "rules": {"nsExports": "error"}}"#,
+                ),
+                (
+                    "node_modules/@types/node/package.json",
+                    r#"{"name": "@types/node", "types": "index.d.ts"}"#,
+                ),
+                ("node_modules/@types/node/index.d.ts", ""),
+                (
+                    "src/index.ts",
+                    "/// <reference path=\"globals.d.ts\" />\n/// <reference types=\"node\" />\nimport legacy = require('./legacy');\nexport function run(options: import('./options').Options): typeof import('./config') {\n    return legacy.start(options);\n}\n",
+                ),
+                ("src/globals.d.ts", "declare const VERSION: string;\n"),
+                (
+                    "src/legacy.ts",
+                    "export function start(options: unknown) { return options as any; }\nexport function stop() {}\n",
+                ),
+                ("src/options.ts", "export interface Options {}\nexport interface Unused {}\n"),
+                ("src/config.ts", "export const port = 1;\n"),
+                ("src/orphan.ts", "export const x = 1;\n"),
+            ],
+        );
+
+        let result = crate::analyze(root).unwrap();
+        let files: Vec<String> = result
+            .issues
+            .files
+            .iter()
+            .map(|f| f.path.file_name().unwrap().to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(files, vec!["orphan.ts"]);
+        assert!(result.issues.unlisted.is_empty());
+        assert!(result.issues.unresolved.is_empty());
+
+        let exports: Vec<&str> = result
+            .issues
+            .exports
+            .iter()
+            .chain(&result.issues.ns_exports)
+            .filter(|e| !e.path.ends_with("src/orphan.ts") && !e.path.ends_with("src/index.ts"))
+            .map(|e| e.name.as_str())
+            .collect();
+        assert_eq!(exports, vec!["stop"]);
+        let types: Vec<&str> = result
+            .issues
+            .types
+            .iter()
+            .map(|t| t.name.as_str())
+            .collect();
+        assert_eq!(types, vec!["Unused"]);
+    }
+}
@@ -6,9 +6,9 @@ use std::time::{Duration, SystemTime};
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 
-use crate::parser::{Export, Import, ReExport};
+use crate::parser::{Export, GlobImport, GlobKind, Import, ImportedName, ReExport};
 
-const CACHE_VERSION: u32 = 1;
+const CACHE_VERSION: u32 = 4;
 const CACHE_FILE_NAME: &str = "cache.json";
 
 #[derive(Debug, Clone)]
@@ -33,6 +33,8 @@ pub struct CacheEntry {
     pub imports: Vec<CachedImport>,
     pub exports: Vec<CachedExport>,
     pub re_exports: Vec<CachedReExport>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub glob_imports: Vec<CachedGlobImport>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -50,6 +52,37 @@ pub struct CachedImportedName {
     pub name: String,
     pub alias: Option<String>,
     pub is_type: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub members: Option<Vec<CachedMemberRef>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedMemberRef {
+    pub name: String,
+    pub line: u32,
+    pub col: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedGlobImport {
+    pub kind: CachedGlobKind,
+    pub imported_names: Vec<CachedImportedName>,
+    pub eager: bool,
+    pub line: u32,
+    pub col: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum CachedGlobKind {
+    Patterns {
+        patterns: Vec<String>,
+    },
+    Context {
+        directory: String,
+        recursive: bool,
+        filter: Option<String>,
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -229,6 +262,7 @@ impl CacheEntry {
         imports: &[Import],
         exports: &[Export],
         re_exports: &[ReExport],
+        glob_imports: &[GlobImport],
     ) -> Self {
         Self {
             content_hash,
@@ -236,6 +270,7 @@ impl CacheEntry {
             imports: imports.iter().map(CachedImport::from).collect(),
             exports: exports.iter().map(CachedExport::from).collect(),
             re_exports: re_exports.iter().map(CachedReExport::from).collect(),
+            glob_imports: glob_imports.iter().map(CachedGlobImport::from).collect(),
         }
     }
 
@@ -250,6 +285,10 @@ impl CacheEntry {
     pub fn to_re_exports(&self) -> Vec<ReExport> {
         self.re_exports.iter().map(|r| r.into()).collect()
     }
+
+    pub fn to_glob_imports(&self) -> Vec<GlobImport> {
+        self.glob_imports.iter().map(|g| g.into()).collect()
+    }
 }
 
 impl From<&Import> for CachedImport {
@@ -259,11 +298,7 @@ impl From<&Import> for CachedImport {
             imported_names: import
                 .imported_names
                 .iter()
-                .map(|n| CachedImportedName {
-                    name: n.name.clone(),
-                    alias: n.alias.clone(),
-                    is_type: n.is_type,
-                })
+                .map(CachedImportedName::from)
                 .collect(),
             is_type_only: import.is_type_only,
             is_side_effect: import.is_side_effect,
@@ -280,11 +315,7 @@ impl From<&CachedImport> for Import {
             imported_names: cached
                 .imported_names
                 .iter()
-                .map(|n| crate::parser::ImportedName {
-                    name: n.name.clone(),
-                    alias: n.alias.clone(),
-                    is_type: n.is_type,
-                })
+                .map(ImportedName::from)
                 .collect(),
             is_type_only: cached.is_type_only,
             is_side_effect: cached.is_side_effect,
@@ -294,6 +325,102 @@ impl From<&CachedImport> for Import {
     }
 }
 
+impl From<&ImportedName> for CachedImportedName {
+    fn from(name: &ImportedName) -> Self {
+        Self {
+            name: name.name.clone(),
+            alias: name.alias.clone(),
+            is_type: name.is_type,
+            members: name.members.as_ref().map(|members| {
+                members
+                    .iter()
+                    .map(|m| CachedMemberRef {
+                        name: m.name.clone(),
+                        line: m.line,
+                        col: m.col,
+                    })
+                    .collect()
+            }),
+        }
+    }
+}
+
+impl From<&CachedImportedName> for ImportedName {
+    fn from(cached: &CachedImportedName) -> Self {
+        Self {
+            name: cached.name.clone(),
+            alias: cached.alias.clone(),
+            is_type: cached.is_type,
+            members: cached.members.as_ref().map(|members| {
+                members
+                    .iter()
+                    .map(|m| crate::parser::MemberRef {
+                        name: m.name.clone(),
+                        line: m.line,
+                        col: m.col,
+                    })
+                    .collect()
+            }),
+        }
+    }
+}
+
+impl From<&GlobImport> for CachedGlobImport {
+    fn from(glob: &GlobImport) -> Self {
+        Self {
+            kind: match &glob.kind {
+                GlobKind::Patterns(patterns) => CachedGlobKind::Patterns {
+                    patterns: patterns.clone(),
+                },
+                GlobKind::Context {
+                    directory,
+                    recursive,
+                    filter,
+                } => CachedGlobKind::Context {
+                    directory: directory.clone(),
+                    recursive: *recursive,
+                    filter: filter.clone(),
+                },
+            },
+            imported_names: glob
+                .imported_names
+                .iter()
+                .map(CachedImportedName::from)
+                .collect(),
+            eager: glob.eager,
+            line: glob.line,
+            col: glob.col,
+        }
+    }
+}
+
+impl From<&CachedGlobImport> for GlobImport {
+    fn from(cached: &CachedGlobImport) -> Self {
+        Self {
+            kind: match &cached.kind {
+                CachedGlobKind::Patterns { patterns } => GlobKind::Patterns(patterns.clone()),
+                CachedGlobKind::Context {
+                    directory,
+                    recursive,
+                    filter,
+                } => GlobKind::Context {
+                    directory: directory.clone(),
+                    recursive: *recursive,
+                    filter: filter.clone(),
+                },
+            },
+            imported_names: cached
+                .imported_names
+                .iter()
+                .map(ImportedName::from)
+                .collect(),
+            eager: cached.eager,
+            line: cached.line,
+            col: cached.col,
+        }
+    }
+}
+
 impl From<&Export> for CachedExport {
     fn from(export: &Export) -> Self {
         Self {
@@ -320,6 +447,7 @@ impl From<&CachedExport> for Export {
             "Enum" => crate::parser::ExportKind::Enum,
             "Namespace" => crate::parser::ExportKind::Namespace,
             "Default" => crate::parser::ExportKind::Default,
+            "CssClass" => crate::parser::ExportKind::CssClass,
             _ => crate::parser::ExportKind::Variable,
         };
 
@@ -398,6 +526,7 @@ mod tests {
             imports: vec![],
             exports: vec![],
             re_exports: vec![],
+            glob_imports: vec![],
         };
 
         cache.insert(path.clone(), entry);
@@ -420,6 +549,7 @@ mod tests {
                 imports: vec![],
                 exports: vec![],
                 re_exports: vec![],
+                glob_imports: vec![],
             };
             cache.insert(path, entry);
             cache.save().unwrap();
@@ -0,0 +1,137 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use anyhow::{bail, Context, Result};
+
+fn git(dir: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .output()
+        .context("Failed to run git")?;
+
+    if !output.status.success() {
+        bail!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+pub fn repo_root(dir: &Path) -> Result<PathBuf> {
+    let top = git(dir, &["rev-parse", "--show-toplevel"])?;
+    let top = PathBuf::from(top.trim());
+    Ok(top.canonicalize().unwrap_or(top))
+}
+
+pub fn merge_base(dir: &Path, base_ref: &str) -> Result<String> {
+    let sha = git(dir, &["merge-base", base_ref, "HEAD"])
+        .with_context(|| format!("Could not find a common ancestor with {}", base_ref))?;
+    Ok(sha.trim().to_string())
+}
+
+pub fn changed_files(dir: &Path, base_ref: &str) -> Result<HashSet<PathBuf>> {
+    let top = repo_root(dir)?;
+    let base = merge_base(dir, base_ref)?;
+
+    let diff = git(&top, &["diff", "--name-only", "--no-renames", &base])?;
+    let untracked = git(&top, &["ls-files", "--others", "--exclude-standard"])?;
+
+    Ok(diff
+        .lines()
+        .chain(untracked.lines())
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| top.join(line))
+        .collect())
+}
+
+pub struct Worktree {
+    repo: PathBuf,
+    pub path: PathBuf,
+}
+
+impl Worktree {
+    pub fn checkout(repo: &Path, commit: &str) -> Result<Self> {
+        let short = &commit[..commit.len().min(12)];
+        let path = std::env::temp_dir().join(format!("pior-base-{}-{}", std::process::id(), short));
+
+        git(
+            repo,
+            &["worktree", "add", "--detach", "--quiet", &path.to_string_lossy(), commit],
+        )?;
+
+        let path = path.canonicalize().unwrap_or(path);
+        Ok(Self {
+            repo: repo.to_path_buf(),
+            path,
+        })
+    }
+}
+
+impl Drop for Worktree {
+    fn drop(&mut self) {
+        let _ = git(
+            &self.repo,
+            &["worktree", "remove", "--force", &self.path.to_string_lossy()],
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn init_repo() -> TempDir {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path();
+        for args in [
+            vec!["init", "--quiet", "--initial-branch=main"],
+            vec!["config", "user.email", "test@example.com"],
+            vec!["config", "user.name", "test"],
+        ] {
+            git(dir, &args).unwrap();
+        }
+        fs::write(dir.join("a.ts"), "export const a = 1;").unwrap();
+        git(dir, &["add", "."]).unwrap();
+        git(dir, &["commit", "--quiet", "-m", "init"]).unwrap();
+        temp
+    }
+
+    #[test]
+    fn test_changed_files_includes_modified_and_untracked() {
+        let temp = init_repo();
+        let dir = temp.path();
+        git(dir, &["checkout", "--quiet", "-b", "feature"]).unwrap();
+        fs::write(dir.join("a.ts"), "export const a = 2;").unwrap();
+        fs::write(dir.join("b.ts"), "export const b = 1;").unwrap();
+
+        let top = repo_root(dir).unwrap();
+        let changed = changed_files(dir, "main").unwrap();
+
+        assert!(changed.contains(&top.join("a.ts")));
+        assert!(changed.contains(&top.join("b.ts")));
+    }
+
+    #[test]
+    fn test_worktree_checkout_and_cleanup() {
+        let temp = init_repo();
+        let dir = temp.path();
+        let base = merge_base(dir, "HEAD").unwrap();
+
+        let path = {
+            let worktree = Worktree::checkout(&repo_root(dir).unwrap(), &base).unwrap();
+            assert!(worktree.path.join("a.ts").is_file());
+            worktree.path.clone()
+        };
+
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,392 @@
+pub mod git;
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+
+use crate::analyzer::AnalyzeOptions;
+use crate::{
+    AnalysisResult, Counters, DuplicateExport, Issues, MisplacedDependency, MissingTypesPackage,
+    TypeOnlyDependency, UnknownCssClass, UnlistedBinary, UnlistedDependency,
+    UnpairedTypesPackage, UnresolvedImport, UnusedAsset, UnusedClassMember, UnusedCssClass,
+    UnusedDependency, UnusedEnumMember, UnusedExport, UnusedFile, UnusedType,
+};
+
+pub trait TrackedIssue {
+    fn fingerprint(&self, root: &Path) -> String;
+
+    fn touches(&self, changed: &HashSet<PathBuf>) -> bool;
+}
+
+fn relative(path: &Path, root: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+impl TrackedIssue for UnusedFile {
+    fn fingerprint(&self, root: &Path) -> String {
+        format!("files:{}", relative(&self.path, root))
+    }
+
+    fn touches(&self, changed: &HashSet<PathBuf>) -> bool {
+        changed.contains(&self.path)
+    }
+}
+
+impl TrackedIssue for UnusedDependency {
+    fn fingerprint(&self, root: &Path) -> String {
+        let kind = if self.is_dev {
+            "devDependencies"
+        } else {
+            "dependencies"
+        };
+        format!("{}:{}:{}", kind, relative(&self.package_json, root), self.name)
+    }
+
+    fn touches(&self, changed: &HashSet<PathBuf>) -> bool {
+        changed.contains(&self.package_json)
+    }
+}
+
+impl TrackedIssue for MisplacedDependency {
+    fn fingerprint(&self, root: &Path) -> String {
+        let kind = if self.is_dev {
+            "misplacedDevDependencies"
+        } else {
+            "misplacedDependencies"
+        };
+        format!("{}:{}:{}", kind, relative(&self.package_json, root), self.name)
+    }
+
+    fn touches(&self, changed: &HashSet<PathBuf>) -> bool {
+        changed.contains(&self.package_json) || self.used_in.iter().any(|p| changed.contains(p))
+    }
+}
+
+impl TrackedIssue for TypeOnlyDependency {
+    fn fingerprint(&self, root: &Path) -> String {
+        format!(
+            "typeOnlyDependencies:{}:{}",
+            relative(&self.package_json, root),
+            self.name
+        )
+    }
+
+    fn touches(&self, changed: &HashSet<PathBuf>) -> bool {
+        changed.contains(&self.package_json) || self.used_in.iter().any(|p| changed.contains(p))
+    }
+}
+
+impl TrackedIssue for UnpairedTypesPackage {
+    fn fingerprint(&self, root: &Path) -> String {
+        format!(
+            "unpairedTypes:{}:{}",
+            relative(&self.package_json, root),
+            self.name
+        )
+    }
+
+    fn touches(&self, changed: &HashSet<PathBuf>) -> bool {
+        changed.contains(&self.package_json)
+    }
+}
+
+impl TrackedIssue for MissingTypesPackage {
+    fn fingerprint(&self, _root: &Path) -> String {
+        format!("missingTypes:{}", self.name)
+    }
+
+    fn touches(&self, changed: &HashSet<PathBuf>) -> bool {
+        self.used_in.iter().any(|p| changed.contains(p))
+    }
+}
+
+impl TrackedIssue for UnusedExport {
+    fn fingerprint(&self, root: &Path) -> String {
+        format!("exports:{}:{}", relative(&self.path, root), self.name)
+    }
+
+    fn touches(&self, changed: &HashSet<PathBuf>) -> bool {
+        changed.contains(&self.path)
+    }
+}
+
+impl TrackedIssue for UnusedType {
+    fn fingerprint(&self, root: &Path) -> String {
+        format!("types:{}:{}", relative(&self.path, root), self.name)
+    }
+
+    fn touches(&self, changed: &HashSet<PathBuf>) -> bool {
+        changed.contains(&self.path)
+    }
+}
+
+impl TrackedIssue for UnlistedDependency {
+    fn fingerprint(&self, _root: &Path) -> String {
+        format!("unlisted:{}", self.name)
+    }
+
+    fn touches(&self, changed: &HashSet<PathBuf>) -> bool {
+        self.used_in.iter().any(|p| changed.contains(p))
+    }
+}
+
+impl TrackedIssue for UnlistedBinary {
+    fn fingerprint(&self, _root: &Path) -> String {
+        format!("binaries:{}", self.name)
+    }
+
+    fn touches(&self, changed: &HashSet<PathBuf>) -> bool {
+        self.used_in.iter().any(|p| changed.contains(p))
+    }
+}
+
+impl TrackedIssue for UnresolvedImport {
+    fn fingerprint(&self, root: &Path) -> String {
+        format!("unresolved:{}:{}", relative(&self.path, root), self.specifier)
+    }
+
+    fn touches(&self, changed: &HashSet<PathBuf>) -> bool {
+        changed.contains(&self.path)
+    }
+}
+
+impl TrackedIssue for DuplicateExport {
+    fn fingerprint(&self, root: &Path) -> String {
+        let mut paths: Vec<String> = self
+            .locations
+            .iter()
+            .map(|l| relative(&l.path, root))
+            .collect();
+        paths.sort();
+        format!("duplicates:{}:{}", self.name, paths.join(","))
+    }
+
+    fn touches(&self, changed: &HashSet<PathBuf>) -> bool {
+        self.locations.iter().any(|l| changed.contains(&l.path))
+    }
+}
+
+impl TrackedIssue for UnusedEnumMember {
+    fn fingerprint(&self, root: &Path) -> String {
+        format!(
+            "enumMembers:{}:{}.{}",
+            relative(&self.path, root),
+            self.enum_name,
+            self.member_name
+        )
+    }
+
+    fn touches(&self, changed: &HashSet<PathBuf>) -> bool {
+        changed.contains(&self.path)
+    }
+}
+
+impl TrackedIssue for UnusedClassMember {
+    fn fingerprint(&self, root: &Path) -> String {
+        format!(
+            "classMembers:{}:{}.{}",
+            relative(&self.path, root),
+            self.class_name,
+            self.member_name
+        )
+    }
+
+    fn touches(&self, changed: &HashSet<PathBuf>) -> bool {
+        changed.contains(&self.path)
+    }
+}
+
+impl TrackedIssue for UnusedAsset {
+    fn fingerprint(&self, root: &Path) -> String {
+        format!("assets:{}", relative(&self.path, root))
+    }
+
+    fn touches(&self, changed: &HashSet<PathBuf>) -> bool {
+        changed.contains(&self.path)
+    }
+}
+
+impl TrackedIssue for UnusedCssClass {
+    fn fingerprint(&self, root: &Path) -> String {
+        format!("cssClasses:{}:{}", relative(&self.path, root), self.name)
+    }
+
+    fn touches(&self, changed: &HashSet<PathBuf>) -> bool {
+        changed.contains(&self.path)
+    }
+}
+
+impl TrackedIssue for UnknownCssClass {
+    fn fingerprint(&self, root: &Path) -> String {
+        format!(
+            "unknownCssClasses:{}:{}:{}",
+            relative(&self.path, root),
+            relative(&self.stylesheet, root),
+            self.name
+        )
+    }
+
+    fn touches(&self, changed: &HashSet<PathBuf>) -> bool {
+        changed.contains(&self.path) || changed.contains(&self.stylesheet)
+    }
+}
+
+fn retain_issues(issues: &mut Issues, mut keep: impl FnMut(&dyn TrackedIssue) -> bool) {
+    issues.files.retain(|i| keep(i));
+    issues.dependencies.retain(|i| keep(i));
+    issues.dev_dependencies.retain(|i| keep(i));
+    issues.misplaced_dependencies.retain(|i| keep(i));
+    issues.misplaced_dev_dependencies.retain(|i| keep(i));
+    issues.type_only_dependencies.retain(|i| keep(i));
+    issues.unpaired_types.retain(|i| keep(i));
+    issues.missing_types.retain(|i| keep(i));
+    issues.exports.retain(|i| keep(i));
+    issues.types.retain(|i| keep(i));
+    issues.unlisted.retain(|i| keep(i));
+    issues.binaries.retain(|i| keep(i));
+    issues.unresolved.retain(|i| keep(i));
+    issues.duplicates.retain(|i| keep(i));
+    issues.assets.retain(|i| keep(i));
+    issues.css_classes.retain(|i| keep(i));
+    issues.unknown_css_classes.retain(|i| keep(i));
+    issues.enum_members.retain(|i| keep(i));
+    issues.class_members.retain(|i| keep(i));
+    issues.ns_exports.retain(|i| keep(i));
+    issues.ns_types.retain(|i| keep(i));
+}
+
+pub fn fingerprints(result: &AnalysisResult, root: &Path) -> HashSet<String> {
+    let mut fingerprints = HashSet::new();
+    let mut issues = result.issues.clone();
+    retain_issues(&mut issues, |issue| {
+        fingerprints.insert(issue.fingerprint(root));
+        true
+    });
+    fingerprints
+}
+
+pub fn retain_changed(result: &mut AnalysisResult, changed: &HashSet<PathBuf>) {
+    retain_issues(&mut result.issues, |issue| issue.touches(changed));
+    result.counters = Counters::from_issues(&result.issues);
+}
+
+pub fn retain_new(result: &mut AnalysisResult, root: &Path, baseline: &HashSet<String>) {
+    retain_issues(&mut result.issues, |issue| {
+        !baseline.contains(&issue.fingerprint(root))
+    });
+    result.counters = Counters::from_issues(&result.issues);
+}
+
+pub fn analyze_base(
+    root: &Path,
+    base_ref: &str,
+    config_path: Option<&Path>,
+    options: AnalyzeOptions,
+) -> Result<HashSet<String>> {
+    let repo = git::repo_root(root)?;
+    let commit = git::merge_base(root, base_ref)?;
+    let worktree = git::Worktree::checkout(&repo, &commit)?;
+
+    let subdir = root.strip_prefix(&repo).unwrap_or(Path::new(""));
+    let base_root = worktree.path.join(subdir);
+
+    link_node_modules(root, &base_root);
+
+    let in_base = |p: &Path| match p.strip_prefix(root) {
+        Ok(relative) => base_root.join(relative),
+        Err(_) => p.to_path_buf(),
+    };
+    let base_config = config_path.map(in_base);
+
+    let options = AnalyzeOptions {
+        cache: false,
+        cache_dir: None,
+        tsconfig: options.tsconfig.as_deref().map(in_base),
+        ..options
+    };
+
+    let result = crate::analyze_with_options(&base_root, base_config.as_deref(), options)?;
+    let base_root = base_root.canonicalize().unwrap_or(base_root);
+    Ok(fingerprints(&result, &base_root))
+}
+
+#[cfg(unix)]
+fn link_node_modules(root: &Path, base_root: &Path) {
+    let source = root.join("node_modules");
+    let target = base_root.join("node_modules");
+    if source.is_dir() && base_root.is_dir() && !target.exists() {
+        let _ = std::os::unix::fs::symlink(source, target);
+    }
+}
+
+#[cfg(not(unix))]
+fn link_node_modules(_root: &Path, _base_root: &Path) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(root: &Path) -> AnalysisResult {
+        let mut result = AnalysisResult::default();
+        result.issues.files.push(UnusedFile {
+            path: root.join("src/old.ts"),
+        });
+        result.issues.exports.push(UnusedExport {
+            path: root.join("src/changed.ts"),
+            name: "helper".to_string(),
+            line: 10,
+            col: 1,
+            kind: crate::ExportKind::Function,
+            is_type: false,
+        });
+        result.issues.dependencies.push(UnusedDependency {
+            name: "lodash".to_string(),
+            package_json: root.join("package.json"),
+            workspace: None,
+            is_dev: false,
+        });
+        result.counters = Counters::from_issues(&result.issues);
+        result
+    }
+
+    #[test]
+    fn test_retain_changed() {
+        let root = Path::new("/project");
+        let mut result = sample(root);
+        let changed = HashSet::from([root.join("src/changed.ts")]);
+
+        retain_changed(&mut result, &changed);
+
+        assert!(result.issues.files.is_empty());
+        assert!(result.issues.dependencies.is_empty());
+        assert_eq!(result.issues.exports.len(), 1);
+        assert_eq!(result.counters.total(), 1);
+    }
+
+    #[test]
+    fn test_retain_new_ignores_moved_lines_and_roots() {
+        let base = sample(Path::new("/tmp/base"));
+        let baseline = fingerprints(&base, Path::new("/tmp/base"));
+
+        let root = Path::new("/project");
+        let mut result = sample(root);
+        result.issues.exports[0].line = 42;
+        result.issues.types.push(UnusedType {
+            path: root.join("src/changed.ts"),
+            name: "Props".to_string(),
+            line: 1,
+            col: 1,
+            kind: crate::TypeKind::Interface,
+        });
+
+        retain_new(&mut result, root, &baseline);
+
+        assert!(result.issues.exports.is_empty());
+        assert_eq!(result.issues.types.len(), 1);
+        assert_eq!(result.counters.total(), 1);
+    }
+}
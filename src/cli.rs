@@ -9,7 +9,11 @@ use std::path::PathBuf;
   pior ./path/to/project         Analyze specific path
   pior --files                   Only check unused files
   pior --fix                     Auto-fix all fixable issues
-  pior --format json             Output as JSON")]
+  pior --format json             Output as JSON
+  pior --changed-since origin/main
+                                 Only report issues in files changed since a ref
+  pior schema                    Print the JSON Schema of the report
+  pior lsp                       Start the language server on stdio")]
 pub struct Cli {
     #[arg(default_value = ".")]
     pub path: PathBuf,
@@ -71,6 +75,12 @@ pub struct Cli {
     #[arg(long)]
     pub trace_export: Option<String>,
 
+    #[arg(long)]
+    pub trace_dependency: Option<String>,
+
+    #[arg(long, value_name = "SPECIFIER")]
+    pub trace_resolution: Option<String>,
+
     #[arg(long)]
     pub stats: bool,
 
@@ -83,6 +93,18 @@ pub struct Cli {
     #[arg(long)]
     pub no_exit_code: bool,
 
+    #[arg(long)]
+    pub absolute_paths: bool,
+
+    #[arg(long, value_name = "REF")]
+    pub changed_since: Option<String>,
+
+    #[arg(long, requires = "changed_since")]
+    pub new_only: bool,
+
+    #[arg(long)]
+    pub from_report: Option<PathBuf>,
+
     #[arg(long)]
     pub max_issues: Option<usize>,
 
@@ -96,6 +118,8 @@ pub enum Commands {
         #[arg(long, default_value = "json")]
         format: ConfigFormat,
     },
+    Schema,
+    Lsp,
 }
 
 #[derive(ValueEnum, Clone, Debug, PartialEq, Eq, Hash)]
@@ -103,12 +127,20 @@ pub enum IssueType {
     Files,
     Dependencies,
     DevDependencies,
+    MisplacedDependencies,
+    MisplacedDevDependencies,
+    TypeOnlyDependencies,
+    UnpairedTypes,
+    MissingTypes,
     Exports,
     Types,
     Unlisted,
     Binaries,
     Unresolved,
     Duplicates,
+    Assets,
+    CssClasses,
+    UnknownCssClasses,
     EnumMembers,
     ClassMembers,
     NsExports,
@@ -144,6 +176,11 @@ impl Cli {
             return Some(vec![
                 IssueType::Dependencies,
                 IssueType::DevDependencies,
+                IssueType::MisplacedDependencies,
+                IssueType::MisplacedDevDependencies,
+                IssueType::TypeOnlyDependencies,
+                IssueType::UnpairedTypes,
+                IssueType::MissingTypes,
                 IssueType::Unlisted,
             ]);
         }
@@ -1,10 +1,12 @@
 use anyhow::{Context, Result};
 use std::fs;
+use std::collections::{HashSet, VecDeque};
 use std::path::{Path, PathBuf};
 
 use super::schema::{Config, PackageJson, ResolvedConfig, TsConfig};
+use crate::resolver::{normalize_path, Runtime};
 
-const CONFIG_FILES: &[&str] = &[
+pub(crate) const CONFIG_FILES: &[&str] = &[
     "pior.json",
     "pior.jsonc",
     ".piorrc",
@@ -12,6 +14,14 @@ const CONFIG_FILES: &[&str] = &[
 ];
 
 pub fn load_config(root: &Path, config_path: Option<&Path>) -> Result<ResolvedConfig> {
+    load_config_with_tsconfig(root, config_path, None)
+}
+
+pub fn load_config_with_tsconfig(
+    root: &Path,
+    config_path: Option<&Path>,
+    tsconfig_path: Option<&Path>,
+) -> Result<ResolvedConfig> {
     let root = root.canonicalize().unwrap_or_else(|_| root.to_path_buf());
 
     let config = if let Some(path) = config_path {
@@ -20,13 +30,18 @@ pub fn load_config(root: &Path, config_path: Option<&Path>) -> Result<ResolvedCo
         find_and_load_config(&root)?
     };
 
-    let tsconfig = find_and_load_tsconfig(&root, None)?;
+    let tsconfig = find_and_load_tsconfig(&root, tsconfig_path)?;
+    let tsconfig_references = match tsconfig {
+        Some(ref tsconfig) => load_tsconfig_references(tsconfig)?,
+        None => Vec::new(),
+    };
     let package_json = load_package_json(&root)?;
 
     Ok(ResolvedConfig {
         root,
         config,
         tsconfig,
+        tsconfig_references,
         package_json,
     })
 }
@@ -48,17 +63,34 @@ fn load_config_file(path: &Path) -> Result<Config> {
 
     let content = strip_json_comments(&content);
 
-    serde_json::from_str(&content)
-        .with_context(|| format!("Failed to parse config file: {}", path.display()))
+    let config: Config = serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse config file: {}", path.display()))?;
+
+    if config.resolve.profile().is_none() {
+        anyhow::bail!(
+            "Unknown resolve target '{}' in {}: expected node, browser, react-native or a key of resolve.profiles",
+            config.resolve.target.as_deref().unwrap_or_default(),
+            path.display()
+        );
+    }
+
+    if let Some(runtime) = config.runtime.iter().find(|r| Runtime::parse(r).is_none()) {
+        anyhow::bail!(
+            "Unknown runtime '{}' in {}: expected node, node@<version>, bun, deno or workerd",
+            runtime,
+            path.display()
+        );
+    }
+
+    Ok(config)
 }
 
 pub fn find_and_load_tsconfig(root: &Path, custom_path: Option<&Path>) -> Result<Option<TsConfig>> {
     let path = if let Some(p) = custom_path {
-        if p.exists() {
-            Some(p.to_path_buf())
-        } else {
-            return Ok(None);
+        if !p.is_file() {
+            anyhow::bail!("tsconfig not found: {}", p.display());
         }
+        Some(p.canonicalize().unwrap_or_else(|_| p.to_path_buf()))
     } else {
         find_tsconfig(root)
     };
@@ -92,26 +124,93 @@ fn load_tsconfig(path: &Path) -> Result<TsConfig> {
 
     let mut config: TsConfig = serde_json::from_str(&content)
         .with_context(|| format!("Failed to parse tsconfig: {}", path.display()))?;
+    anchor_tsconfig_paths(&mut config, path.parent().unwrap_or(Path::new(".")));
 
-    if let Some(extends) = &config.extends {
-        let parent_path = resolve_tsconfig_extends(path, extends)?;
-        if let Some(parent_path) = parent_path {
-            if parent_path.exists() {
-                let parent = load_tsconfig(&parent_path)?;
-                config = merge_tsconfig(parent, config);
-            }
+    let mut base: Option<TsConfig> = None;
+    for extends in config.extends.specifiers() {
+        let Some(parent_path) = resolve_tsconfig_extends(path, extends)? else {
+            continue;
+        };
+        if parent_path.exists() {
+            let parent = load_tsconfig(&parent_path)?;
+            base = Some(match base {
+                Some(base) => merge_tsconfig(base, parent),
+                None => parent,
+            });
         }
     }
+    if let Some(base) = base {
+        config = merge_tsconfig(base, config);
+    }
 
+    config.path = Some(path.to_path_buf());
     Ok(config)
 }
 
+fn load_tsconfig_references(tsconfig: &TsConfig) -> Result<Vec<TsConfig>> {
+    let mut seen: HashSet<PathBuf> = tsconfig.path.iter().cloned().collect();
+    let mut pending: VecDeque<PathBuf> = reference_paths(tsconfig).collect();
+    let mut references = Vec::new();
+
+    while let Some(path) = pending.pop_front() {
+        if !path.is_file() || !seen.insert(path.clone()) {
+            continue;
+        }
+        let reference = load_tsconfig(&path)?;
+        pending.extend(reference_paths(&reference));
+        references.push(reference);
+    }
+
+    Ok(references)
+}
+
+fn reference_paths(tsconfig: &TsConfig) -> impl Iterator<Item = PathBuf> + '_ {
+    let dir = tsconfig
+        .path
+        .as_deref()
+        .and_then(Path::parent)
+        .unwrap_or(Path::new("."));
+
+    tsconfig.references.iter().map(move |reference| {
+        let path = dir.join(&reference.path);
+        let path = if path.is_dir() {
+            path.join("tsconfig.json")
+        } else {
+            path
+        };
+        path.canonicalize().unwrap_or(path)
+    })
+}
+
+fn anchor_tsconfig_paths(config: &mut TsConfig, dir: &Path) {
+    let options = &mut config.compiler_options;
+    let anchor = |value: &str| normalize_path(&dir.join(value)).to_string_lossy().into_owned();
+
+    if let Some(base_url) = options.base_url.as_mut() {
+        *base_url = anchor(base_url);
+    }
+    for root_dir in &mut options.root_dirs {
+        *root_dir = anchor(root_dir);
+    }
+    for pattern in config
+        .include
+        .iter_mut()
+        .chain(&mut config.exclude)
+        .chain(&mut config.files)
+    {
+        *pattern = anchor(pattern);
+    }
+    if !options.paths.is_empty() {
+        config.paths_base = Some(dir.to_path_buf());
+    }
+}
+
 fn resolve_tsconfig_extends(from: &Path, extends: &str) -> Result<Option<PathBuf>> {
     let parent_dir = from.parent().unwrap_or(Path::new("."));
 
     if extends.starts_with("./") || extends.starts_with("../") {
         let mut path = parent_dir.join(extends);
-        if !path.extension().map_or(false, |e| e == "json") {
+        if path.extension().is_none_or(|e| e != "json") {
             path = path.with_extension("json");
         }
         return Ok(Some(path));
@@ -153,13 +252,15 @@ fn find_node_modules(from: &Path) -> Option<PathBuf> {
 }
 
 fn merge_tsconfig(parent: TsConfig, child: TsConfig) -> TsConfig {
+    let paths_inherited = child.compiler_options.paths.is_empty();
+
     TsConfig {
         compiler_options: super::schema::TsCompilerOptions {
             base_url: child
                 .compiler_options
                 .base_url
                 .or(parent.compiler_options.base_url),
-            paths: if child.compiler_options.paths.is_empty() {
+            paths: if paths_inherited {
                 parent.compiler_options.paths
             } else {
                 child.compiler_options.paths
@@ -168,6 +269,11 @@ fn merge_tsconfig(parent: TsConfig, child: TsConfig) -> TsConfig {
                 .compiler_options
                 .root_dir
                 .or(parent.compiler_options.root_dir),
+            root_dirs: if child.compiler_options.root_dirs.is_empty() {
+                parent.compiler_options.root_dirs
+            } else {
+                child.compiler_options.root_dirs
+            },
             out_dir: child
                 .compiler_options
                 .out_dir
@@ -175,6 +281,22 @@ fn merge_tsconfig(parent: TsConfig, child: TsConfig) -> TsConfig {
             strict: child.compiler_options.strict || parent.compiler_options.strict,
             module: child.compiler_options.module.or(parent.compiler_options.module),
             target: child.compiler_options.target.or(parent.compiler_options.target),
+            types: if child.compiler_options.types.is_empty() {
+                parent.compiler_options.types
+            } else {
+                child.compiler_options.types
+            },
+            import_helpers: child.compiler_options.import_helpers
+                || parent.compiler_options.import_helpers,
+            allow_importing_ts_extensions: child.compiler_options.allow_importing_ts_extensions
+                || parent.compiler_options.allow_importing_ts_extensions,
+            module_suffixes: if child.compiler_options.module_suffixes.is_empty() {
+                parent.compiler_options.module_suffixes
+            } else {
+                child.compiler_options.module_suffixes
+            },
+            preserve_symlinks: child.compiler_options.preserve_symlinks
+                || parent.compiler_options.preserve_symlinks,
         },
         include: if child.include.is_empty() {
             parent.include
@@ -191,7 +313,14 @@ fn merge_tsconfig(parent: TsConfig, child: TsConfig) -> TsConfig {
         } else {
             child.files
         },
-        extends: None,
+        extends: child.extends,
+        references: child.references,
+        path: child.path,
+        paths_base: if paths_inherited {
+            parent.paths_base
+        } else {
+            child.paths_base
+        },
     }
 }
 
@@ -210,7 +339,7 @@ fn load_package_json(root: &Path) -> Result<Option<PackageJson>> {
     Ok(Some(pkg))
 }
 
-fn strip_json_comments(input: &str) -> String {
+pub fn strip_json_comments(input: &str) -> String {
     let mut result = String::with_capacity(input.len());
     let mut chars = input.chars().peekable();
     let mut in_string = false;
@@ -284,6 +413,8 @@ pub fn generate_default_config() -> Config {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_utils::write_tree;
+    use crate::config::ResolveProfile;
 
     #[test]
     fn test_strip_single_line_comments() {
@@ -321,4 +452,120 @@ mod tests {
         assert!(!config.entry.is_empty());
         assert!(!config.project.is_empty());
     }
+
+    #[test]
+    fn test_tsconfig_references_and_extends_lists() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = temp.path().canonicalize().unwrap();
+        write_tree(
+            &root,
+            &[
+                (
+                    "tsconfig.json",
+                    r#"{"files": [], "references": [{"path": "./tsconfig.app.json"}, {"path": "./packages/ui"}]}"#,
+                ),
+                (
+                    "tsconfig.app.json",
+                    r#"{
+            // later entries win
+            "extends": ["./configs/base.json", "./configs/strict.json"],
+            "include": ["src"],
+            "references": [{"path": "./tsconfig.json"}]
+        }"#,
+                ),
+                (
+                    "configs/base.json",
+                    r#"{"compilerOptions": {"moduleSuffixes": [".web", ""], "types": ["jest"]}}"#,
+                ),
+                ("configs/strict.json", r#"{"compilerOptions": {"types": ["node"]}}"#),
+                (
+                    "packages/ui/tsconfig.json",
+                    r#"{"compilerOptions": {"baseUrl": ".", "rootDirs": ["./src", "../shared/src"]}}"#,
+                ),
+            ],
+        );
+
+        let config = load_config(&root, None).unwrap();
+        assert_eq!(config.tsconfig.as_ref().unwrap().references.len(), 2);
+
+        let paths: Vec<PathBuf> = config
+            .tsconfig_references
+            .iter()
+            .filter_map(|ts| ts.path.clone())
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                root.join("tsconfig.app.json"),
+                root.join("packages/ui/tsconfig.json")
+            ]
+        );
+
+        let app = &config.tsconfig_references[0];
+        assert_eq!(app.compiler_options.module_suffixes, vec![".web", ""]);
+        assert_eq!(app.compiler_options.types, vec!["node"]);
+        assert_eq!(app.include, vec![root.join("src").to_string_lossy()]);
+        assert_eq!(
+            config.tsconfig_references[1]
+                .compiler_options
+                .base_url
+                .as_deref(),
+            Some(root.join("packages/ui").to_string_lossy().as_ref())
+        );
+        assert_eq!(
+            config.tsconfig_references[1].compiler_options.root_dirs,
+            vec![
+                root.join("packages/ui/src").to_string_lossy(),
+                root.join("packages/shared/src").to_string_lossy(),
+            ]
+        );
+
+        let custom =
+            load_config_with_tsconfig(&root, None, Some(&root.join("tsconfig.app.json"))).unwrap();
+        assert_eq!(
+            custom.tsconfig.unwrap().path,
+            Some(root.join("tsconfig.app.json"))
+        );
+        assert!(load_config_with_tsconfig(&root, None, Some(&root.join("missing.json"))).is_err());
+    }
+
+    #[test]
+    fn test_resolve_profiles() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = temp.path().canonicalize().unwrap();
+        let write = |content: &str| std::fs::write(root.join("pior.json"), content).unwrap();
+
+        let config = load_config(&root, None).unwrap();
+        assert_eq!(config.config.resolve.profile(), Some(ResolveProfile::default()));
+
+        write(r#"{"resolve": {"target": "react-native"}}"#);
+        let profile = load_config(&root, None).unwrap().config.resolve.profile().unwrap();
+        assert_eq!(profile.main_fields[0], "react-native");
+        assert_eq!(profile.alias_fields, vec!["react-native", "browser"]);
+
+        write(
+            r#"{"resolve": {"target": "electron", "aliasFields": [], "profiles": {"electron": {"mainFields": ["electron", "main"], "aliasFields": ["browser"]}}}}"#,
+        );
+        let profile = load_config(&root, None).unwrap().config.resolve.profile().unwrap();
+        assert_eq!(profile.main_fields, vec!["electron", "main"]);
+        assert!(profile.alias_fields.is_empty());
+
+        write(r#"{"resolve": {"target": "deno"}}"#);
+        let err = load_config(&root, None).unwrap_err().to_string();
+        assert!(err.contains("Unknown resolve target 'deno'"));
+    }
+
+    #[test]
+    fn test_runtime() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = temp.path().canonicalize().unwrap();
+        let write = |content: &str| std::fs::write(root.join("pior.json"), content).unwrap();
+
+        write(r#"{"runtime": ["node@18.17", "bun"]}"#);
+        assert_eq!(load_config(&root, None).unwrap().config.runtime, vec!["node@18.17", "bun"]);
+
+        write(r#"{"runtime": ["electron"]}"#);
+        let err = load_config(&root, None).unwrap_err().to_string();
+        assert!(err.contains("Unknown runtime 'electron'"));
+    }
 }
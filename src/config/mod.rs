@@ -1,8 +1,13 @@
 mod loader;
 mod schema;
 
-pub use loader::{find_and_load_tsconfig, generate_default_config, load_config};
+pub use loader::{
+    find_and_load_tsconfig, generate_default_config, load_config, load_config_with_tsconfig,
+    strip_json_comments,
+};
+pub(crate) use loader::CONFIG_FILES;
 pub use schema::{
-    Config, PackageJson, PluginConfig, PluginSetting, PluginsConfig, ResolvedConfig, RuleLevel,
-    RulesConfig, TsCompilerOptions, TsConfig, WorkspaceConfig, WorkspacesField,
+    Config, PackageJson, PluginConfig, PluginSetting, PluginsConfig, ResolveConfig, ResolveProfile,
+    ResolveProfileConfig, ResolvedConfig, RuleLevel, RulesConfig, TsCompilerOptions, TsConfig, TsExtends, TsProjectReference, WorkspaceConfig,
+    WorkspacesField,
 };
@@ -32,6 +32,9 @@ pub struct Config {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub ignore_files: Vec<String>,
 
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub assets: Vec<String>,
+
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub ignore_dependencies: Vec<String>,
 
@@ -55,6 +58,12 @@ pub struct Config {
 
     #[serde(default)]
     pub plugins: PluginsConfig,
+
+    #[serde(default, skip_serializing_if = "ResolveConfig::is_empty")]
+    pub resolve: ResolveConfig,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub runtime: Vec<String>,
 }
 
 fn default_true() -> bool {
@@ -73,6 +82,21 @@ pub struct RulesConfig {
     #[serde(default = "default_error")]
     pub dev_dependencies: RuleLevel,
 
+    #[serde(default = "default_warn")]
+    pub misplaced_dependencies: RuleLevel,
+
+    #[serde(default = "default_error")]
+    pub misplaced_dev_dependencies: RuleLevel,
+
+    #[serde(default = "default_warn")]
+    pub type_only_dependencies: RuleLevel,
+
+    #[serde(default = "default_warn")]
+    pub unpaired_types: RuleLevel,
+
+    #[serde(default = "default_warn")]
+    pub missing_types: RuleLevel,
+
     #[serde(default = "default_warn")]
     pub exports: RuleLevel,
 
@@ -91,6 +115,15 @@ pub struct RulesConfig {
     #[serde(default = "default_warn")]
     pub duplicates: RuleLevel,
 
+    #[serde(default = "default_warn")]
+    pub assets: RuleLevel,
+
+    #[serde(default = "default_warn")]
+    pub css_classes: RuleLevel,
+
+    #[serde(default = "default_error")]
+    pub unknown_css_classes: RuleLevel,
+
     #[serde(default = "default_off")]
     pub enum_members: RuleLevel,
 
@@ -110,12 +143,20 @@ impl Default for RulesConfig {
             files: default_error(),
             dependencies: default_error(),
             dev_dependencies: default_error(),
+            misplaced_dependencies: default_warn(),
+            misplaced_dev_dependencies: default_error(),
+            type_only_dependencies: default_warn(),
+            unpaired_types: default_warn(),
+            missing_types: default_warn(),
             exports: default_warn(),
             types: default_error(),
             unlisted: default_error(),
             binaries: default_warn(),
             unresolved: default_error(),
             duplicates: default_warn(),
+            assets: default_warn(),
+            css_classes: default_warn(),
+            unknown_css_classes: default_error(),
             enum_members: default_off(),
             class_members: default_off(),
             ns_exports: default_off(),
@@ -207,6 +248,32 @@ pub struct PluginsConfig {
 
     #[serde(default)]
     pub playwright: PluginSetting,
+
+    #[serde(default)]
+    pub babel: PluginSetting,
+}
+
+impl PluginsConfig {
+    pub fn is_enabled(&self, name: &str) -> bool {
+        let setting = match name {
+            "next" => &self.next,
+            "vite" => &self.vite,
+            "jest" => &self.jest,
+            "vitest" => &self.vitest,
+            "eslint" => &self.eslint,
+            "prettier" => &self.prettier,
+            "tailwind" => &self.tailwind,
+            "webpack" => &self.webpack,
+            "rollup" => &self.rollup,
+            "esbuild" => &self.esbuild,
+            "storybook" => &self.storybook,
+            "cypress" => &self.cypress,
+            "playwright" => &self.playwright,
+            "babel" => &self.babel,
+            _ => return true,
+        };
+        setting.is_enabled()
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -236,14 +303,113 @@ pub struct PluginConfig {
     pub config: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolveConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub main_fields: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias_fields: Option<Vec<String>>,
+
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub profiles: HashMap<String, ResolveProfileConfig>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolveProfileConfig {
+    pub main_fields: Option<Vec<String>>,
+
+    #[serde(default)]
+    pub alias_fields: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolveProfile {
+    pub main_fields: Vec<String>,
+    pub alias_fields: Vec<String>,
+}
+
+impl Default for ResolveProfile {
+    fn default() -> Self {
+        Self::from_fields(&["module", "main", "types"], &[])
+    }
+}
+
+impl ResolveProfile {
+    pub fn builtin(target: &str) -> Option<Self> {
+        match target {
+            "node" => Some(Self::default()),
+            "browser" => Some(Self::from_fields(
+                &["browser", "module", "main", "types"],
+                &["browser"],
+            )),
+            "react-native" => Some(Self::from_fields(
+                &["react-native", "browser", "module", "main", "types"],
+                &["react-native", "browser"],
+            )),
+            _ => None,
+        }
+    }
+
+    fn from_fields(main_fields: &[&str], alias_fields: &[&str]) -> Self {
+        Self {
+            main_fields: main_fields.iter().map(|f| f.to_string()).collect(),
+            alias_fields: alias_fields.iter().map(|f| f.to_string()).collect(),
+        }
+    }
+}
+
+impl ResolveConfig {
+    fn is_empty(&self) -> bool {
+        self.target.is_none()
+            && self.main_fields.is_none()
+            && self.alias_fields.is_none()
+            && self.profiles.is_empty()
+    }
+
+    pub fn profile(&self) -> Option<ResolveProfile> {
+        let target = self.target.as_deref().unwrap_or("node");
+        let mut profile = match self.profiles.get(target) {
+            Some(custom) => ResolveProfile {
+                main_fields: custom
+                    .main_fields
+                    .clone()
+                    .unwrap_or_else(|| ResolveProfile::default().main_fields),
+                alias_fields: custom.alias_fields.clone(),
+            },
+            None => ResolveProfile::builtin(target)?,
+        };
+
+        if let Some(main_fields) = &self.main_fields {
+            profile.main_fields = main_fields.clone();
+        }
+        if let Some(alias_fields) = &self.alias_fields {
+            profile.alias_fields = alias_fields.clone();
+        }
+        Some(profile)
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct ResolvedConfig {
     pub root: PathBuf,
     pub config: Config,
     pub tsconfig: Option<TsConfig>,
+    pub tsconfig_references: Vec<TsConfig>,
     pub package_json: Option<PackageJson>,
 }
 
+impl ResolvedConfig {
+    pub fn tsconfigs(&self) -> impl Iterator<Item = &TsConfig> {
+        self.tsconfig.iter().chain(&self.tsconfig_references)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct TsConfig {
@@ -259,7 +425,41 @@ pub struct TsConfig {
     #[serde(default)]
     pub files: Vec<String>,
 
-    pub extends: Option<String>,
+    #[serde(default)]
+    pub extends: TsExtends,
+
+    #[serde(default)]
+    pub references: Vec<TsProjectReference>,
+
+    #[serde(skip)]
+    pub path: Option<PathBuf>,
+
+    #[serde(skip)]
+    pub paths_base: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(untagged)]
+pub enum TsExtends {
+    #[default]
+    None,
+    Single(String),
+    List(Vec<String>),
+}
+
+impl TsExtends {
+    pub fn specifiers(&self) -> Vec<&str> {
+        match self {
+            TsExtends::None => vec![],
+            TsExtends::Single(extends) => vec![extends.as_str()],
+            TsExtends::List(list) => list.iter().map(|s| s.as_str()).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TsProjectReference {
+    pub path: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -272,6 +472,9 @@ pub struct TsCompilerOptions {
 
     pub root_dir: Option<String>,
 
+    #[serde(default)]
+    pub root_dirs: Vec<String>,
+
     pub out_dir: Option<String>,
 
     #[serde(default)]
@@ -282,6 +485,20 @@ pub struct TsCompilerOptions {
 
     #[serde(default)]
     pub target: Option<String>,
+
+    #[serde(default)]
+    pub types: Vec<String>,
+
+    #[serde(default)]
+    pub import_helpers: bool,
+
+    #[serde(default)]
+    pub allow_importing_ts_extensions: bool,
+
+    #[serde(default)]
+    pub module_suffixes: Vec<String>,
+    #[serde(default)]
+    pub preserve_symlinks: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -25,7 +25,7 @@ pub fn fix_dependencies(root: &Path, result: &AnalysisResult) -> Result<(Vec<Str
     if let Some(deps) = pkg.get_mut("dependencies") {
         if let Some(deps_obj) = deps.as_object_mut() {
             for unused in &result.issues.dependencies {
-                if deps_obj.remove(&unused.name).is_some() {
+                if deps_obj.shift_remove(&unused.name).is_some() {
                     deps_removed.push(unused.name.clone());
                 }
             }
@@ -35,7 +35,7 @@ pub fn fix_dependencies(root: &Path, result: &AnalysisResult) -> Result<(Vec<Str
     if let Some(deps) = pkg.get_mut("devDependencies") {
         if let Some(deps_obj) = deps.as_object_mut() {
             for unused in &result.issues.dev_dependencies {
-                if deps_obj.remove(&unused.name).is_some() {
+                if deps_obj.shift_remove(&unused.name).is_some() {
                     dev_deps_removed.push(unused.name.clone());
                 }
             }
@@ -51,11 +51,165 @@ pub fn fix_dependencies(root: &Path, result: &AnalysisResult) -> Result<(Vec<Str
     Ok((deps_removed, dev_deps_removed))
 }
 
+pub fn fix_misplaced_dependencies(
+    root: &Path,
+    result: &AnalysisResult,
+) -> Result<(Vec<String>, Vec<String>)> {
+    let package_json_path = root.join("package.json");
+
+    if !package_json_path.exists() {
+        return Ok((vec![], vec![]));
+    }
+
+    if result.issues.misplaced_dependencies.is_empty()
+        && result.issues.misplaced_dev_dependencies.is_empty()
+    {
+        return Ok((vec![], vec![]));
+    }
+
+    let content = std::fs::read_to_string(&package_json_path)
+        .with_context(|| format!("Failed to read package.json: {}", package_json_path.display()))?;
+
+    let mut pkg: serde_json::Value = serde_json::from_str(&content)
+        .with_context(|| "Failed to parse package.json")?;
+
+    let mut deps_moved = Vec::new();
+    let mut dev_deps_moved = Vec::new();
+
+    for misplaced in &result.issues.misplaced_dependencies {
+        if move_dependency(&mut pkg, &misplaced.name, "dependencies", "devDependencies") {
+            deps_moved.push(misplaced.name.clone());
+        }
+    }
+
+    for misplaced in &result.issues.misplaced_dev_dependencies {
+        if move_dependency(&mut pkg, &misplaced.name, "devDependencies", "dependencies") {
+            dev_deps_moved.push(misplaced.name.clone());
+        }
+    }
+
+    if !deps_moved.is_empty() || !dev_deps_moved.is_empty() {
+        let updated_content = serde_json::to_string_pretty(&pkg)?;
+        std::fs::write(&package_json_path, updated_content + "\n")
+            .with_context(|| "Failed to write package.json")?;
+    }
+
+    Ok((deps_moved, dev_deps_moved))
+}
+
+pub fn add_dependency(root: &Path, name: &str, dev: bool) -> Result<bool> {
+    let package_json_path = root.join("package.json");
+
+    let Some(version) = installed_version(root, name) else {
+        anyhow::bail!(
+            "'{}' is not installed; install it with your package manager to add it",
+            name
+        );
+    };
+
+    let content = std::fs::read_to_string(&package_json_path)
+        .with_context(|| format!("Failed to read package.json: {}", package_json_path.display()))?;
+
+    let mut pkg: serde_json::Value = serde_json::from_str(&content)
+        .with_context(|| "Failed to parse package.json")?;
+
+    let section = if dev { "devDependencies" } else { "dependencies" };
+
+    let Some(pkg_obj) = pkg.as_object_mut() else {
+        return Ok(false);
+    };
+
+    let target = pkg_obj
+        .entry(section)
+        .or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()));
+
+    let Some(target_obj) = target.as_object_mut() else {
+        return Ok(false);
+    };
+
+    if target_obj.contains_key(name) {
+        return Ok(false);
+    }
+    insert_dependency(target_obj, name, serde_json::Value::String(format!("^{}", version)));
+
+    let updated_content = serde_json::to_string_pretty(&pkg)?;
+    std::fs::write(&package_json_path, updated_content + "\n")
+        .with_context(|| "Failed to write package.json")?;
+
+    Ok(true)
+}
+
+fn installed_version(root: &Path, name: &str) -> Option<String> {
+    let resolver = crate::resolver::ModuleResolver::for_packages(root);
+    let package_dir = resolver.find_package_dir(name, root)?;
+    let manifest = resolver.read_package_json(&package_dir)?;
+    manifest.get("version")?.as_str().map(|v| v.to_string())
+}
+
+fn move_dependency(pkg: &mut serde_json::Value, name: &str, from: &str, to: &str) -> bool {
+    let Some(version) = pkg
+        .get_mut(from)
+        .and_then(|section| section.as_object_mut())
+        .and_then(|section| section.shift_remove(name))
+    else {
+        return false;
+    };
+
+    let Some(pkg_obj) = pkg.as_object_mut() else {
+        return false;
+    };
+
+    let target = pkg_obj
+        .entry(to)
+        .or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()));
+
+    match target.as_object_mut() {
+        Some(target_obj) => {
+            insert_dependency(target_obj, name, version);
+            true
+        }
+        None => false,
+    }
+}
+
+fn insert_dependency(
+    section: &mut serde_json::Map<String, serde_json::Value>,
+    name: &str,
+    version: serde_json::Value,
+) {
+    let sorted = section.keys().zip(section.keys().skip(1)).all(|(a, b)| a <= b);
+    section.insert(name.to_string(), version);
+    if sorted {
+        section.sort_keys();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use tempfile::TempDir;
 
+    #[test]
+    fn test_add_dependency_uses_installed_version() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(temp.path().join("package.json"), r#"{"name": "app"}"#).unwrap();
+        std::fs::create_dir_all(temp.path().join("node_modules/zod")).unwrap();
+        std::fs::write(
+            temp.path().join("node_modules/zod/package.json"),
+            r#"{"name": "zod", "version": "3.22.4"}"#,
+        )
+        .unwrap();
+
+        assert!(add_dependency(temp.path(), "zod", false).unwrap());
+        assert!(!add_dependency(temp.path(), "zod", false).unwrap());
+        assert!(add_dependency(temp.path(), "left-pad", true).is_err());
+
+        let content = std::fs::read_to_string(temp.path().join("package.json")).unwrap();
+        let pkg: serde_json::Value = serde_json::from_str(&content).unwrap();
+        assert_eq!(pkg["dependencies"]["zod"], "^3.22.4");
+        assert!(pkg.get("devDependencies").is_none());
+    }
+
     #[test]
     fn test_fix_dependencies_no_package_json() {
         let temp = TempDir::new().unwrap();
@@ -93,4 +247,103 @@ mod tests {
         assert!(updated["dependencies"]["react"].is_string());
         assert!(updated["dependencies"]["lodash"].is_null());
     }
+
+    #[test]
+    fn test_fix_dependencies_keeps_key_order() {
+        let temp = TempDir::new().unwrap();
+        let pkg_path = temp.path().join("package.json");
+
+        std::fs::write(
+            &pkg_path,
+            r#"{"name": "app", "scripts": {}, "dependencies": {"zzz": "1", "aaa": "1", "lodash": "1", "react": "1", "bbb": "1"}}"#,
+        )
+        .unwrap();
+
+        let mut result = AnalysisResult::default();
+        result.issues.dependencies.push(crate::UnusedDependency {
+            name: "aaa".to_string(),
+            package_json: pkg_path.clone(),
+            workspace: None,
+            is_dev: false,
+        });
+        fix_dependencies(temp.path(), &result).unwrap();
+
+        let updated: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&pkg_path).unwrap()).unwrap();
+        let keys = |value: &serde_json::Value| -> Vec<String> {
+            value.as_object().unwrap().keys().cloned().collect()
+        };
+        assert_eq!(keys(&updated), vec!["name", "scripts", "dependencies"]);
+        assert_eq!(
+            keys(&updated["dependencies"]),
+            vec!["zzz", "lodash", "react", "bbb"]
+        );
+    }
+
+    #[test]
+    fn test_fix_misplaced_dependencies_preserves_version() {
+        let temp = TempDir::new().unwrap();
+        let pkg_path = temp.path().join("package.json");
+
+        std::fs::write(
+            &pkg_path,
+            r#"{"dependencies": {"msw": "^2.1.0"}, "devDependencies": {"zod": "~3.22.4"}}"#,
+        )
+        .unwrap();
+
+        let misplaced = |name: &str, version: &str, is_dev: bool| crate::MisplacedDependency {
+            name: name.to_string(),
+            version: version.to_string(),
+            package_json: pkg_path.clone(),
+            workspace: None,
+            is_dev,
+            used_in: vec![],
+        };
+
+        let mut result = AnalysisResult::default();
+        result.issues.misplaced_dependencies.push(misplaced("msw", "^2.1.0", false));
+        result.issues.misplaced_dev_dependencies.push(misplaced("zod", "~3.22.4", true));
+
+        let (moved, dev_moved) = fix_misplaced_dependencies(temp.path(), &result).unwrap();
+        assert_eq!(moved, vec!["msw"]);
+        assert_eq!(dev_moved, vec!["zod"]);
+
+        let updated: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&pkg_path).unwrap()).unwrap();
+        assert_eq!(updated["devDependencies"]["msw"], "^2.1.0");
+        assert_eq!(updated["dependencies"]["zod"], "~3.22.4");
+        assert!(updated["dependencies"]["msw"].is_null());
+        assert!(updated["devDependencies"]["zod"].is_null());
+    }
+
+    #[test]
+    fn test_fix_misplaced_dependencies_keeps_sections_sorted() {
+        let temp = TempDir::new().unwrap();
+        let pkg_path = temp.path().join("package.json");
+
+        std::fs::write(
+            &pkg_path,
+            r#"{"dependencies": {"aaa": "1", "lodash": "1", "react": "1", "zzz": "1"}, "devDependencies": {"jest": "1", "vitest": "1"}}"#,
+        )
+        .unwrap();
+
+        let mut result = AnalysisResult::default();
+        result.issues.misplaced_dependencies.push(crate::MisplacedDependency {
+            name: "lodash".to_string(),
+            version: "1".to_string(),
+            package_json: pkg_path.clone(),
+            workspace: None,
+            is_dev: false,
+            used_in: vec![],
+        });
+        fix_misplaced_dependencies(temp.path(), &result).unwrap();
+
+        let updated: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&pkg_path).unwrap()).unwrap();
+        let keys = |value: &serde_json::Value| -> Vec<String> {
+            value.as_object().unwrap().keys().cloned().collect()
+        };
+        assert_eq!(keys(&updated["dependencies"]), vec!["aaa", "react", "zzz"]);
+        assert_eq!(keys(&updated["devDependencies"]), vec!["jest", "lodash", "vitest"]);
+    }
 }
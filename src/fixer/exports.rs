@@ -3,7 +3,7 @@ use anyhow::{Context, Result};
 use std::collections::HashMap;
 use std::path::Path;
 
-use super::ExportRemoval;
+use super::{ExportRemoval, TextChange};
 
 pub fn fix_exports(_root: &Path, result: &AnalysisResult) -> Result<Vec<ExportRemoval>> {
     if result.issues.exports.is_empty() && result.issues.types.is_empty() {
@@ -60,6 +60,91 @@ pub fn fix_exports(_root: &Path, result: &AnalysisResult) -> Result<Vec<ExportRe
     Ok(removed)
 }
 
+const DECLARATION_KEYWORDS: &[&str] = &[
+    "const",
+    "let",
+    "var",
+    "function",
+    "async",
+    "class",
+    "abstract",
+    "interface",
+    "type",
+    "enum",
+    "declare",
+    "namespace",
+];
+
+pub fn unexport_edit(content: &str, line: u32, col: u32, name: &str) -> Option<TextChange> {
+    let text = content.lines().nth(line.checked_sub(1)? as usize)?;
+    let start = text
+        .char_indices()
+        .nth(col.checked_sub(1)? as usize)
+        .map(|(i, _)| i)?;
+    let rest = &text[start..];
+
+    if rest.starts_with("export {") || rest.starts_with("export type {") {
+        let new_text = remove_from_named_export(text, name)?;
+        return Some(TextChange {
+            line,
+            start_col: 1,
+            end_col: text.chars().count() as u32 + 1,
+            new_text,
+        });
+    }
+
+    let after_export = rest.strip_prefix("export")?;
+    let mut declaration = after_export.trim_start();
+    if declaration.len() == after_export.len() {
+        return None;
+    }
+
+    if let Some(after_default) = declaration.strip_prefix("default") {
+        let after_default = after_default.trim_start();
+        if !is_named_declaration(after_default) {
+            return None;
+        }
+        declaration = after_default;
+    }
+
+    let keyword = declaration
+        .split(|c: char| !c.is_alphanumeric())
+        .next()
+        .unwrap_or("");
+    if !DECLARATION_KEYWORDS.contains(&keyword) {
+        return None;
+    }
+
+    let removed = rest[..rest.len() - declaration.len()].chars().count() as u32;
+    Some(TextChange {
+        line,
+        start_col: col,
+        end_col: col + removed,
+        new_text: String::new(),
+    })
+}
+
+/// Anonymous declarations can't drop `export default` without a syntax error.
+fn is_named_declaration(declaration: &str) -> bool {
+    let rest = match (
+        declaration.strip_prefix("function"),
+        declaration.strip_prefix("class"),
+    ) {
+        (Some(rest), _) => rest.trim_start().trim_start_matches('*'),
+        (_, Some(rest)) => rest,
+        _ => return false,
+    };
+    if rest.len() == rest.trim_start().len() && !declaration.starts_with("function*") {
+        return false;
+    }
+    let name = rest
+        .trim_start()
+        .split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+        .next()
+        .unwrap_or("");
+    !name.is_empty() && name != "extends"
+}
+
 fn remove_export(content: &str, export_name: &str) -> Option<String> {
     let lines: Vec<&str> = content.lines().collect();
     let mut result = Vec::new();
@@ -187,8 +272,7 @@ fn remove_from_named_export(line: &str, name: &str) -> Option<String> {
         .filter(|e| {
             let export_name = e
                 .split_whitespace()
-                .filter(|&w| w != "type")
-                .next()
+                .find(|&w| w != "type")
                 .unwrap_or("");
             export_name != name
         })
@@ -205,6 +289,35 @@ fn remove_from_named_export(line: &str, name: &str) -> Option<String> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_unexport_edit_declaration() {
+        let content = "const a = 1;\n  export async function load() {}\n";
+        let edit = unexport_edit(content, 2, 3, "load").unwrap();
+        assert_eq!(
+            edit,
+            TextChange {
+                line: 2,
+                start_col: 3,
+                end_col: 10,
+                new_text: String::new(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_unexport_edit_default_and_named() {
+        let edit = unexport_edit("export default class Foo {}", 1, 1, "default").unwrap();
+        assert_eq!(edit.end_col, 16);
+
+        let edit = unexport_edit("export { a, b };", 1, 1, "a").unwrap();
+        assert_eq!(edit.new_text, "export { b };");
+
+        assert!(unexport_edit("export default foo;", 1, 1, "default").is_none());
+        assert!(unexport_edit("export default function () {}", 1, 1, "default").is_none());
+        assert!(unexport_edit("export default class extends Base {}", 1, 1, "default").is_none());
+        assert!(unexport_edit("export default function* gen() {}", 1, 1, "default").is_some());
+    }
+
     #[test]
     fn test_remove_export_function() {
         let content = r#"export function foo() {}
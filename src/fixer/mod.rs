@@ -1,8 +1,8 @@
 mod dependencies;
 mod exports;
 
-pub use dependencies::fix_dependencies;
-pub use exports::fix_exports;
+pub use dependencies::{add_dependency, fix_dependencies, fix_misplaced_dependencies};
+pub use exports::{fix_exports, unexport_edit};
 
 use crate::AnalysisResult;
 use anyhow::Result;
@@ -11,6 +11,8 @@ use std::path::Path;
 pub struct FixResult {
     pub dependencies_removed: Vec<String>,
     pub dev_dependencies_removed: Vec<String>,
+    pub dependencies_moved: Vec<String>,
+    pub dev_dependencies_moved: Vec<String>,
     pub exports_removed: Vec<ExportRemoval>,
 }
 
@@ -20,13 +22,24 @@ pub struct ExportRemoval {
     pub line: u32,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextChange {
+    pub line: u32,
+    pub start_col: u32,
+    pub end_col: u32,
+    pub new_text: String,
+}
+
 pub fn fix_all(root: &Path, result: &AnalysisResult) -> Result<FixResult> {
     let (dependencies_removed, dev_dependencies_removed) = fix_dependencies(root, result)?;
+    let (dependencies_moved, dev_dependencies_moved) = fix_misplaced_dependencies(root, result)?;
     let exports_removed = fix_exports(root, result)?;
 
     Ok(FixResult {
         dependencies_removed,
         dev_dependencies_removed,
+        dependencies_moved,
+        dev_dependencies_moved,
         exports_removed,
     })
 }
@@ -1,16 +1,20 @@
 use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 
 use anyhow::Result;
-use globset::{Glob, GlobSetBuilder};
+use globset::{Glob, GlobSet, GlobSetBuilder};
 use ignore::WalkBuilder;
 use rayon::prelude::*;
 
 use crate::cache::{Cache, CacheEntry, compute_content_hash, get_modified_time};
-use crate::config::ResolvedConfig;
-use crate::parser::{parse_file, Export, Import, ParsedModule, ReExport};
-use crate::resolver::ModuleResolver;
+use crate::config::{ResolvedConfig, TsConfig};
+use crate::parser::css::is_stylesheet;
+use crate::parser::mdx::is_markdown;
+use crate::parser::{parse_file, Export, GlobImport, Import, ParsedModule, ReExport};
+use crate::plugins::run_project_plugins;
+use crate::resolver::{Builtins, BundlerAlias, ModuleResolver, PnpManifest};
+use crate::workspace::{build_workspace_dependency_map, WorkspaceDiscovery};
 
 #[derive(Debug)]
 pub struct ModuleGraph {
@@ -25,6 +29,7 @@ pub struct Module {
     pub imports: Vec<ResolvedImport>,
     pub exports: Vec<Export>,
     pub re_exports: Vec<ResolvedReExport>,
+    pub glob_imports: Vec<GlobImport>,
 }
 
 #[derive(Debug)]
@@ -38,6 +43,14 @@ pub struct ResolvedImport {
     pub original: Import,
     pub resolved_path: Option<PathBuf>,
     pub package_name: Option<String>,
+    pub from_glob: bool,
+}
+
+#[derive(Debug)]
+pub struct ResolutionTrace {
+    pub from: PathBuf,
+    pub resolved: Option<PathBuf>,
+    pub lines: Vec<String>,
 }
 
 #[derive(Debug, Default)]
@@ -45,6 +58,8 @@ pub struct BuildOptions {
     pub cache: Option<Cache>,
     pub production: bool,
     pub strict: bool,
+    pub overlay: HashMap<PathBuf, String>,
+    pub aliases: Vec<BundlerAlias>,
 }
 
 pub fn build_graph(config: &ResolvedConfig) -> Result<ModuleGraph> {
@@ -52,63 +67,53 @@ pub fn build_graph(config: &ResolvedConfig) -> Result<ModuleGraph> {
 }
 
 pub fn build_graph_with_options(config: &ResolvedConfig, options: BuildOptions) -> Result<ModuleGraph> {
+    let resolver = create_resolver(config, &options.aliases);
+    let matcher = ProjectMatcher::new(config, options.production)?;
+    build_graph_from_parts(config, options, &matcher, &resolver)
+}
+
+pub(crate) fn build_graph_from_parts(
+    config: &ResolvedConfig,
+    options: BuildOptions,
+    matcher: &ProjectMatcher,
+    resolver: &ModuleResolver,
+) -> Result<ModuleGraph> {
     let root = &config.root;
 
-    let resolver = create_resolver(config);
-    let project_files = collect_project_files(root, config, &options)?;
+    let project_files = matcher.walk(root);
     let entry_points = find_entry_points(root, config, &project_files);
 
-    let cache = options.cache.map(|c| Mutex::new(c));
+    let cache = options.cache.map(Mutex::new);
 
-    let parsed_modules: Vec<(PathBuf, ParsedModule)> = project_files
+    let mut parsed_modules: Vec<(PathBuf, ParsedModule)> = project_files
         .par_iter()
         .filter_map(|path| {
-            if let Some(ref cache_mutex) = cache {
-                if let Ok(content) = std::fs::read_to_string(path) {
-                    let content_hash = compute_content_hash(&content);
-
-                    {
-                        let cache_guard = cache_mutex.lock().ok()?;
-                        if let Some(entry) = cache_guard.get(path) {
-                            if entry.content_hash == content_hash {
-                                let parsed = ParsedModule {
-                                    imports: entry.to_imports(),
-                                    exports: entry.to_exports(),
-                                    re_exports: entry.to_re_exports(),
-                                };
-                                return Some((path.clone(), parsed));
-                            }
-                        }
-                    }
-
-                    match crate::parser::parse_source(&content, path) {
-                        Ok(module) => {
-                            let entry = CacheEntry::from_parsed(
-                                content_hash,
-                                get_modified_time(path),
-                                &module.imports,
-                                &module.exports,
-                                &module.re_exports,
-                            );
-                            if let Ok(mut cache_guard) = cache_mutex.lock() {
-                                cache_guard.insert(path.clone(), entry);
-                            }
-                            Some((path.clone(), module))
-                        }
-                        Err(_) => None,
-                    }
-                } else {
-                    None
-                }
-            } else {
-                match parse_file(path) {
-                    Ok(module) => Some((path.clone(), module)),
-                    Err(_) => None,
-                }
-            }
+            parse_project_file(path, &options.overlay, cache.as_ref())
+                .map(|module| (path.clone(), module))
         })
         .collect();
 
+    let mut seen: HashSet<PathBuf> = project_files.iter().cloned().collect();
+    let mut pending = parsed_modules.len();
+    while pending > 0 {
+        let start = parsed_modules.len() - pending;
+        let stylesheets: Vec<PathBuf> = parsed_modules[start..]
+            .iter()
+            .flat_map(|(path, parsed)| referenced_stylesheets(path, parsed, resolver))
+            .filter(|path| seen.insert(path.clone()))
+            .collect();
+
+        let followed: Vec<(PathBuf, ParsedModule)> = stylesheets
+            .par_iter()
+            .map(|path| {
+                let module = parse_project_file(path, &options.overlay, cache.as_ref());
+                (path.clone(), module.unwrap_or_default())
+            })
+            .collect();
+        pending = followed.len();
+        parsed_modules.extend(followed);
+    }
+
     if let Some(cache_mutex) = cache {
         if let Ok(cache) = cache_mutex.into_inner() {
             let _ = cache.save();
@@ -119,49 +124,11 @@ pub fn build_graph_with_options(config: &ResolvedConfig, options: BuildOptions)
     let mut external_imports: HashMap<String, Vec<PathBuf>> = HashMap::new();
 
     for (path, parsed) in parsed_modules {
-        let mut resolved_imports = Vec::new();
-
-        for import in parsed.imports {
-            let resolved_path = resolver.resolve(&import.specifier, &path);
-            let package_name = if resolver.is_external(&import.specifier) {
-                ModuleResolver::get_package_name(&import.specifier).map(|s| s.to_string())
-            } else {
-                None
-            };
-
-            if let Some(ref pkg) = package_name {
-                external_imports
-                    .entry(pkg.clone())
-                    .or_default()
-                    .push(path.clone());
-            }
-
-            resolved_imports.push(ResolvedImport {
-                original: import,
-                resolved_path,
-                package_name,
-            });
-        }
-
-        let mut resolved_re_exports = Vec::new();
-        for re_export in parsed.re_exports {
-            let resolved_path = resolver.resolve(&re_export.specifier, &path);
-            resolved_re_exports.push(ResolvedReExport {
-                original: re_export,
-                resolved_path,
-            });
-        }
-
-        modules.insert(
-            path.clone(),
-            Module {
-                path,
-                imports: resolved_imports,
-                exports: parsed.exports,
-                re_exports: resolved_re_exports,
-            },
-        );
+        let module = resolve_module(path, parsed, resolver);
+        record_external_imports(&module, &mut external_imports);
+        modules.insert(module.path.clone(), module);
     }
+    expand_glob_imports(&mut modules, root);
 
     Ok(ModuleGraph {
         modules,
@@ -170,115 +137,412 @@ pub fn build_graph_with_options(config: &ResolvedConfig, options: BuildOptions)
     })
 }
 
-fn create_resolver(config: &ResolvedConfig) -> ModuleResolver {
-    let mut paths = config.config.paths.clone();
+pub fn trace_resolution(
+    config: &ResolvedConfig,
+    specifier: &str,
+    production: bool,
+) -> Result<Vec<ResolutionTrace>> {
+    let aliases: Vec<BundlerAlias> = run_project_plugins(config, production)
+        .into_iter()
+        .flat_map(|(_, result)| result.aliases)
+        .collect();
+    let resolver = create_resolver(config, &aliases);
+    let matcher = ProjectMatcher::new(config, production)?;
+    let no_overlay = HashMap::new();
 
-    if let Some(ref tsconfig) = config.tsconfig {
-        for (key, value) in &tsconfig.compiler_options.paths {
-            paths.entry(key.clone()).or_insert_with(|| value.clone());
-        }
-    }
+    let mut files = matcher.walk(&config.root);
+    files.sort();
 
-    let base_url = config
-        .tsconfig
-        .as_ref()
-        .and_then(|ts| ts.compiler_options.base_url.as_ref())
-        .map(|b| config.root.join(b));
+    Ok(files
+        .par_iter()
+        .filter_map(|path| {
+            let parsed = parse_project_file(path, &no_overlay, None)?;
+            let imported = parsed
+                .imports
+                .iter()
+                .map(|import| &import.specifier)
+                .chain(parsed.re_exports.iter().map(|re_export| &re_export.specifier))
+                .any(|imported| imported == specifier);
+            if !imported {
+                return None;
+            }
 
-    ModuleResolver::new(config.root.clone())
-        .with_base_url(base_url)
-        .with_paths(paths)
+            let (resolved, lines) = resolver.trace(specifier, path);
+            Some(ResolutionTrace {
+                from: path.clone(),
+                resolved,
+                lines,
+            })
+        })
+        .collect())
 }
 
-fn collect_project_files(root: &Path, config: &ResolvedConfig, options: &BuildOptions) -> Result<Vec<PathBuf>> {
-    let mut files = Vec::new();
-
-    let project_patterns = if config.config.project.is_empty() {
-        vec![
-            "**/*.ts".to_string(),
-            "**/*.tsx".to_string(),
-            "**/*.js".to_string(),
-            "**/*.jsx".to_string(),
-            "**/*.mjs".to_string(),
-            "**/*.cjs".to_string(),
-        ]
-    } else {
-        config.config.project.clone()
+pub(crate) fn parse_project_file(
+    path: &Path,
+    overlay: &HashMap<PathBuf, String>,
+    cache: Option<&Mutex<Cache>>,
+) -> Option<ParsedModule> {
+    if let Some(content) = overlay.get(path) {
+        return crate::parser::parse_source(content, path).ok();
+    }
+
+    let Some(cache_mutex) = cache else {
+        return parse_file(path).ok();
     };
 
-    let mut include_builder = GlobSetBuilder::new();
-    for pattern in &project_patterns {
-        if let Ok(glob) = Glob::new(pattern) {
-            include_builder.add(glob);
+    let content = std::fs::read_to_string(path).ok()?;
+    let content_hash = compute_content_hash(&content);
+
+    {
+        let cache_guard = cache_mutex.lock().ok()?;
+        if let Some(entry) = cache_guard.get(path) {
+            if entry.content_hash == content_hash {
+                return Some(ParsedModule {
+                    imports: entry.to_imports(),
+                    exports: entry.to_exports(),
+                    re_exports: entry.to_re_exports(),
+                    glob_imports: entry.to_glob_imports(),
+                });
+            }
         }
     }
-    let include_set = include_builder.build()?;
 
-    let mut exclude_builder = GlobSetBuilder::new();
-    for pattern in &config.config.ignore_files {
-        if let Ok(glob) = Glob::new(pattern) {
-            exclude_builder.add(glob);
-        }
+    let module = crate::parser::parse_source(&content, path).ok()?;
+    let entry = CacheEntry::from_parsed(
+        content_hash,
+        get_modified_time(path),
+        &module.imports,
+        &module.exports,
+        &module.re_exports,
+        &module.glob_imports,
+    );
+    if let Ok(mut cache_guard) = cache_mutex.lock() {
+        cache_guard.insert(path.to_path_buf(), entry);
+    }
+    Some(module)
+}
+
+pub(crate) fn referenced_stylesheets(
+    path: &Path,
+    parsed: &ParsedModule,
+    resolver: &ModuleResolver,
+) -> Vec<PathBuf> {
+    parsed
+        .imports
+        .iter()
+        .filter_map(|import| resolver.resolve(&import.specifier, path))
+        .filter(|resolved| is_stylesheet(resolved) && !in_node_modules(resolved))
+        .collect()
+}
+
+pub(crate) fn in_node_modules(path: &Path) -> bool {
+    path.components().any(|c| c.as_os_str() == "node_modules")
+}
+
+pub(crate) fn resolve_import(
+    import: Import,
+    path: &Path,
+    resolver: &ModuleResolver,
+) -> Option<ResolvedImport> {
+    let mut resolved_path = resolver.resolve(&import.specifier, path);
+    if resolved_path.is_none() && resolver.is_disabled(&import.specifier, path) {
+        return None;
     }
-    for pattern in ["**/node_modules/**", "**/dist/**", "**/build/**", "**/.git/**"] {
-        if let Ok(glob) = Glob::new(pattern) {
-            exclude_builder.add(glob);
+    let local_stylesheet =
+        is_stylesheet(path) && resolved_path.as_deref().is_some_and(|p| !in_node_modules(p));
+    let builtin = resolved_path.is_none() && resolver.is_builtin(&import.specifier);
+    let mut package_name =
+        if resolver.is_external(&import.specifier, path) && !local_stylesheet && !builtin {
+            ModuleResolver::get_package_name(&import.specifier).map(|s| s.to_string())
+        } else {
+            None
+        };
+
+    if import.is_type_only && resolved_path.is_none() {
+        if let Some(types_package) =
+            package_name.as_deref().map(ModuleResolver::get_types_package_name)
+        {
+            let from_dir = path.parent().unwrap_or(path);
+            if resolver.find_package_dir(&types_package, from_dir).is_some() {
+                resolved_path = resolver.resolve(&types_package, path);
+                package_name = Some(types_package);
+            }
         }
     }
 
-    if options.production {
-        for pattern in [
-            "**/*.test.ts",
-            "**/*.test.tsx",
-            "**/*.test.js",
-            "**/*.test.jsx",
-            "**/*.spec.ts",
-            "**/*.spec.tsx",
-            "**/*.spec.js",
-            "**/*.spec.jsx",
-            "**/__tests__/**",
-            "**/__mocks__/**",
-            "**/test/**",
-            "**/tests/**",
-            "**/*.stories.ts",
-            "**/*.stories.tsx",
-            "**/*.stories.js",
-            "**/*.stories.jsx",
-        ] {
-            if let Ok(glob) = Glob::new(pattern) {
-                exclude_builder.add(glob);
+    Some(ResolvedImport {
+        original: import,
+        resolved_path,
+        package_name,
+        from_glob: false,
+    })
+}
+
+pub(crate) fn resolve_module(path: PathBuf, parsed: ParsedModule, resolver: &ModuleResolver) -> Module {
+    let imports = parsed
+        .imports
+        .into_iter()
+        .filter_map(|import| resolve_import(import, &path, resolver))
+        .collect();
+
+    let re_exports = parsed
+        .re_exports
+        .into_iter()
+        .map(|re_export| ResolvedReExport {
+            resolved_path: resolver.resolve(&re_export.specifier, &path),
+            original: re_export,
+        })
+        .collect();
+
+    Module {
+        path,
+        imports,
+        exports: parsed.exports,
+        re_exports,
+        glob_imports: parsed.glob_imports,
+    }
+}
+
+pub(crate) fn expand_glob_imports(modules: &mut HashMap<PathBuf, Module>, root: &Path) {
+    let files: Vec<PathBuf> = modules.keys().cloned().collect();
+
+    for module in modules.values_mut() {
+        module.imports.retain(|import| !import.from_glob);
+
+        for glob in &module.glob_imports {
+            let matcher = glob.matcher(&module.path, root);
+            let base = module.path.parent().unwrap_or(root);
+            let mut matched: Vec<&PathBuf> = files
+                .iter()
+                .filter(|file| **file != module.path && matcher.is_match(file))
+                .collect();
+            matched.sort();
+
+            for file in matched {
+                let relative = pathdiff::diff_paths(file, base).unwrap_or_else(|| file.clone());
+                let relative = relative.to_string_lossy().replace('\\', "/");
+                let specifier = if relative.starts_with("../") {
+                    relative
+                } else {
+                    format!("./{}", relative)
+                };
+
+                module.imports.push(ResolvedImport {
+                    original: Import {
+                        specifier,
+                        imported_names: glob.imported_names.clone(),
+                        is_type_only: false,
+                        is_side_effect: false,
+                        line: glob.line,
+                        col: glob.col,
+                    },
+                    resolved_path: Some(file.clone()),
+                    package_name: None,
+                    from_glob: true,
+                });
             }
         }
     }
+}
 
-    let exclude_set = exclude_builder.build()?;
+pub(crate) fn record_external_imports(
+    module: &Module,
+    external_imports: &mut HashMap<String, Vec<PathBuf>>,
+) {
+    for import in &module.imports {
+        if let Some(ref pkg) = import.package_name {
+            external_imports
+                .entry(pkg.clone())
+                .or_default()
+                .push(module.path.clone());
+        }
+    }
+}
 
-    let walker = WalkBuilder::new(root)
-        .hidden(false)
-        .git_ignore(true)
-        .build();
+pub(crate) fn create_resolver(config: &ResolvedConfig, aliases: &[BundlerAlias]) -> ModuleResolver {
+    let workspaces = workspace_packages(&config.root);
+    let pnp = PnpManifest::find(&config.root);
+    let mut resolver =
+        tsconfig_resolver(config, config.tsconfig.as_ref(), &workspaces, &pnp, aliases);
 
-    for entry in walker.filter_map(Result::ok) {
-        let path = entry.path();
-        if !path.is_file() {
+    for reference in &config.tsconfig_references {
+        let Some(dir) = reference.path.as_deref().and_then(Path::parent) else {
             continue;
+        };
+        let include = if reference.include.is_empty() && reference.files.is_empty() {
+            vec![dir.join("**/*").to_string_lossy().into_owned()]
+        } else {
+            reference.include.clone()
+        };
+        resolver = resolver.with_scope(
+            dir.to_path_buf(),
+            &include,
+            &reference.files,
+            &reference.exclude,
+            tsconfig_resolver(config, Some(reference), &workspaces, &pnp, aliases),
+        );
+    }
+
+    resolver
+}
+
+fn tsconfig_resolver(
+    config: &ResolvedConfig,
+    tsconfig: Option<&TsConfig>,
+    workspaces: &HashMap<String, PathBuf>,
+    pnp: &Option<Arc<PnpManifest>>,
+    aliases: &[BundlerAlias],
+) -> ModuleResolver {
+    let mut paths = config.config.paths.clone();
+    let compiler_options = tsconfig.map(|ts| &ts.compiler_options);
+    let profile = config.config.resolve.profile().unwrap_or_default();
+
+    let base_url = compiler_options
+        .and_then(|options| options.base_url.as_ref())
+        .map(|b| config.root.join(b));
+
+    if let Some(tsconfig) = tsconfig {
+        let paths_base = base_url
+            .clone()
+            .or_else(|| tsconfig.paths_base.clone())
+            .unwrap_or_else(|| config.root.clone());
+        for (key, value) in &tsconfig.compiler_options.paths {
+            paths.entry(key.clone()).or_insert_with(|| {
+                value
+                    .iter()
+                    .map(|replacement| paths_base.join(replacement).to_string_lossy().into_owned())
+                    .collect()
+            });
         }
+    }
+
+    ModuleResolver::new(config.root.clone())
+        .with_base_url(base_url)
+        .with_paths(paths)
+        .with_root_dirs(
+            compiler_options
+                .map(|options| options.root_dirs.iter().map(PathBuf::from).collect())
+                .unwrap_or_default(),
+        )
+        .with_module_suffixes(
+            compiler_options
+                .map(|options| options.module_suffixes.clone())
+                .unwrap_or_default(),
+        )
+        .with_allow_ts_extensions(
+            compiler_options.is_some_and(|options| options.allow_importing_ts_extensions),
+        )
+        .with_preserve_symlinks(compiler_options.is_some_and(|options| options.preserve_symlinks))
+        .with_workspace_packages(workspaces.clone())
+        .with_pnp(pnp.clone())
+        .with_main_fields(profile.main_fields)
+        .with_alias_fields(profile.alias_fields)
+        .with_builtins(Builtins::new(&config.config.runtime))
+        .with_bundler_aliases(aliases.to_vec())
+}
 
-        let relative = path.strip_prefix(root).unwrap_or(path);
+fn workspace_packages(root: &Path) -> HashMap<String, PathBuf> {
+    WorkspaceDiscovery::discover(root)
+        .map(|discovery| build_workspace_dependency_map(&discovery.workspaces))
+        .unwrap_or_default()
+}
 
-        if exclude_set.is_match(relative) {
-            continue;
+pub(crate) struct ProjectMatcher {
+    root: PathBuf,
+    include: GlobSet,
+    exclude: GlobSet,
+}
+
+impl ProjectMatcher {
+    pub(crate) fn new(config: &ResolvedConfig, production: bool) -> Result<Self> {
+        let project_patterns = if config.config.project.is_empty() {
+            vec![
+                "**/*.ts".to_string(),
+                "**/*.tsx".to_string(),
+                "**/*.js".to_string(),
+                "**/*.jsx".to_string(),
+                "**/*.mjs".to_string(),
+                "**/*.cjs".to_string(),
+                "**/*.vue".to_string(),
+                "**/*.svelte".to_string(),
+                "**/*.astro".to_string(),
+                "**/*.mdx".to_string(),
+            ]
+        } else {
+            config.config.project.clone()
+        };
+
+        let mut include_builder = GlobSetBuilder::new();
+        for pattern in &project_patterns {
+            if let Ok(glob) = Glob::new(pattern) {
+                include_builder.add(glob);
+            }
         }
 
-        if include_set.is_match(relative) {
-            files.push(path.to_path_buf());
+        let mut exclude_builder = GlobSetBuilder::new();
+        for pattern in &config.config.ignore_files {
+            if let Ok(glob) = Glob::new(pattern) {
+                exclude_builder.add(glob);
+            }
+        }
+        for pattern in ["**/node_modules/**", "**/dist/**", "**/build/**", "**/.git/**"] {
+            if let Ok(glob) = Glob::new(pattern) {
+                exclude_builder.add(glob);
+            }
         }
+
+        if production {
+            for pattern in [
+                "**/*.test.ts",
+                "**/*.test.tsx",
+                "**/*.test.js",
+                "**/*.test.jsx",
+                "**/*.spec.ts",
+                "**/*.spec.tsx",
+                "**/*.spec.js",
+                "**/*.spec.jsx",
+                "**/__tests__/**",
+                "**/__mocks__/**",
+                "**/test/**",
+                "**/tests/**",
+                "**/*.stories.ts",
+                "**/*.stories.tsx",
+                "**/*.stories.js",
+                "**/*.stories.jsx",
+            ] {
+                if let Ok(glob) = Glob::new(pattern) {
+                    exclude_builder.add(glob);
+                }
+            }
+        }
+
+        Ok(Self {
+            root: config.root.clone(),
+            include: include_builder.build()?,
+            exclude: exclude_builder.build()?,
+        })
     }
 
-    Ok(files)
+    pub(crate) fn is_match(&self, path: &Path) -> bool {
+        let relative = path.strip_prefix(&self.root).unwrap_or(path);
+        !self.exclude.is_match(relative) && self.include.is_match(relative)
+    }
+
+    pub(crate) fn walk(&self, dir: &Path) -> Vec<PathBuf> {
+        let walker = WalkBuilder::new(dir)
+            .hidden(false)
+            .git_ignore(true)
+            .build();
+
+        walker
+            .filter_map(Result::ok)
+            .map(|entry| entry.into_path())
+            .filter(|path| path.is_file() && self.is_match(path))
+            .collect()
+    }
 }
 
-fn find_entry_points(
+pub(crate) fn find_entry_points(
     root: &Path,
     config: &ResolvedConfig,
     project_files: &[PathBuf],
@@ -328,6 +592,12 @@ fn find_entry_points(
         }
     }
 
+    for file in project_files {
+        if is_markdown(file) && !entries.contains(file) {
+            entries.push(file.clone());
+        }
+    }
+
     if let Some(ref pkg) = config.package_json {
         if let Some(main) = &pkg.main {
             let path = root.join(main);
@@ -342,11 +612,18 @@ fn find_entry_points(
 
 impl ModuleGraph {
     pub fn get_reachable_files(&self) -> HashSet<PathBuf> {
+        self.get_reachable_files_where(|_| true)
+    }
+
+    pub fn get_reachable_files_where<F>(&self, include: F) -> HashSet<PathBuf>
+    where
+        F: Fn(&Path) -> bool,
+    {
         let mut reachable = HashSet::new();
         let mut queue: Vec<PathBuf> = self.entry_points.clone();
 
         while let Some(path) = queue.pop() {
-            if !reachable.insert(path.clone()) {
+            if !include(&path) || !reachable.insert(path.clone()) {
                 continue;
             }
 
@@ -372,9 +649,8 @@ impl ModuleGraph {
         reachable
     }
 
-
-
-    pub fn get_used_exports(&self) -> HashMap<PathBuf, HashSet<String>> {
+    /// Namespace imports use every export unless `track_members` is set.
+    pub fn get_used_exports(&self, track_members: bool) -> HashMap<PathBuf, HashSet<String>> {
         let mut used: HashMap<PathBuf, HashSet<String>> = HashMap::new();
 
         for module in self.modules.values() {
@@ -383,10 +659,16 @@ impl ModuleGraph {
                     let entry = used.entry(resolved.clone()).or_default();
 
                     for name in &import.original.imported_names {
-                        if name.name == "*" {
-                            entry.insert("*".to_string());
-                        } else {
-                            entry.insert(name.name.clone());
+                        match (name.name.as_str(), &name.members) {
+                            ("*", Some(members)) if track_members => {
+                                entry.extend(members.iter().map(|member| member.name.clone()));
+                            }
+                            ("*", _) => {
+                                entry.insert("*".to_string());
+                            }
+                            _ => {
+                                entry.insert(name.name.clone());
+                            }
                         }
                     }
 
@@ -400,6 +682,21 @@ impl ModuleGraph {
         used
     }
 
+    pub fn get_namespace_imported(&self) -> HashSet<PathBuf> {
+        self.modules
+            .values()
+            .flat_map(|module| &module.imports)
+            .filter(|import| {
+                import
+                    .original
+                    .imported_names
+                    .iter()
+                    .any(|name| name.name == "*" && name.members.is_some())
+            })
+            .filter_map(|import| import.resolved_path.clone())
+            .collect()
+    }
+
     pub fn get_used_packages(&self) -> HashSet<String> {
         self.external_imports.keys().cloned().collect()
     }
@@ -408,6 +705,7 @@ impl ModuleGraph {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_utils::write_tree;
 
     #[test]
     fn test_module_graph_empty() {
@@ -421,6 +719,55 @@ mod tests {
         assert!(graph.get_used_packages().is_empty());
     }
 
+    #[test]
+    fn test_reachable_files_where_skips_excluded() {
+        let module = |path: &str, imports: &[&str]| Module {
+            path: PathBuf::from(path),
+            imports: imports
+                .iter()
+                .map(|target| ResolvedImport {
+                    original: Import {
+                        specifier: target.to_string(),
+                        imported_names: vec![],
+                        is_type_only: false,
+                        is_side_effect: true,
+                        line: 1,
+                        col: 1,
+                    },
+                    resolved_path: Some(PathBuf::from(target)),
+                    package_name: None,
+                    from_glob: false,
+                })
+                .collect(),
+            exports: vec![],
+            re_exports: vec![],
+            glob_imports: vec![],
+        };
+
+        let mut modules = HashMap::new();
+        for m in [
+            module("/src/index.ts", &["/src/a.test.ts", "/src/b.ts"]),
+            module("/src/a.test.ts", &["/src/c.ts"]),
+            module("/src/b.ts", &[]),
+            module("/src/c.ts", &[]),
+        ] {
+            modules.insert(m.path.clone(), m);
+        }
+
+        let graph = ModuleGraph {
+            modules,
+            entry_points: vec![PathBuf::from("/src/index.ts")],
+            external_imports: HashMap::new(),
+        };
+
+        let reachable =
+            graph.get_reachable_files_where(|p| !p.to_string_lossy().contains(".test."));
+        assert!(reachable.contains(Path::new("/src/b.ts")));
+        assert!(!reachable.contains(Path::new("/src/a.test.ts")));
+        assert!(!reachable.contains(Path::new("/src/c.ts")));
+        assert_eq!(graph.get_reachable_files().len(), 4);
+    }
+
     #[test]
     fn test_build_options_default() {
         let options = BuildOptions::default();
@@ -428,4 +775,138 @@ mod tests {
         assert!(!options.production);
         assert!(!options.strict);
     }
+
+    #[test]
+    fn test_create_resolver_anchors_paths_to_declaring_tsconfig() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = temp.path().canonicalize().unwrap();
+        write_tree(
+            &root,
+            &[
+                (
+                    "configs/tsconfig.base.json",
+                    r#"{"compilerOptions": {"paths": {"@lib/*": ["../lib/*"]}, "rootDirs": ["../src", "../generated"]}}"#,
+                ),
+                ("tsconfig.json", r#"{"extends": "./configs/tsconfig.base.json"}"#),
+                ("lib/math.ts", ""),
+                ("generated/schema.ts", ""),
+            ],
+        );
+
+        let config = crate::config::load_config(&root, None).unwrap();
+        let resolver = create_resolver(&config, &[]);
+        let from = root.join("src/index.ts");
+
+        assert_eq!(
+            resolver.resolve("@lib/math", &from),
+            Some(root.join("lib/math.ts"))
+        );
+        assert_eq!(
+            resolver.resolve("./schema", &from),
+            Some(root.join("generated/schema.ts"))
+        );
+    }
+
+    #[test]
+    fn test_create_resolver_uses_nearest_referenced_tsconfig() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = temp.path().canonicalize().unwrap();
+        write_tree(
+            &root,
+            &[
+                (
+                    "tsconfig.json",
+                    r#"{"files": [], "references": [{"path": "./tsconfig.app.json"}, {"path": "./tsconfig.node.json"}, {"path": "./packages/ui"}]}"#,
+                ),
+                (
+                    "tsconfig.app.json",
+                    r#"{"include": ["src"], "compilerOptions": {"paths": {"@/*": ["./src/*"]}}}"#,
+                ),
+                (
+                    "tsconfig.node.json",
+                    r#"{"include": ["vite.config.ts"], "compilerOptions": {"moduleSuffixes": [".node", ""]}}"#,
+                ),
+                (
+                    "packages/ui/tsconfig.json",
+                    r#"{"compilerOptions": {"baseUrl": ".", "paths": {"@/*": ["./lib/*"]}}}"#,
+                ),
+                ("src/util.ts", ""),
+                ("packages/ui/lib/util.ts", ""),
+                ("env.node.ts", ""),
+                ("env.ts", ""),
+            ],
+        );
+
+        let config = crate::config::load_config(&root, None).unwrap();
+        let resolver = create_resolver(&config, &[]);
+
+        let app = root.join("src/main.ts");
+        let ui = root.join("packages/ui/index.ts");
+        let vite = root.join("vite.config.ts");
+        assert_eq!(
+            resolver.resolve("@/util", &app),
+            Some(root.join("src/util.ts"))
+        );
+        assert_eq!(
+            resolver.resolve("@/util", &ui),
+            Some(root.join("packages/ui/lib/util.ts"))
+        );
+        assert_eq!(
+            resolver.resolve("./env", &vite),
+            Some(root.join("env.node.ts"))
+        );
+        assert_eq!(
+            resolver.resolve("./env", &root.join("other.ts")),
+            Some(root.join("env.ts"))
+        );
+        assert!(!resolver.is_external("@/util", &ui));
+        assert!(resolver.is_external("@/util", &vite));
+    }
+
+    #[test]
+    fn test_create_resolver_applies_bundler_aliases() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = temp.path().canonicalize().unwrap();
+        write_tree(
+            &root,
+            &[
+                (
+                    "vite.config.ts",
+                    "import path from 'node:path';\nexport default { resolve: { alias: { '~': path.resolve(__dirname, 'src') } } };\n",
+                ),
+                (
+                    "jest.config.js",
+                    "module.exports = { moduleNameMapper: { '^@test/(.*)$': '<rootDir>/test/$1' } };\n",
+                ),
+                ("src/components/button.tsx", ""),
+                ("src/styles/theme.css", ""),
+                ("test/helpers.ts", ""),
+            ],
+        );
+
+        let config = crate::config::load_config(&root, None).unwrap();
+        let aliases: Vec<BundlerAlias> = run_project_plugins(&config, false)
+            .into_iter()
+            .flat_map(|(_, result)| result.aliases)
+            .collect();
+        let resolver = create_resolver(&config, &aliases);
+        let app = root.join("src/app.ts");
+        let test = root.join("src/app.test.ts");
+
+        assert_eq!(
+            resolver.resolve("~/components/button", &app),
+            Some(root.join("src/components/button.tsx"))
+        );
+        assert_eq!(
+            resolver.resolve("~/styles/theme.css", &root.join("src/main.css")),
+            Some(root.join("src/styles/theme.css"))
+        );
+        assert!(!resolver.is_external("~/components/button", &app));
+        assert_eq!(
+            resolver.resolve("@test/helpers", &test),
+            Some(root.join("test/helpers.ts"))
+        );
+        assert_eq!(resolver.resolve("@test/helpers", &app), None);
+        assert!(resolver.is_external("@test/helpers", &app));
+    }
 }
@@ -0,0 +1,501 @@
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use rayon::prelude::*;
+
+use crate::config::ResolvedConfig;
+use crate::parser::css::{is_asset, is_stylesheet};
+use crate::resolver::{normalize_path, ModuleResolver};
+
+use super::builder::{
+    build_graph_from_parts, create_resolver, expand_glob_imports, find_entry_points,
+    in_node_modules, parse_project_file, record_external_imports, resolve_import, resolve_module,
+    BuildOptions, ModuleGraph, ProjectMatcher,
+};
+
+pub struct IncrementalGraph {
+    matcher: ProjectMatcher,
+    resolver: ModuleResolver,
+    graph: ModuleGraph,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct GraphUpdate {
+    pub parsed: usize,
+    pub removed: usize,
+    pub reresolved: usize,
+}
+
+impl IncrementalGraph {
+    pub fn build(config: &ResolvedConfig, options: BuildOptions) -> Result<Self> {
+        let resolver = create_resolver(config, &options.aliases);
+        let matcher = ProjectMatcher::new(config, options.production)?;
+        let graph = build_graph_from_parts(config, options, &matcher, &resolver)?;
+
+        Ok(Self {
+            matcher,
+            resolver,
+            graph,
+        })
+    }
+
+    pub fn graph(&self) -> &ModuleGraph {
+        &self.graph
+    }
+
+    pub fn update(&mut self, config: &ResolvedConfig, changed: &[PathBuf]) -> Result<GraphUpdate> {
+        self.resolver.clear_cache();
+
+        let mut to_parse: HashSet<PathBuf> = HashSet::new();
+        let mut to_remove: HashSet<PathBuf> = HashSet::new();
+        let mut created_assets: Vec<PathBuf> = Vec::new();
+
+        for path in changed {
+            if path.is_dir() {
+                to_parse.extend(self.matcher.walk(path));
+            } else if path.is_file() {
+                if self.matcher.is_match(path)
+                    || (is_stylesheet(path) && self.graph.modules.contains_key(path))
+                {
+                    to_parse.insert(path.clone());
+                } else if is_asset(path) {
+                    created_assets.push(path.clone());
+                } else if self.graph.modules.contains_key(path) {
+                    to_remove.insert(path.clone());
+                }
+            } else {
+                to_remove.extend(
+                    self.graph
+                        .modules
+                        .keys()
+                        .filter(|module| module.starts_with(path))
+                        .cloned(),
+                );
+            }
+        }
+
+        let added: Vec<PathBuf> = to_parse
+            .iter()
+            .filter(|path| !self.graph.modules.contains_key(*path))
+            .chain(&created_assets)
+            .cloned()
+            .collect();
+
+        let no_overlay = HashMap::new();
+        let parsed: Vec<_> = to_parse
+            .par_iter()
+            .map(|path| (path.clone(), parse_project_file(path, &no_overlay, None)))
+            .collect();
+
+        let mut update = GraphUpdate::default();
+
+        for (path, module) in parsed {
+            self.forget_external_imports(&path);
+            match module {
+                Some(module) => {
+                    let module = resolve_module(path.clone(), module, &self.resolver);
+                    record_external_imports(&module, &mut self.graph.external_imports);
+                    self.graph.modules.insert(path, module);
+                    update.parsed += 1;
+                }
+                None => {
+                    to_remove.insert(path);
+                }
+            }
+        }
+
+        for path in &to_remove {
+            if self.graph.modules.remove(path).is_some() {
+                self.forget_external_imports(path);
+                update.removed += 1;
+            }
+        }
+
+        if !added.is_empty() || !to_remove.is_empty() {
+            update.reresolved = self.reresolve_affected(&added, &to_remove, &to_parse);
+        }
+
+        let followed = self.follow_stylesheets();
+        update.parsed += followed;
+
+        if update.parsed > 0 || update.removed > 0 {
+            expand_glob_imports(&mut self.graph.modules, &config.root);
+        }
+
+        if !added.is_empty() || !to_remove.is_empty() || followed > 0 {
+            let mut files: Vec<PathBuf> = self.graph.modules.keys().cloned().collect();
+            files.sort();
+            self.graph.entry_points = find_entry_points(&config.root, config, &files);
+        }
+
+        Ok(update)
+    }
+
+    fn follow_stylesheets(&mut self) -> usize {
+        let no_overlay = HashMap::new();
+        let mut followed = 0;
+
+        loop {
+            let missing: HashSet<PathBuf> = self
+                .graph
+                .modules
+                .values()
+                .flat_map(|module| &module.imports)
+                .filter_map(|import| import.resolved_path.as_ref())
+                .filter(|path| is_stylesheet(path) && !in_node_modules(path))
+                .filter(|path| !self.graph.modules.contains_key(*path))
+                .cloned()
+                .collect();
+            if missing.is_empty() {
+                return followed;
+            }
+
+            for path in missing {
+                let module = parse_project_file(&path, &no_overlay, None).unwrap_or_default();
+                let module = resolve_module(path.clone(), module, &self.resolver);
+                record_external_imports(&module, &mut self.graph.external_imports);
+                self.graph.modules.insert(path, module);
+                followed += 1;
+            }
+        }
+    }
+
+    fn forget_external_imports(&mut self, path: &Path) {
+        self.graph.external_imports.retain(|_, files| {
+            files.retain(|file| file != path);
+            !files.is_empty()
+        });
+    }
+
+    fn reresolve_affected(
+        &mut self,
+        added: &[PathBuf],
+        removed: &HashSet<PathBuf>,
+        fresh: &HashSet<PathBuf>,
+    ) -> usize {
+        let dirs: HashSet<&Path> = added
+            .iter()
+            .chain(removed)
+            .filter_map(|p| p.parent())
+            .collect();
+        let in_dirs =
+            |path: &Path| dirs.contains(path) || path.parent().is_some_and(|p| dirs.contains(p));
+        let affects = |from: &Path, specifier: &str, resolved: &Option<PathBuf>| match resolved {
+            None => true,
+            Some(resolved) => {
+                removed.contains(resolved)
+                    || resolved.parent().is_some_and(|dir| dirs.contains(dir))
+                    || relative_target(from, specifier).is_some_and(|target| in_dirs(&target))
+            }
+        };
+
+        let resolver = &self.resolver;
+        let mut reresolved = 0;
+        let mut touched = Vec::new();
+
+        for (path, module) in self.graph.modules.iter_mut() {
+            if fresh.contains(path) {
+                continue;
+            }
+
+            for import in &mut module.imports {
+                if !import.from_glob
+                    && affects(path, &import.original.specifier, &import.resolved_path)
+                {
+                    if let Some(resolved) = resolve_import(import.original.clone(), path, resolver)
+                    {
+                        *import = resolved;
+                    }
+                    touched.push(path.clone());
+                    reresolved += 1;
+                }
+            }
+
+            for re_export in &mut module.re_exports {
+                if affects(
+                    path,
+                    &re_export.original.specifier,
+                    &re_export.resolved_path,
+                ) {
+                    re_export.resolved_path = resolver.resolve(&re_export.original.specifier, path);
+                    reresolved += 1;
+                }
+            }
+        }
+
+        for path in touched {
+            self.forget_external_imports(&path);
+            if let Some(module) = self.graph.modules.get(&path) {
+                record_external_imports(module, &mut self.graph.external_imports);
+            }
+        }
+
+        reresolved
+    }
+}
+
+fn relative_target(from: &Path, specifier: &str) -> Option<PathBuf> {
+    if specifier != "."
+        && specifier != ".."
+        && !specifier.starts_with("./")
+        && !specifier.starts_with("../")
+    {
+        return None;
+    }
+    Some(normalize_path(&from.parent()?.join(specifier)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::load_config;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn project() -> (TempDir, ResolvedConfig) {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(
+            root.join("src/index.ts"),
+            "import { a } from './a';\nimport { b } from './b';\nconsole.log(a, b);\n",
+        )
+        .unwrap();
+        fs::write(root.join("src/a.ts"), "export const a = 1;\n").unwrap();
+        let config = load_config(root, None).unwrap();
+        (temp, config)
+    }
+
+    fn import_target(graph: &ModuleGraph, from: &Path, specifier: &str) -> Option<PathBuf> {
+        graph.modules[from]
+            .imports
+            .iter()
+            .find(|i| i.original.specifier == specifier)
+            .and_then(|i| i.resolved_path.clone())
+    }
+
+    #[test]
+    fn test_update_reparses_changed_file() {
+        let (_temp, config) = project();
+        let root = config.root.clone();
+        let mut graph = IncrementalGraph::build(&config, BuildOptions::default()).unwrap();
+
+        fs::write(
+            root.join("src/a.ts"),
+            "import 'lodash';\nexport const a = 2;\n",
+        )
+        .unwrap();
+        let update = graph.update(&config, &[root.join("src/a.ts")]).unwrap();
+
+        assert_eq!(
+            update,
+            GraphUpdate {
+                parsed: 1,
+                removed: 0,
+                reresolved: 0
+            }
+        );
+        assert_eq!(
+            graph.graph().external_imports["lodash"],
+            vec![root.join("src/a.ts")]
+        );
+    }
+
+    #[test]
+    fn test_update_resolves_added_and_removed_files() {
+        let (_temp, config) = project();
+        let root = config.root.clone();
+        let index = root.join("src/index.ts");
+        let mut graph = IncrementalGraph::build(&config, BuildOptions::default()).unwrap();
+        assert_eq!(import_target(graph.graph(), &index, "./b"), None);
+
+        fs::create_dir_all(root.join("src/b")).unwrap();
+        fs::write(root.join("src/b/index.ts"), "export const b = 1;\n").unwrap();
+        let update = graph.update(&config, &[root.join("src/b")]).unwrap();
+        assert_eq!(update.parsed, 1);
+        assert_eq!(
+            import_target(graph.graph(), &index, "./b"),
+            Some(root.join("src/b/index.ts"))
+        );
+
+        fs::remove_file(root.join("src/a.ts")).unwrap();
+        let update = graph.update(&config, &[root.join("src/a.ts")]).unwrap();
+        assert_eq!(update.removed, 1);
+        assert!(!graph.graph().modules.contains_key(&root.join("src/a.ts")));
+        assert_eq!(import_target(graph.graph(), &index, "./a"), None);
+    }
+
+    #[test]
+    fn test_update_reresolves_base_url_imports() {
+        let (temp, _) = project();
+        let root = temp.path().canonicalize().unwrap();
+        fs::write(
+            root.join("tsconfig.json"),
+            r#"{"compilerOptions": {"baseUrl": "src"}}"#,
+        )
+        .unwrap();
+        let index = root.join("src/index.ts");
+        fs::write(&index, "import { h } from 'helpers';\nconsole.log(h);\n").unwrap();
+        let config = load_config(&root, None).unwrap();
+        let mut graph = IncrementalGraph::build(&config, BuildOptions::default()).unwrap();
+        assert!(graph.graph().external_imports.contains_key("helpers"));
+
+        fs::write(root.join("src/helpers.ts"), "export const h = 1;\n").unwrap();
+        let update = graph
+            .update(&config, &[root.join("src/helpers.ts")])
+            .unwrap();
+
+        assert_eq!(update.reresolved, 1);
+        assert_eq!(
+            import_target(graph.graph(), &index, "helpers"),
+            Some(root.join("src/helpers.ts"))
+        );
+        assert!(!graph.graph().external_imports.contains_key("helpers"));
+    }
+
+    #[test]
+    fn test_update_reresolves_module_suffixes() {
+        let (temp, _) = project();
+        let root = temp.path().canonicalize().unwrap();
+        fs::write(
+            root.join("tsconfig.json"),
+            r#"{"compilerOptions": {"moduleSuffixes": [".ios", ""]}}"#,
+        )
+        .unwrap();
+        let index = root.join("src/index.ts");
+        fs::write(&index, "import { p } from './plat';\nconsole.log(p);\n").unwrap();
+        fs::write(root.join("src/plat.ts"), "export const p = 1;\n").unwrap();
+        let config = load_config(&root, None).unwrap();
+        let mut graph = IncrementalGraph::build(&config, BuildOptions::default()).unwrap();
+        assert_eq!(
+            import_target(graph.graph(), &index, "./plat"),
+            Some(root.join("src/plat.ts"))
+        );
+
+        fs::write(root.join("src/plat.ios.ts"), "export const p = 2;\n").unwrap();
+        graph
+            .update(&config, &[root.join("src/plat.ios.ts")])
+            .unwrap();
+        assert_eq!(
+            import_target(graph.graph(), &index, "./plat"),
+            Some(root.join("src/plat.ios.ts"))
+        );
+    }
+
+    #[test]
+    fn test_update_reresolves_declaration_files() {
+        let (_temp, config) = project();
+        let root = config.root.clone();
+        let index = root.join("src/index.ts");
+        fs::write(&index, "import type { T } from './types';\nexport type U = T;\n").unwrap();
+        let mut graph = IncrementalGraph::build(&config, BuildOptions::default()).unwrap();
+        assert_eq!(import_target(graph.graph(), &index, "./types"), None);
+
+        fs::write(root.join("src/types.d.ts"), "export type T = string;\n").unwrap();
+        graph
+            .update(&config, &[root.join("src/types.d.ts")])
+            .unwrap();
+        assert_eq!(
+            import_target(graph.graph(), &index, "./types"),
+            Some(root.join("src/types.d.ts"))
+        );
+    }
+
+    #[test]
+    fn test_update_reresolves_directory_index() {
+        let (temp, _) = project();
+        let root = temp.path().canonicalize().unwrap();
+        fs::write(
+            root.join("tsconfig.json"),
+            r#"{"compilerOptions": {"moduleSuffixes": [".native", ""]}}"#,
+        )
+        .unwrap();
+        let index = root.join("src/index.ts");
+        fs::create_dir_all(root.join("src/api")).unwrap();
+        fs::write(&index, "import { api } from './api';\nconsole.log(api);\n").unwrap();
+        let config = load_config(&root, None).unwrap();
+        let mut graph = IncrementalGraph::build(&config, BuildOptions::default()).unwrap();
+        assert_eq!(import_target(graph.graph(), &index, "./api"), None);
+
+        fs::write(root.join("src/api/index.native.ts"), "export const api = 1;\n").unwrap();
+        graph
+            .update(&config, &[root.join("src/api/index.native.ts")])
+            .unwrap();
+        assert_eq!(
+            import_target(graph.graph(), &index, "./api"),
+            Some(root.join("src/api/index.native.ts"))
+        );
+
+        fs::write(root.join("src/api.ts"), "export const api = 2;\n").unwrap();
+        graph.update(&config, &[root.join("src/api.ts")]).unwrap();
+        assert_eq!(
+            import_target(graph.graph(), &index, "./api"),
+            Some(root.join("src/api.ts"))
+        );
+    }
+
+    #[test]
+    fn test_update_follows_stylesheets() {
+        let (_temp, config) = project();
+        let root = config.root.clone();
+        let main = root.join("src/main.scss");
+        fs::write(
+            root.join("src/a.ts"),
+            "import './main.scss';\nexport const a = 1;\n",
+        )
+        .unwrap();
+        fs::write(&main, ".a {}\n").unwrap();
+        let mut graph = IncrementalGraph::build(&config, BuildOptions::default()).unwrap();
+        assert!(graph.graph().modules.contains_key(&main));
+
+        fs::write(root.join("src/_theme.scss"), ".theme {}\n").unwrap();
+        fs::write(&main, "@use 'theme';\n").unwrap();
+        let update = graph.update(&config, std::slice::from_ref(&main)).unwrap();
+
+        assert_eq!(update.parsed, 2);
+        assert_eq!(
+            import_target(graph.graph(), &main, "theme"),
+            Some(root.join("src/_theme.scss"))
+        );
+        assert!(graph
+            .graph()
+            .modules
+            .contains_key(&root.join("src/_theme.scss")));
+    }
+
+    #[test]
+    fn test_update_expands_glob_imports() {
+        let (_temp, config) = project();
+        let root = config.root.clone();
+        let index = root.join("src/index.ts");
+        fs::create_dir_all(root.join("src/pages")).unwrap();
+        fs::write(&index, "const pages = import.meta.glob('./pages/*.ts');\n").unwrap();
+        fs::write(root.join("src/pages/home.ts"), "export default 1;\n").unwrap();
+        let mut graph = IncrementalGraph::build(&config, BuildOptions::default()).unwrap();
+        assert_eq!(
+            import_target(graph.graph(), &index, "./pages/home.ts"),
+            Some(root.join("src/pages/home.ts"))
+        );
+
+        fs::write(root.join("src/pages/about.ts"), "export default 2;\n").unwrap();
+        graph
+            .update(&config, &[root.join("src/pages/about.ts")])
+            .unwrap();
+        assert_eq!(
+            import_target(graph.graph(), &index, "./pages/about.ts"),
+            Some(root.join("src/pages/about.ts"))
+        );
+
+        fs::remove_file(root.join("src/pages/home.ts")).unwrap();
+        graph
+            .update(&config, &[root.join("src/pages/home.ts")])
+            .unwrap();
+        let globbed = graph.graph().modules[&index]
+            .imports
+            .iter()
+            .filter(|i| i.from_glob)
+            .count();
+        assert_eq!(globbed, 1);
+    }
+}
@@ -1,3 +1,8 @@
 mod builder;
+mod incremental;
 
-pub use builder::{ModuleGraph, Module, build_graph, build_graph_with_options, BuildOptions, ResolvedImport};
+pub use builder::{
+    ModuleGraph, Module, build_graph, build_graph_with_options, trace_resolution, BuildOptions,
+    ResolutionTrace, ResolvedImport,
+};
+pub use incremental::{GraphUpdate, IncrementalGraph};
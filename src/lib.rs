@@ -1,20 +1,28 @@
 pub mod analyzer;
 pub mod cache;
+pub mod changes;
 pub mod cli;
 pub mod config;
 pub mod fixer;
 pub mod graph;
+pub mod lsp;
 pub mod parser;
 pub mod plugins;
+pub mod report;
 pub mod resolver;
 pub mod watch;
 pub mod workspace;
 
+#[cfg(test)]
+mod test_utils;
+
 pub use analyzer::AnalyzeOptions;
 
 use std::path::Path;
 
 use anyhow::Result;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
 pub fn analyze(path: &Path) -> Result<AnalysisResult> {
     let resolved_config = config::load_config(path, None)?;
@@ -34,64 +42,137 @@ pub fn analyze_with_options(
     config_path: Option<&Path>,
     options: AnalyzeOptions,
 ) -> Result<AnalysisResult> {
-    let resolved_config = config::load_config(path, config_path)?;
+    let resolved_config =
+        config::load_config_with_tsconfig(path, config_path, options.tsconfig.as_deref())?;
     analyzer::analyze_project_with_options(&resolved_config, options)
 }
 
-#[derive(Debug, Default)]
+pub fn trace_resolution(
+    path: &Path,
+    config_path: Option<&Path>,
+    options: &AnalyzeOptions,
+    specifier: &str,
+) -> Result<Vec<graph::ResolutionTrace>> {
+    let resolved_config =
+        config::load_config_with_tsconfig(path, config_path, options.tsconfig.as_deref())?;
+    graph::trace_resolution(&resolved_config, specifier, options.production)
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct AnalysisResult {
     pub issues: Issues,
     pub counters: Counters,
     pub stats: Stats,
+    pub dependency_credits: Vec<DependencyCredit>,
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct Issues {
     pub files: Vec<UnusedFile>,
     pub dependencies: Vec<UnusedDependency>,
     pub dev_dependencies: Vec<UnusedDependency>,
+    pub misplaced_dependencies: Vec<MisplacedDependency>,
+    pub misplaced_dev_dependencies: Vec<MisplacedDependency>,
+    pub type_only_dependencies: Vec<TypeOnlyDependency>,
+    pub unpaired_types: Vec<UnpairedTypesPackage>,
+    pub missing_types: Vec<MissingTypesPackage>,
     pub exports: Vec<UnusedExport>,
     pub types: Vec<UnusedType>,
     pub unlisted: Vec<UnlistedDependency>,
     pub binaries: Vec<UnlistedBinary>,
     pub unresolved: Vec<UnresolvedImport>,
     pub duplicates: Vec<DuplicateExport>,
+    pub assets: Vec<UnusedAsset>,
+    pub css_classes: Vec<UnusedCssClass>,
+    pub unknown_css_classes: Vec<UnknownCssClass>,
     pub enum_members: Vec<UnusedEnumMember>,
     pub class_members: Vec<UnusedClassMember>,
+    pub ns_exports: Vec<UnusedExport>,
+    pub ns_types: Vec<UnusedType>,
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct Counters {
     pub files: usize,
     pub dependencies: usize,
     pub dev_dependencies: usize,
+    pub misplaced_dependencies: usize,
+    pub misplaced_dev_dependencies: usize,
+    pub type_only_dependencies: usize,
+    pub unpaired_types: usize,
+    pub missing_types: usize,
     pub exports: usize,
     pub types: usize,
     pub unlisted: usize,
     pub binaries: usize,
     pub unresolved: usize,
     pub duplicates: usize,
+    pub assets: usize,
+    pub css_classes: usize,
+    pub unknown_css_classes: usize,
     pub enum_members: usize,
     pub class_members: usize,
+    pub ns_exports: usize,
+    pub ns_types: usize,
 }
 
 impl Counters {
+    pub fn from_issues(issues: &Issues) -> Self {
+        Self {
+            files: issues.files.len(),
+            dependencies: issues.dependencies.len(),
+            dev_dependencies: issues.dev_dependencies.len(),
+            misplaced_dependencies: issues.misplaced_dependencies.len(),
+            misplaced_dev_dependencies: issues.misplaced_dev_dependencies.len(),
+            type_only_dependencies: issues.type_only_dependencies.len(),
+            unpaired_types: issues.unpaired_types.len(),
+            missing_types: issues.missing_types.len(),
+            exports: issues.exports.len(),
+            types: issues.types.len(),
+            unlisted: issues.unlisted.len(),
+            binaries: issues.binaries.len(),
+            unresolved: issues.unresolved.len(),
+            duplicates: issues.duplicates.len(),
+            assets: issues.assets.len(),
+            css_classes: issues.css_classes.len(),
+            unknown_css_classes: issues.unknown_css_classes.len(),
+            enum_members: issues.enum_members.len(),
+            class_members: issues.class_members.len(),
+            ns_exports: issues.ns_exports.len(),
+            ns_types: issues.ns_types.len(),
+        }
+    }
+
     pub fn total(&self) -> usize {
         self.files
             + self.dependencies
             + self.dev_dependencies
+            + self.misplaced_dependencies
+            + self.misplaced_dev_dependencies
+            + self.type_only_dependencies
+            + self.unpaired_types
+            + self.missing_types
             + self.exports
             + self.types
             + self.unlisted
             + self.binaries
             + self.unresolved
             + self.duplicates
+            + self.assets
+            + self.css_classes
+            + self.unknown_css_classes
             + self.enum_members
             + self.class_members
+            + self.ns_exports
+            + self.ns_types
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct Stats {
     pub files_analyzed: usize,
     pub duration_ms: u64,
@@ -100,20 +181,111 @@ pub struct Stats {
     pub analysis_time_ms: u64,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UnusedFile {
     pub path: std::path::PathBuf,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UnusedDependency {
     pub name: String,
     pub package_json: std::path::PathBuf,
     pub workspace: Option<String>,
+    #[serde(default)]
+    pub is_dev: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MisplacedDependency {
+    pub name: String,
+    pub version: String,
+    pub package_json: std::path::PathBuf,
+    pub workspace: Option<String>,
+    pub is_dev: bool,
+    pub used_in: Vec<std::path::PathBuf>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TypeOnlyDependency {
+    pub name: String,
+    pub package_json: std::path::PathBuf,
+    pub workspace: Option<String>,
+    pub used_in: Vec<std::path::PathBuf>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UnpairedTypesPackage {
+    pub name: String,
+    pub package_name: String,
+    pub package_json: std::path::PathBuf,
+    pub workspace: Option<String>,
     pub is_dev: bool,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MissingTypesPackage {
+    pub name: String,
+    pub types_package: String,
+    pub used_in: Vec<std::path::PathBuf>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DependencyCredit {
+    pub name: String,
+    pub source: CreditSource,
+}
+
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum CreditSource {
+    Import { path: std::path::PathBuf },
+    Plugin { plugin: String },
+    ConfigFile { path: std::path::PathBuf, field: String },
+    Script { script: String, binary: String },
+    TsConfig { field: String },
+}
+
+impl CreditSource {
+    pub fn is_import(&self) -> bool {
+        matches!(self, CreditSource::Import { .. })
+    }
+
+    pub fn describe(&self, root: &Path) -> String {
+        let relative = |path: &Path| path.strip_prefix(root).unwrap_or(path).display().to_string();
+        match self {
+            CreditSource::Import { path } => format!("imported in {}", relative(path)),
+            CreditSource::Plugin { plugin } => format!("required by the {} plugin", plugin),
+            CreditSource::ConfigFile { path, field } => {
+                format!("referenced in {} ({})", relative(path), field)
+            }
+            CreditSource::Script { script, binary } => {
+                format!("binary \"{}\" used in script \"{}\"", binary, script)
+            }
+            CreditSource::TsConfig { field } => format!("referenced by tsconfig {}", field),
+        }
+    }
+}
+
+impl AnalysisResult {
+    pub fn credits_for<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a CreditSource> {
+        self.dependency_credits
+            .iter()
+            .filter(move |credit| credit.name == name)
+            .map(|credit| &credit.source)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UnusedExport {
     pub path: std::path::PathBuf,
     pub name: String,
@@ -123,7 +295,8 @@ pub struct UnusedExport {
     pub is_type: bool,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UnusedType {
     pub path: std::path::PathBuf,
     pub name: String,
@@ -132,19 +305,22 @@ pub struct UnusedType {
     pub kind: TypeKind,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UnlistedDependency {
     pub name: String,
     pub used_in: Vec<std::path::PathBuf>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UnlistedBinary {
     pub name: String,
     pub used_in: Vec<std::path::PathBuf>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UnresolvedImport {
     pub path: std::path::PathBuf,
     pub specifier: String,
@@ -152,20 +328,48 @@ pub struct UnresolvedImport {
     pub col: u32,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DuplicateExport {
     pub name: String,
     pub locations: Vec<ExportLocation>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ExportLocation {
     pub path: std::path::PathBuf,
     pub line: u32,
     pub col: u32,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UnusedAsset {
+    pub path: std::path::PathBuf,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UnusedCssClass {
+    pub path: std::path::PathBuf,
+    pub name: String,
+    pub line: u32,
+    pub col: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UnknownCssClass {
+    pub path: std::path::PathBuf,
+    pub stylesheet: std::path::PathBuf,
+    pub name: String,
+    pub line: u32,
+    pub col: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UnusedEnumMember {
     pub path: std::path::PathBuf,
     pub enum_name: String,
@@ -174,7 +378,8 @@ pub struct UnusedEnumMember {
     pub col: u32,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UnusedClassMember {
     pub path: std::path::PathBuf,
     pub class_name: String,
@@ -184,7 +389,8 @@ pub struct UnusedClassMember {
     pub col: u32,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
 pub enum ExportKind {
     Function,
     Class,
@@ -196,14 +402,16 @@ pub enum ExportKind {
     Default,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
 pub enum TypeKind {
     Type,
     Interface,
     Enum,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
 pub enum ClassMemberKind {
     Method,
     Property,
@@ -0,0 +1,189 @@
+use std::collections::HashMap;
+
+use lsp_types::{
+    CodeAction, CodeActionKind, CodeActionOrCommand, CodeActionParams, Command, Diagnostic,
+    NumberOrString, Position, Range, TextEdit, Url, WorkspaceEdit,
+};
+use serde_json::json;
+
+use crate::fixer::{self, TextChange};
+
+use super::diagnostics::{self, utf16_col};
+
+pub const ADD_DEPENDENCY: &str = "pior.addDependency";
+
+pub fn code_actions(params: &CodeActionParams, text: Option<&str>) -> Vec<CodeActionOrCommand> {
+    let uri = &params.text_document.uri;
+    let mut actions = Vec::new();
+
+    for diagnostic in &params.context.diagnostics {
+        if diagnostic.source.as_deref() != Some(diagnostics::SOURCE) {
+            continue;
+        }
+        let Some(NumberOrString::String(code)) = &diagnostic.code else {
+            continue;
+        };
+        let data = diagnostic.data.as_ref();
+        let name = data.and_then(|d| d["name"].as_str());
+
+        match code.as_str() {
+            diagnostics::UNUSED_EXPORT | diagnostics::UNUSED_TYPE => {
+                let (Some(text), Some(name), Some(line), Some(col)) = (
+                    text,
+                    name,
+                    data.and_then(|d| d["line"].as_u64()),
+                    data.and_then(|d| d["col"].as_u64()),
+                ) else {
+                    continue;
+                };
+                if let Some(change) = fixer::unexport_edit(text, line as u32, col as u32, name) {
+                    actions.push(unexport_action(uri, text, diagnostic, name, change));
+                }
+            }
+            diagnostics::UNLISTED_DEPENDENCY => {
+                let Some(name) = name else {
+                    continue;
+                };
+                for (dev, field) in [(false, "dependencies"), (true, "devDependencies")] {
+                    let title = format!("Add '{}' to {}", name, field);
+                    actions.push(CodeActionOrCommand::CodeAction(CodeAction {
+                        title: title.clone(),
+                        kind: Some(CodeActionKind::QUICKFIX),
+                        diagnostics: Some(vec![diagnostic.clone()]),
+                        command: Some(Command {
+                            title,
+                            command: ADD_DEPENDENCY.to_string(),
+                            arguments: Some(vec![json!(name), json!(dev)]),
+                        }),
+                        is_preferred: Some(!dev),
+                        ..Default::default()
+                    }));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    actions
+}
+
+fn unexport_action(
+    uri: &Url,
+    text: &str,
+    diagnostic: &Diagnostic,
+    name: &str,
+    change: TextChange,
+) -> CodeActionOrCommand {
+    let line_idx = change.line - 1;
+    let line_text = text.lines().nth(line_idx as usize).unwrap_or("");
+    let range = Range::new(
+        Position::new(
+            line_idx,
+            utf16_col(line_text, change.start_col as usize - 1),
+        ),
+        Position::new(line_idx, utf16_col(line_text, change.end_col as usize - 1)),
+    );
+
+    let title = if change.new_text.is_empty() {
+        "Remove export keyword".to_string()
+    } else {
+        format!("Remove '{}' from export list", name)
+    };
+
+    let edit = TextEdit::new(range, change.new_text);
+    CodeActionOrCommand::CodeAction(CodeAction {
+        title,
+        kind: Some(CodeActionKind::QUICKFIX),
+        diagnostics: Some(vec![diagnostic.clone()]),
+        edit: Some(WorkspaceEdit {
+            changes: Some(HashMap::from([(uri.clone(), vec![edit])])),
+            ..Default::default()
+        }),
+        is_preferred: Some(true),
+        ..Default::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lsp_types::{
+        CodeActionContext, PartialResultParams, TextDocumentIdentifier, WorkDoneProgressParams,
+    };
+
+    fn params(diagnostic: Diagnostic) -> CodeActionParams {
+        CodeActionParams {
+            text_document: TextDocumentIdentifier::new(
+                Url::parse("file:///project/src/a.ts").unwrap(),
+            ),
+            range: diagnostic.range,
+            context: CodeActionContext {
+                diagnostics: vec![diagnostic],
+                ..Default::default()
+            },
+            work_done_progress_params: WorkDoneProgressParams::default(),
+            partial_result_params: PartialResultParams::default(),
+        }
+    }
+
+    fn pior_diagnostic(code: &str, data: serde_json::Value) -> Diagnostic {
+        Diagnostic {
+            code: Some(NumberOrString::String(code.to_string())),
+            source: Some(diagnostics::SOURCE.to_string()),
+            data: Some(data),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_remove_export_keyword_action() {
+        let text = "import x from './x';\nexport function helper() {}\n";
+        let diagnostic = pior_diagnostic(
+            diagnostics::UNUSED_EXPORT,
+            json!({ "name": "helper", "line": 2, "col": 1 }),
+        );
+
+        let actions = code_actions(&params(diagnostic), Some(text));
+        assert_eq!(actions.len(), 1);
+
+        let CodeActionOrCommand::CodeAction(action) = &actions[0] else {
+            panic!("expected a code action");
+        };
+        assert_eq!(action.title, "Remove export keyword");
+        let changes = action.edit.as_ref().unwrap().changes.as_ref().unwrap();
+        let edit = &changes.values().next().unwrap()[0];
+        assert_eq!(
+            edit.range,
+            Range::new(Position::new(1, 0), Position::new(1, 7))
+        );
+        assert_eq!(edit.new_text, "");
+    }
+
+    #[test]
+    fn test_add_dependency_actions() {
+        let diagnostic =
+            pior_diagnostic(diagnostics::UNLISTED_DEPENDENCY, json!({ "name": "zod" }));
+
+        let actions = code_actions(&params(diagnostic), None);
+        let titles: Vec<_> = actions
+            .iter()
+            .map(|a| match a {
+                CodeActionOrCommand::CodeAction(action) => action.title.clone(),
+                CodeActionOrCommand::Command(command) => command.title.clone(),
+            })
+            .collect();
+
+        assert_eq!(
+            titles,
+            vec!["Add 'zod' to dependencies", "Add 'zod' to devDependencies"]
+        );
+    }
+
+    #[test]
+    fn test_ignores_foreign_diagnostics() {
+        let mut diagnostic =
+            pior_diagnostic(diagnostics::UNLISTED_DEPENDENCY, json!({ "name": "zod" }));
+        diagnostic.source = Some("eslint".to_string());
+        assert!(code_actions(&params(diagnostic), None).is_empty());
+    }
+}
@@ -0,0 +1,252 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use lsp_types::{Diagnostic, DiagnosticSeverity, DiagnosticTag, NumberOrString, Position, Range};
+use serde_json::json;
+
+use crate::config::{RuleLevel, RulesConfig};
+use crate::AnalysisResult;
+
+use super::Documents;
+
+pub const SOURCE: &str = "pior";
+
+pub const UNUSED_FILE: &str = "unused-file";
+pub const UNUSED_EXPORT: &str = "unused-export";
+pub const UNUSED_TYPE: &str = "unused-type";
+pub const UNRESOLVED_IMPORT: &str = "unresolved-import";
+pub const UNLISTED_DEPENDENCY: &str = "unlisted-dependency";
+
+pub fn collect(
+    result: &AnalysisResult,
+    rules: &RulesConfig,
+    documents: &Documents,
+) -> HashMap<PathBuf, Vec<Diagnostic>> {
+    let mut diagnostics: HashMap<PathBuf, Vec<Diagnostic>> = HashMap::new();
+    let mut texts: HashMap<PathBuf, Option<String>> = HashMap::new();
+    let mut text_of = |path: &PathBuf| -> Option<String> {
+        texts
+            .entry(path.clone())
+            .or_insert_with(|| documents.text(path))
+            .clone()
+    };
+
+    if let Some(severity) = severity(&rules.files) {
+        for file in &result.issues.files {
+            diagnostics
+                .entry(file.path.clone())
+                .or_default()
+                .push(diagnostic(
+                    Range::default(),
+                    severity,
+                    UNUSED_FILE,
+                    "File is not reachable from any entry point".to_string(),
+                    None,
+                ));
+        }
+    }
+
+    if let Some(severity) = severity(&rules.exports) {
+        for export in &result.issues.exports {
+            let text = text_of(&export.path);
+            diagnostics
+                .entry(export.path.clone())
+                .or_default()
+                .push(diagnostic(
+                    line_range(text.as_deref(), export.line, export.col),
+                    severity,
+                    UNUSED_EXPORT,
+                    format!("Unused export '{}'", export.name),
+                    Some(json!({ "name": export.name, "line": export.line, "col": export.col })),
+                ));
+        }
+    }
+
+    if let Some(severity) = severity(&rules.ns_exports) {
+        for export in &result.issues.ns_exports {
+            let text = text_of(&export.path);
+            diagnostics
+                .entry(export.path.clone())
+                .or_default()
+                .push(diagnostic(
+                    line_range(text.as_deref(), export.line, export.col),
+                    severity,
+                    UNUSED_EXPORT,
+                    format!("Unused export '{}' behind a namespace import", export.name),
+                    Some(json!({ "name": export.name, "line": export.line, "col": export.col })),
+                ));
+        }
+    }
+
+    if let Some(severity) = severity(&rules.types) {
+        for ty in &result.issues.types {
+            let text = text_of(&ty.path);
+            diagnostics
+                .entry(ty.path.clone())
+                .or_default()
+                .push(diagnostic(
+                    line_range(text.as_deref(), ty.line, ty.col),
+                    severity,
+                    UNUSED_TYPE,
+                    format!("Unused type '{}'", ty.name),
+                    Some(json!({ "name": ty.name, "line": ty.line, "col": ty.col })),
+                ));
+        }
+    }
+
+    if let Some(severity) = severity(&rules.ns_types) {
+        for ty in &result.issues.ns_types {
+            let text = text_of(&ty.path);
+            diagnostics
+                .entry(ty.path.clone())
+                .or_default()
+                .push(diagnostic(
+                    line_range(text.as_deref(), ty.line, ty.col),
+                    severity,
+                    UNUSED_TYPE,
+                    format!("Unused type '{}' behind a namespace import", ty.name),
+                    Some(json!({ "name": ty.name, "line": ty.line, "col": ty.col })),
+                ));
+        }
+    }
+
+    if let Some(severity) = severity(&rules.unresolved) {
+        for unresolved in &result.issues.unresolved {
+            let text = text_of(&unresolved.path);
+            diagnostics
+                .entry(unresolved.path.clone())
+                .or_default()
+                .push(diagnostic(
+                    line_range(text.as_deref(), unresolved.line, unresolved.col),
+                    severity,
+                    UNRESOLVED_IMPORT,
+                    format!("Unresolved import '{}'", unresolved.specifier),
+                    None,
+                ));
+        }
+    }
+
+    if let Some(severity) = severity(&rules.unlisted) {
+        for unlisted in &result.issues.unlisted {
+            for path in &unlisted.used_in {
+                let Some(range) = text_of(path).and_then(|t| specifier_range(&t, &unlisted.name))
+                else {
+                    continue;
+                };
+                diagnostics
+                    .entry(path.clone())
+                    .or_default()
+                    .push(diagnostic(
+                        range,
+                        severity,
+                        UNLISTED_DEPENDENCY,
+                        format!("'{}' is not listed in package.json", unlisted.name),
+                        Some(json!({ "name": unlisted.name })),
+                    ));
+            }
+        }
+    }
+
+    diagnostics
+}
+
+fn severity(level: &RuleLevel) -> Option<DiagnosticSeverity> {
+    match level {
+        RuleLevel::Error => Some(DiagnosticSeverity::ERROR),
+        RuleLevel::Warn => Some(DiagnosticSeverity::WARNING),
+        RuleLevel::Off => None,
+    }
+}
+
+fn diagnostic(
+    range: Range,
+    severity: DiagnosticSeverity,
+    code: &str,
+    message: String,
+    data: Option<serde_json::Value>,
+) -> Diagnostic {
+    let tags = matches!(code, UNUSED_FILE | UNUSED_EXPORT | UNUSED_TYPE)
+        .then(|| vec![DiagnosticTag::UNNECESSARY]);
+
+    Diagnostic {
+        range,
+        severity: Some(severity),
+        code: Some(NumberOrString::String(code.to_string())),
+        source: Some(SOURCE.to_string()),
+        message,
+        tags,
+        data,
+        ..Default::default()
+    }
+}
+
+pub fn utf16_col(line_text: &str, chars: usize) -> u32 {
+    line_text
+        .chars()
+        .take(chars)
+        .map(|c| c.len_utf16() as u32)
+        .sum()
+}
+
+pub fn line_range(text: Option<&str>, line: u32, col: u32) -> Range {
+    let line_idx = line.saturating_sub(1);
+    let line_text = text
+        .and_then(|t| t.lines().nth(line_idx as usize))
+        .unwrap_or("");
+    let start = utf16_col(line_text, col.saturating_sub(1) as usize);
+    let end = line_text.encode_utf16().count() as u32;
+
+    Range::new(
+        Position::new(line_idx, start),
+        Position::new(line_idx, end.max(start)),
+    )
+}
+
+fn specifier_range(text: &str, package: &str) -> Option<Range> {
+    for (line_idx, line) in text.lines().enumerate() {
+        for quote in ['\'', '"', '`'] {
+            let needle = format!("{}{}", quote, package);
+            let mut search = 0;
+            while let Some(found) = line[search..].find(&needle) {
+                let start = search + found + 1;
+                let tail = &line[start + package.len()..];
+                if tail.starts_with(quote) || tail.starts_with('/') {
+                    let end = start
+                        + tail
+                            .find(quote)
+                            .map_or(package.len(), |i| package.len() + i);
+                    let start_col = line[..start].encode_utf16().count() as u32;
+                    let end_col = line[..end].encode_utf16().count() as u32;
+                    return Some(Range::new(
+                        Position::new(line_idx as u32, start_col),
+                        Position::new(line_idx as u32, end_col),
+                    ));
+                }
+                search = start;
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_range_counts_utf16() {
+        let range = line_range(Some("const a = 1;\n/* é */ export const b = 2;"), 2, 9);
+        assert_eq!(range.start, Position::new(1, 8));
+        assert_eq!(range.end, Position::new(1, 27));
+    }
+
+    #[test]
+    fn test_specifier_range() {
+        let text =
+            "import a from './a';\nimport { z } from \"zod/v4\";\nimport zodiac from 'zodiac';";
+        let range = specifier_range(text, "zod").unwrap();
+        assert_eq!(range.start, Position::new(1, 19));
+        assert_eq!(range.end, Position::new(1, 25));
+        assert!(specifier_range(text, "zo").is_none());
+    }
+}
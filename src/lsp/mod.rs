@@ -0,0 +1,483 @@
+mod actions;
+mod diagnostics;
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::Result;
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, RequestId, Response};
+use lsp_types::notification::{
+    DidChangeTextDocument, DidChangeWatchedFiles, DidCloseTextDocument, DidOpenTextDocument,
+    DidSaveTextDocument, Notification as _, PublishDiagnostics,
+};
+use lsp_types::request::{CodeActionRequest, ExecuteCommand, Request as _};
+use lsp_types::{
+    CodeActionParams, CodeActionProviderCapability, Diagnostic, DidChangeTextDocumentParams,
+    DidCloseTextDocumentParams, DidOpenTextDocumentParams, ExecuteCommandOptions,
+    ExecuteCommandParams, InitializeParams, PublishDiagnosticsParams, ServerCapabilities,
+    TextDocumentSyncCapability, TextDocumentSyncKind, Url,
+};
+use serde::de::DeserializeOwned;
+
+use crate::analyzer::{analyze_project_with_options, AnalyzeOptions};
+use crate::config::load_config;
+
+pub use actions::ADD_DEPENDENCY;
+
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+#[derive(Default)]
+pub struct Documents {
+    open: HashMap<PathBuf, String>,
+}
+
+impl Documents {
+    pub fn text(&self, path: &Path) -> Option<String> {
+        match self.open.get(path) {
+            Some(text) => Some(text.clone()),
+            None => std::fs::read_to_string(path).ok(),
+        }
+    }
+}
+
+pub fn run_stdio() -> Result<()> {
+    let (connection, io_threads) = Connection::stdio();
+    run(connection)?;
+    io_threads.join()?;
+    Ok(())
+}
+
+pub fn run(connection: Connection) -> Result<()> {
+    let capabilities = ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
+        execute_command_provider: Some(ExecuteCommandOptions {
+            commands: vec![ADD_DEPENDENCY.to_string()],
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let params = connection.initialize(serde_json::to_value(capabilities)?)?;
+    let params: InitializeParams = serde_json::from_value(params)?;
+
+    let mut server = Server {
+        root: workspace_root(&params),
+        connection,
+        documents: Documents::default(),
+        published: HashSet::new(),
+        dirty: true,
+    };
+    server.main_loop()
+}
+
+#[allow(deprecated)]
+fn workspace_root(params: &InitializeParams) -> PathBuf {
+    let root = params
+        .workspace_folders
+        .as_ref()
+        .and_then(|folders| folders.first())
+        .map(|folder| &folder.uri)
+        .or(params.root_uri.as_ref())
+        .and_then(|uri| uri.to_file_path().ok())
+        .or_else(|| std::env::current_dir().ok())
+        .unwrap_or_else(|| PathBuf::from("."));
+    canonical(root)
+}
+
+fn canonical(path: PathBuf) -> PathBuf {
+    path.canonicalize().unwrap_or(path)
+}
+
+fn uri_path(uri: &Url) -> Option<PathBuf> {
+    uri.to_file_path().ok().map(canonical)
+}
+
+fn invalid_params(id: RequestId, error: serde_json::Error) -> Response {
+    Response::new_err(
+        id,
+        ErrorCode::InvalidParams as i32,
+        format!("Invalid params: {}", error),
+    )
+}
+
+fn notification_params<P: DeserializeOwned>(notification: Notification) -> Option<P> {
+    match serde_json::from_value(notification.params) {
+        Ok(params) => Some(params),
+        Err(e) => {
+            eprintln!("pior: ignoring malformed {}: {}", notification.method, e);
+            None
+        }
+    }
+}
+
+struct Server {
+    root: PathBuf,
+    connection: Connection,
+    documents: Documents,
+    published: HashSet<PathBuf>,
+    dirty: bool,
+}
+
+impl Server {
+    fn main_loop(&mut self) -> Result<()> {
+        loop {
+            let message = if self.dirty {
+                match self.connection.receiver.recv_timeout(DEBOUNCE) {
+                    Ok(message) => message,
+                    Err(e) if e.is_timeout() => {
+                        self.analyze_and_publish()?;
+                        continue;
+                    }
+                    Err(_) => return Ok(()),
+                }
+            } else {
+                match self.connection.receiver.recv() {
+                    Ok(message) => message,
+                    Err(_) => return Ok(()),
+                }
+            };
+
+            match message {
+                Message::Request(request) => {
+                    if self.connection.handle_shutdown(&request)? {
+                        return Ok(());
+                    }
+                    self.handle_request(request)?;
+                }
+                Message::Notification(notification) => self.handle_notification(notification)?,
+                Message::Response(_) => {}
+            }
+        }
+    }
+
+    fn handle_request(&mut self, request: Request) -> Result<()> {
+        let response = match request.method.as_str() {
+            CodeActionRequest::METHOD => {
+                match serde_json::from_value::<CodeActionParams>(request.params) {
+                    Ok(params) => {
+                        let text = uri_path(&params.text_document.uri)
+                            .and_then(|p| self.documents.text(&p));
+                        let actions = actions::code_actions(&params, text.as_deref());
+                        Response::new_ok(request.id, actions)
+                    }
+                    Err(e) => invalid_params(request.id, e),
+                }
+            }
+            ExecuteCommand::METHOD => {
+                match serde_json::from_value::<ExecuteCommandParams>(request.params) {
+                    Ok(params) => match self.execute_command(&params) {
+                        Ok(()) => Response::new_ok(request.id, serde_json::Value::Null),
+                        Err(e) => Response::new_err(
+                            request.id,
+                            ErrorCode::RequestFailed as i32,
+                            format!("{:#}", e),
+                        ),
+                    },
+                    Err(e) => invalid_params(request.id, e),
+                }
+            }
+            _ => Response::new_err(
+                request.id,
+                ErrorCode::MethodNotFound as i32,
+                format!("Unhandled request: {}", request.method),
+            ),
+        };
+
+        self.connection.sender.send(Message::Response(response))?;
+        Ok(())
+    }
+
+    fn execute_command(&mut self, params: &ExecuteCommandParams) -> Result<()> {
+        if params.command != ADD_DEPENDENCY {
+            anyhow::bail!("Unknown command: {}", params.command);
+        }
+
+        let name = params.arguments.first().and_then(|v| v.as_str());
+        let dev = params
+            .arguments
+            .get(1)
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        let Some(name) = name else {
+            anyhow::bail!("{} expects a package name", ADD_DEPENDENCY);
+        };
+
+        crate::fixer::add_dependency(&self.root, name, dev)?;
+        self.dirty = true;
+        Ok(())
+    }
+
+    fn handle_notification(&mut self, notification: Notification) -> Result<()> {
+        match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let Some(params) = notification_params::<DidOpenTextDocumentParams>(notification)
+                else {
+                    return Ok(());
+                };
+                if let Some(path) = uri_path(&params.text_document.uri) {
+                    self.documents.open.insert(path, params.text_document.text);
+                    self.dirty = true;
+                }
+            }
+            DidChangeTextDocument::METHOD => {
+                let Some(params) =
+                    notification_params::<DidChangeTextDocumentParams>(notification)
+                else {
+                    return Ok(());
+                };
+                if let (Some(path), Some(change)) = (
+                    uri_path(&params.text_document.uri),
+                    params.content_changes.into_iter().last(),
+                ) {
+                    self.documents.open.insert(path, change.text);
+                    self.dirty = true;
+                }
+            }
+            DidCloseTextDocument::METHOD => {
+                let Some(params) = notification_params::<DidCloseTextDocumentParams>(notification)
+                else {
+                    return Ok(());
+                };
+                if let Some(path) = uri_path(&params.text_document.uri) {
+                    self.documents.open.remove(&path);
+                    self.dirty = true;
+                }
+            }
+            DidSaveTextDocument::METHOD | DidChangeWatchedFiles::METHOD => {
+                self.dirty = true;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn analyze_and_publish(&mut self) -> Result<()> {
+        self.dirty = false;
+
+        let diagnostics = match self.analyze() {
+            Ok(diagnostics) => diagnostics,
+            Err(e) => {
+                eprintln!("pior: analysis failed: {:#}", e);
+                return Ok(());
+            }
+        };
+
+        let stale: Vec<PathBuf> = self
+            .published
+            .iter()
+            .filter(|path| !diagnostics.contains_key(*path))
+            .cloned()
+            .collect();
+        for path in stale {
+            self.publish(&path, Vec::new())?;
+        }
+
+        self.published = diagnostics.keys().cloned().collect();
+        for (path, diagnostics) in diagnostics {
+            self.publish(&path, diagnostics)?;
+        }
+        Ok(())
+    }
+
+    fn analyze(&self) -> Result<HashMap<PathBuf, Vec<Diagnostic>>> {
+        let config = load_config(&self.root, None)?;
+        let options = AnalyzeOptions {
+            overlay: self.documents.open.clone(),
+            ..Default::default()
+        };
+        let result = analyze_project_with_options(&config, options)?;
+        Ok(diagnostics::collect(
+            &result,
+            &config.config.rules,
+            &self.documents,
+        ))
+    }
+
+    fn publish(&self, path: &Path, diagnostics: Vec<Diagnostic>) -> Result<()> {
+        let Ok(uri) = Url::from_file_path(path) else {
+            return Ok(());
+        };
+        let params = PublishDiagnosticsParams::new(uri, diagnostics, None);
+        self.connection
+            .sender
+            .send(Message::Notification(Notification::new(
+                PublishDiagnostics::METHOD.to_string(),
+                params,
+            )))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn request(id: i32, method: &str, params: serde_json::Value) -> Message {
+        Message::Request(Request::new(
+            RequestId::from(id),
+            method.to_string(),
+            params,
+        ))
+    }
+
+    fn notification(method: &str, params: serde_json::Value) -> Message {
+        Message::Notification(Notification::new(method.to_string(), params))
+    }
+
+    fn wait_for_diagnostics(
+        client: &Connection,
+        uri: &Url,
+        predicate: impl Fn(&[Diagnostic]) -> bool,
+    ) -> Vec<Diagnostic> {
+        loop {
+            let message = client
+                .receiver
+                .recv_timeout(Duration::from_secs(10))
+                .expect("server did not publish diagnostics");
+            if let Message::Notification(n) = message {
+                if n.method != PublishDiagnostics::METHOD {
+                    continue;
+                }
+                let params: PublishDiagnosticsParams = serde_json::from_value(n.params).unwrap();
+                if &params.uri == uri && predicate(&params.diagnostics) {
+                    return params.diagnostics;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_diagnostics_follow_unsaved_buffers() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path().canonicalize().unwrap();
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(root.join("package.json"), r#"{"name": "app"}"#).unwrap();
+        fs::write(
+            root.join("src/index.ts"),
+            "import { a } from './lib';\nconsole.log(a);\n",
+        )
+        .unwrap();
+        fs::write(root.join("src/lib.ts"), "export const a = 1;\n").unwrap();
+
+        let (server, client) = Connection::memory();
+        let handle = std::thread::spawn(move || run(server));
+
+        let root_uri = Url::from_file_path(&root).unwrap();
+        client
+            .sender
+            .send(request(
+                1,
+                "initialize",
+                json!({ "capabilities": {}, "rootUri": root_uri }),
+            ))
+            .unwrap();
+        let Message::Response(response) = client.receiver.recv().unwrap() else {
+            panic!("expected initialize response");
+        };
+        assert!(
+            response.result.unwrap()["capabilities"]["codeActionProvider"]
+                .as_bool()
+                .unwrap()
+        );
+        client
+            .sender
+            .send(notification("initialized", json!({})))
+            .unwrap();
+
+        let lib_uri = Url::from_file_path(root.join("src/lib.ts")).unwrap();
+        client
+            .sender
+            .send(notification(
+                DidOpenTextDocument::METHOD,
+                json!({ "textDocument": {
+                    "uri": lib_uri, "languageId": "typescript", "version": 1,
+                    "text": "export const a = 1;\nexport const b = 2;\n"
+                }}),
+            ))
+            .unwrap();
+
+        let diagnostics = wait_for_diagnostics(&client, &lib_uri, |d| !d.is_empty());
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, "Unused export 'b'");
+        assert_eq!(diagnostics[0].range.start, lsp_types::Position::new(1, 0));
+
+        client
+            .sender
+            .send(notification(
+                DidChangeTextDocument::METHOD,
+                json!({
+                    "textDocument": { "uri": lib_uri, "version": 2 },
+                    "contentChanges": [{ "text": "export const a = 1;\n" }]
+                }),
+            ))
+            .unwrap();
+        wait_for_diagnostics(&client, &lib_uri, |d| d.is_empty());
+
+        client
+            .sender
+            .send(request(2, "shutdown", json!(null)))
+            .unwrap();
+        client
+            .sender
+            .send(notification("exit", json!(null)))
+            .unwrap();
+        handle.join().unwrap().unwrap();
+    }
+
+    #[test]
+    fn test_invalid_params_do_not_stop_server() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path().canonicalize().unwrap();
+        fs::write(root.join("package.json"), r#"{"name": "app"}"#).unwrap();
+
+        let (server, client) = Connection::memory();
+        let handle = std::thread::spawn(move || run(server));
+
+        let root_uri = Url::from_file_path(&root).unwrap();
+        client
+            .sender
+            .send(request(
+                1,
+                "initialize",
+                json!({ "capabilities": {}, "rootUri": root_uri }),
+            ))
+            .unwrap();
+        client.receiver.recv().unwrap();
+        client
+            .sender
+            .send(notification("initialized", json!({})))
+            .unwrap();
+
+        client
+            .sender
+            .send(notification(DidOpenTextDocument::METHOD, json!({})))
+            .unwrap();
+        client
+            .sender
+            .send(request(2, CodeActionRequest::METHOD, json!({ "bogus": true })))
+            .unwrap();
+        let response = loop {
+            match client.receiver.recv_timeout(Duration::from_secs(10)).unwrap() {
+                Message::Response(response) => break response,
+                _ => continue,
+            }
+        };
+        assert_eq!(response.id, RequestId::from(2));
+        assert_eq!(response.error.unwrap().code, ErrorCode::InvalidParams as i32);
+
+        client
+            .sender
+            .send(request(3, "shutdown", json!(null)))
+            .unwrap();
+        client
+            .sender
+            .send(notification("exit", json!(null)))
+            .unwrap();
+        handle.join().unwrap().unwrap();
+    }
+}
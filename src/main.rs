@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::process::ExitCode;
 use std::time::Instant;
 
@@ -5,6 +6,7 @@ use anyhow::Result;
 use clap::Parser;
 use owo_colors::OwoColorize;
 
+use pior::analyzer::{IncrementalAnalyzer, Refresh};
 use pior::cli::{Cli, Commands, OutputFormat};
 use pior::watch::{watch, WatchConfig};
 use pior::workspace::WorkspaceDiscovery;
@@ -65,6 +67,9 @@ fn run_watch_mode(cli: &Cli, path: &std::path::Path) -> Result<()> {
 
     let cli_clone = cli.clone();
     let path_clone = path.to_path_buf();
+    let mut analyzer =
+        IncrementalAnalyzer::new(path, cli.config.as_deref(), analyze_options(cli));
+    let baseline = new_only_baseline(cli, path)?;
 
     watch(path, watch_config, move |changed_files| {
         if !changed_files.is_empty() {
@@ -78,6 +83,9 @@ fn run_watch_mode(cli: &Cli, path: &std::path::Path) -> Result<()> {
             println!();
         }
 
+        let start = Instant::now();
+        let analysis = analyzer.analyze(changed_files);
+
         print!("\x1B[2J\x1B[1;1H");
 
         println!(
@@ -86,7 +94,21 @@ fn run_watch_mode(cli: &Cli, path: &std::path::Path) -> Result<()> {
             format!("v{}", env!("CARGO_PKG_VERSION")).dimmed()
         );
 
-        if let Err(e) = run_analysis(&cli_clone, &path_clone) {
+        let outcome = analysis.and_then(|(result, refresh)| {
+            if let Refresh::Incremental(update) = refresh {
+                println!(
+                    "{}",
+                    format!(
+                        "Incremental: {} parsed, {} removed, {} re-resolved\n",
+                        update.parsed, update.removed, update.reresolved
+                    )
+                    .dimmed()
+                );
+            }
+            report_result(&cli_clone, &path_clone, result, baseline.as_ref(), start)
+        });
+
+        if let Err(e) = outcome {
             eprintln!("{} {}", "error:".red().bold(), e);
         }
 
@@ -176,14 +198,63 @@ fn run_analysis(cli: &Cli, path: &std::path::Path) -> Result<()> {
         );
     }
 
-    let options = AnalyzeOptions {
+    let options = analyze_options(cli);
+
+    let result = match cli.from_report {
+        Some(ref report_path) => {
+            if cli.fix {
+                anyhow::bail!("--fix cannot be combined with --from-report");
+            }
+            pior::report::Report::load(report_path)?.into_result()
+        }
+        None => pior::analyze_with_options(path, cli.config.as_deref(), options)?,
+    };
+
+    let baseline = new_only_baseline(cli, path)?;
+    report_result(cli, path, result, baseline.as_ref(), start)
+}
+
+fn analyze_options(cli: &Cli) -> AnalyzeOptions {
+    AnalyzeOptions {
         cache: cli.cache,
         cache_dir: cli.cache_dir.clone(),
         production: cli.production,
         strict: cli.strict,
-    };
+        tsconfig: cli.tsconfig.clone(),
+        ..Default::default()
+    }
+}
+
+fn new_only_baseline(cli: &Cli, path: &std::path::Path) -> Result<Option<HashSet<String>>> {
+    match cli.changed_since {
+        Some(ref base_ref) if cli.new_only => {
+            let baseline = pior::changes::analyze_base(
+                path,
+                base_ref,
+                cli.config.as_deref(),
+                analyze_options(cli),
+            )?;
+            Ok(Some(baseline))
+        }
+        _ => Ok(None),
+    }
+}
 
-    let result = pior::analyze_with_options(path, cli.config.as_deref(), options)?;
+fn report_result(
+    cli: &Cli,
+    path: &std::path::Path,
+    mut result: pior::AnalysisResult,
+    baseline: Option<&HashSet<String>>,
+    start: Instant,
+) -> Result<()> {
+    if let Some(ref base_ref) = cli.changed_since {
+        let changed = pior::changes::git::changed_files(path, base_ref)?;
+        pior::changes::retain_changed(&mut result, &changed);
+
+        if let Some(baseline) = baseline {
+            pior::changes::retain_new(&mut result, path, baseline);
+        }
+    }
 
     let duration = start.elapsed();
 
@@ -205,6 +276,20 @@ fn run_analysis(cli: &Cli, path: &std::path::Path) -> Result<()> {
                     fix_result.dev_dependencies_removed.join(", ").dimmed()
                 );
             }
+            if !fix_result.dev_dependencies_moved.is_empty() {
+                println!(
+                    "[fixed] Moved {} devDependencies to dependencies: {}",
+                    fix_result.dev_dependencies_moved.len(),
+                    fix_result.dev_dependencies_moved.join(", ").dimmed()
+                );
+            }
+            if !fix_result.dependencies_moved.is_empty() {
+                println!(
+                    "[fixed] Moved {} dependencies to devDependencies: {}",
+                    fix_result.dependencies_moved.len(),
+                    fix_result.dependencies_moved.join(", ").dimmed()
+                );
+            }
             if !fix_result.exports_removed.is_empty() {
                 println!(
                     "[fixed] Removed {} unused exports",
@@ -217,12 +302,21 @@ fn run_analysis(cli: &Cli, path: &std::path::Path) -> Result<()> {
 
     match cli.format {
         OutputFormat::Pretty => print_pretty(&result, duration, cli),
-        OutputFormat::Json => print_json(&result, duration)?,
+        OutputFormat::Json => print_json(&result, duration, path, cli)?,
         OutputFormat::Compact => print_compact(&result),
         OutputFormat::Github => print_github(&result),
         OutputFormat::Codeclimate => print_codeclimate(&result)?,
     }
 
+    if let Some(ref name) = cli.trace_dependency {
+        let root = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        print_dependency_trace(&result, name, &root);
+    }
+
+    if let Some(ref specifier) = cli.trace_resolution {
+        print_resolution_trace(cli, path, specifier)?;
+    }
+
     if cli.no_exit_code || cli.watch {
         return Ok(());
     }
@@ -240,6 +334,50 @@ fn run_analysis(cli: &Cli, path: &std::path::Path) -> Result<()> {
     Ok(())
 }
 
+fn print_dependency_trace(result: &pior::AnalysisResult, name: &str, root: &std::path::Path) {
+    let sources: Vec<_> = result.credits_for(name).collect();
+
+    if sources.is_empty() {
+        eprintln!(
+            "{} {} is not referenced by any import, plugin, config file, script or tsconfig",
+            "[trace]".cyan(),
+            name.bold()
+        );
+        return;
+    }
+
+    eprintln!("{} {} counts as used:", "[trace]".cyan(), name.bold());
+    for source in sources {
+        eprintln!("  - {}", source.describe(root));
+    }
+}
+
+fn print_resolution_trace(cli: &Cli, path: &std::path::Path, specifier: &str) -> Result<()> {
+    let traces =
+        pior::trace_resolution(path, cli.config.as_deref(), &analyze_options(cli), specifier)?;
+
+    if traces.is_empty() {
+        eprintln!(
+            "{} no project file imports {}",
+            "[trace]".cyan(),
+            specifier.bold()
+        );
+        return Ok(());
+    }
+
+    for trace in traces {
+        for line in &trace.lines {
+            if line.starts_with("========") {
+                eprintln!("{} {}", "[trace]".cyan(), line);
+            } else {
+                eprintln!("{} {}", "[trace]".cyan(), line.dimmed());
+            }
+        }
+    }
+
+    Ok(())
+}
+
 fn handle_command(command: &Commands) -> Result<()> {
     match command {
         Commands::Init { format } => {
@@ -262,6 +400,12 @@ fn handle_command(command: &Commands) -> Result<()> {
             println!("{} Created {}", "✓".green(), filename.green());
             Ok(())
         }
+        Commands::Schema => {
+            let schema = pior::report::Report::json_schema();
+            println!("{}", serde_json::to_string_pretty(&schema)?);
+            Ok(())
+        }
+        Commands::Lsp => pior::lsp::run_stdio(),
     }
 }
 
@@ -309,6 +453,86 @@ fn print_pretty(result: &pior::AnalysisResult, duration: std::time::Duration, cl
         println!();
     }
 
+    if !issues.misplaced_dev_dependencies.is_empty() {
+        println!(
+            "DevDependencies used in production ({})",
+            issues.misplaced_dev_dependencies.len().to_string().yellow()
+        );
+        for dep in &issues.misplaced_dev_dependencies {
+            println!(
+                "   {} ({}) - used in {}",
+                dep.name.red(),
+                dep.package_json.display().dimmed(),
+                format_used_in(&dep.used_in).dimmed()
+            );
+        }
+        println!();
+    }
+
+    if !issues.misplaced_dependencies.is_empty() {
+        println!(
+            "Dependencies only used in development ({})",
+            issues.misplaced_dependencies.len().to_string().yellow()
+        );
+        for dep in &issues.misplaced_dependencies {
+            println!(
+                "   {} ({}) - used in {}",
+                dep.name.red(),
+                dep.package_json.display().dimmed(),
+                format_used_in(&dep.used_in).dimmed()
+            );
+        }
+        println!();
+    }
+
+    if !issues.type_only_dependencies.is_empty() {
+        println!(
+            "Dependencies only imported for types ({})",
+            issues.type_only_dependencies.len().to_string().yellow()
+        );
+        for dep in &issues.type_only_dependencies {
+            println!(
+                "   {} ({}) - used in {}",
+                dep.name.red(),
+                dep.package_json.display().dimmed(),
+                format_used_in(&dep.used_in).dimmed()
+            );
+        }
+        println!();
+    }
+
+    if !issues.unpaired_types.is_empty() {
+        println!(
+            "Unpaired @types packages ({})",
+            issues.unpaired_types.len().to_string().yellow()
+        );
+        for types in &issues.unpaired_types {
+            println!(
+                "   {} ({}) - '{}' is not a dependency",
+                types.name.red(),
+                types.package_json.display().dimmed(),
+                types.package_name
+            );
+        }
+        println!();
+    }
+
+    if !issues.missing_types.is_empty() {
+        println!(
+            "Missing @types packages ({})",
+            issues.missing_types.len().to_string().yellow()
+        );
+        for missing in &issues.missing_types {
+            println!(
+                "   {} - add {} (used in {})",
+                missing.name.yellow(),
+                missing.types_package.cyan(),
+                format_used_in(&missing.used_in).dimmed()
+            );
+        }
+        println!();
+    }
+
     if !issues.exports.is_empty() {
         println!(
             "Unused exports ({})",
@@ -345,6 +569,42 @@ fn print_pretty(result: &pior::AnalysisResult, duration: std::time::Duration, cl
         println!();
     }
 
+    if !issues.ns_exports.is_empty() {
+        println!(
+            "Unused exports in namespaces ({})",
+            issues.ns_exports.len().to_string().yellow()
+        );
+        for export in &issues.ns_exports {
+            println!(
+                "   {}:{}:{} - {} ({:?})",
+                export.path.display().dimmed(),
+                export.line,
+                export.col,
+                export.name.cyan(),
+                export.kind
+            );
+        }
+        println!();
+    }
+
+    if !issues.ns_types.is_empty() {
+        println!(
+            "Unused types in namespaces ({})",
+            issues.ns_types.len().to_string().yellow()
+        );
+        for t in &issues.ns_types {
+            println!(
+                "   {}:{}:{} - {} ({:?})",
+                t.path.display().dimmed(),
+                t.line,
+                t.col,
+                t.name.cyan(),
+                t.kind
+            );
+        }
+        println!();
+    }
+
     if !issues.unlisted.is_empty() {
         println!(
             "Unlisted dependencies ({})",
@@ -380,6 +640,52 @@ fn print_pretty(result: &pior::AnalysisResult, duration: std::time::Duration, cl
         println!();
     }
 
+    if !issues.assets.is_empty() {
+        println!(
+            "Unused assets ({})",
+            issues.assets.len().to_string().yellow()
+        );
+        for asset in &issues.assets {
+            println!("   {}", asset.path.display().dimmed());
+        }
+        println!();
+    }
+
+    if !issues.css_classes.is_empty() {
+        println!(
+            "Unused CSS classes ({})",
+            issues.css_classes.len().to_string().yellow()
+        );
+        for class in &issues.css_classes {
+            println!(
+                "   {}:{}:{} - {}",
+                class.path.display().dimmed(),
+                class.line,
+                class.col,
+                class.name.cyan()
+            );
+        }
+        println!();
+    }
+
+    if !issues.unknown_css_classes.is_empty() {
+        println!(
+            "Unknown CSS classes ({})",
+            issues.unknown_css_classes.len().to_string().yellow()
+        );
+        for class in &issues.unknown_css_classes {
+            println!(
+                "   {}:{}:{} - {} is not defined in {}",
+                class.path.display().dimmed(),
+                class.line,
+                class.col,
+                class.name.red(),
+                class.stylesheet.display()
+            );
+        }
+        println!();
+    }
+
     let total = result.counters.total();
     if total == 0 {
         println!("{}", "No issues found!".green().bold());
@@ -395,12 +701,45 @@ fn print_pretty(result: &pior::AnalysisResult, duration: std::time::Duration, cl
                 result.counters.unlisted.to_string().yellow()
             );
         }
+        let misplaced =
+            result.counters.misplaced_dependencies + result.counters.misplaced_dev_dependencies;
+        if misplaced > 0 {
+            println!("   Misplaced:    {} dependencies", misplaced.to_string().yellow());
+        }
+        let typings = result.counters.type_only_dependencies
+            + result.counters.unpaired_types
+            + result.counters.missing_types;
+        if typings > 0 {
+            println!(
+                "   Typings:      {} type-only, {} unpaired, {} missing",
+                result.counters.type_only_dependencies.to_string().yellow(),
+                result.counters.unpaired_types.to_string().yellow(),
+                result.counters.missing_types.to_string().yellow()
+            );
+        }
         if result.counters.exports > 0 {
             println!("   Exports:      {} unused", result.counters.exports.to_string().yellow());
         }
         if result.counters.types > 0 {
             println!("   Types:        {} unused", result.counters.types.to_string().yellow());
         }
+        if result.counters.ns_exports > 0 || result.counters.ns_types > 0 {
+            println!(
+                "   Namespaces:   {} exports, {} types unused",
+                result.counters.ns_exports.to_string().yellow(),
+                result.counters.ns_types.to_string().yellow()
+            );
+        }
+        if result.counters.assets > 0 {
+            println!("   Assets:       {} unused", result.counters.assets.to_string().yellow());
+        }
+        if result.counters.css_classes > 0 || result.counters.unknown_css_classes > 0 {
+            println!(
+                "   CSS classes:  {} unused, {} unknown",
+                result.counters.css_classes.to_string().yellow(),
+                result.counters.unknown_css_classes.to_string().yellow()
+            );
+        }
         println!("   Total:        {} issues", total.to_string().red().bold());
     }
 
@@ -424,6 +763,8 @@ fn print_pretty(result: &pior::AnalysisResult, duration: std::time::Duration, cl
     if total > 0 && !cli.fix {
         let fixable = result.counters.dependencies
             + result.counters.dev_dependencies
+            + result.counters.misplaced_dependencies
+            + result.counters.misplaced_dev_dependencies
             + result.counters.exports;
         if fixable > 0 {
             println!();
@@ -436,68 +777,34 @@ fn print_pretty(result: &pior::AnalysisResult, duration: std::time::Duration, cl
     }
 }
 
-fn print_json(result: &pior::AnalysisResult, duration: std::time::Duration) -> Result<()> {
-    use serde_json::json;
+fn format_used_in(paths: &[std::path::PathBuf]) -> String {
+    let mut shown: Vec<String> = paths.iter().take(3).map(|p| p.display().to_string()).collect();
+    if paths.len() > 3 {
+        shown.push(format!("and {} more", paths.len() - 3));
+    }
+    shown.join(", ")
+}
 
-    let output = json!({
-        "version": env!("CARGO_PKG_VERSION"),
-        "issues": {
-            "files": result.issues.files.iter().map(|f| json!({
-                "path": f.path.display().to_string()
-            })).collect::<Vec<_>>(),
-            "dependencies": result.issues.dependencies.iter().map(|d| json!({
-                "name": d.name,
-                "packageJson": d.package_json.display().to_string(),
-                "workspace": d.workspace
-            })).collect::<Vec<_>>(),
-            "devDependencies": result.issues.dev_dependencies.iter().map(|d| json!({
-                "name": d.name,
-                "packageJson": d.package_json.display().to_string(),
-                "workspace": d.workspace
-            })).collect::<Vec<_>>(),
-            "exports": result.issues.exports.iter().map(|e| json!({
-                "path": e.path.display().to_string(),
-                "name": e.name,
-                "line": e.line,
-                "col": e.col,
-                "kind": format!("{:?}", e.kind).to_lowercase(),
-                "isType": e.is_type
-            })).collect::<Vec<_>>(),
-            "types": result.issues.types.iter().map(|t| json!({
-                "path": t.path.display().to_string(),
-                "name": t.name,
-                "line": t.line,
-                "col": t.col,
-                "kind": format!("{:?}", t.kind).to_lowercase()
-            })).collect::<Vec<_>>(),
-            "unlisted": result.issues.unlisted.iter().map(|u| json!({
-                "name": u.name,
-                "usedIn": u.used_in.iter().map(|p| p.display().to_string()).collect::<Vec<_>>()
-            })).collect::<Vec<_>>(),
-            "unresolved": result.issues.unresolved.iter().map(|u| json!({
-                "path": u.path.display().to_string(),
-                "specifier": u.specifier,
-                "line": u.line,
-                "col": u.col
-            })).collect::<Vec<_>>(),
-        },
-        "counters": {
-            "files": result.counters.files,
-            "dependencies": result.counters.dependencies,
-            "devDependencies": result.counters.dev_dependencies,
-            "exports": result.counters.exports,
-            "types": result.counters.types,
-            "unlisted": result.counters.unlisted,
-            "unresolved": result.counters.unresolved,
-            "total": result.counters.total()
-        },
-        "stats": {
-            "filesAnalyzed": result.stats.files_analyzed,
-            "durationMs": duration.as_millis() as u64
-        }
-    });
-
-    println!("{}", serde_json::to_string_pretty(&output)?);
+fn print_json(
+    result: &pior::AnalysisResult,
+    duration: std::time::Duration,
+    root: &std::path::Path,
+    cli: &Cli,
+) -> Result<()> {
+    use pior::report::{PathStyle, Report};
+
+    let paths = if cli.absolute_paths {
+        PathStyle::Absolute
+    } else {
+        PathStyle::Relative
+    };
+
+    let mut report = Report::new(result, root, paths);
+    if cli.from_report.is_none() {
+        report.stats.duration_ms = duration.as_millis() as u64;
+    }
+
+    println!("{}", serde_json::to_string_pretty(&report)?);
     Ok(())
 }
 
@@ -511,6 +818,21 @@ fn print_compact(result: &pior::AnalysisResult) {
     for dep in &result.issues.dev_dependencies {
         println!("{}: unused devDependency", dep.name);
     }
+    for dep in &result.issues.misplaced_dev_dependencies {
+        println!("{}: devDependency used in production", dep.name);
+    }
+    for dep in &result.issues.misplaced_dependencies {
+        println!("{}: dependency only used in development", dep.name);
+    }
+    for dep in &result.issues.type_only_dependencies {
+        println!("{}: dependency only imported for types", dep.name);
+    }
+    for types in &result.issues.unpaired_types {
+        println!("{}: unpaired types package", types.name);
+    }
+    for missing in &result.issues.missing_types {
+        println!("{}: missing types package {}", missing.name, missing.types_package);
+    }
     for export in &result.issues.exports {
         println!(
             "{}:{}:{}: unused export '{}'",
@@ -529,6 +851,24 @@ fn print_compact(result: &pior::AnalysisResult) {
             t.name
         );
     }
+    for export in &result.issues.ns_exports {
+        println!(
+            "{}:{}:{}: unused export in namespace '{}'",
+            export.path.display(),
+            export.line,
+            export.col,
+            export.name
+        );
+    }
+    for t in &result.issues.ns_types {
+        println!(
+            "{}:{}:{}: unused type in namespace '{}'",
+            t.path.display(),
+            t.line,
+            t.col,
+            t.name
+        );
+    }
     for dep in &result.issues.unlisted {
         println!("{}: unlisted dependency", dep.name);
     }
@@ -541,6 +881,27 @@ fn print_compact(result: &pior::AnalysisResult) {
             import.specifier
         );
     }
+    for asset in &result.issues.assets {
+        println!("{}: unused asset", asset.path.display());
+    }
+    for class in &result.issues.css_classes {
+        println!(
+            "{}:{}:{}: unused CSS class '{}'",
+            class.path.display(),
+            class.line,
+            class.col,
+            class.name
+        );
+    }
+    for class in &result.issues.unknown_css_classes {
+        println!(
+            "{}:{}:{}: unknown CSS class '{}'",
+            class.path.display(),
+            class.line,
+            class.col,
+            class.name
+        );
+    }
 }
 
 fn print_github(result: &pior::AnalysisResult) {
@@ -561,6 +922,42 @@ fn print_github(result: &pior::AnalysisResult) {
             dep.name
         );
     }
+    for dep in &result.issues.misplaced_dev_dependencies {
+        println!(
+            "::error file={}::DevDependency '{}' is used in production code",
+            dep.package_json.display(),
+            dep.name
+        );
+    }
+    for dep in &result.issues.misplaced_dependencies {
+        println!(
+            "::warning file={}::Dependency '{}' is only used in development",
+            dep.package_json.display(),
+            dep.name
+        );
+    }
+    for dep in &result.issues.type_only_dependencies {
+        println!(
+            "::warning file={}::Dependency '{}' is only imported for types",
+            dep.package_json.display(),
+            dep.name
+        );
+    }
+    for types in &result.issues.unpaired_types {
+        println!(
+            "::warning file={}::Types package '{}' has no matching '{}' dependency",
+            types.package_json.display(),
+            types.name,
+            types.package_name
+        );
+    }
+    for missing in &result.issues.missing_types {
+        println!(
+            "::warning::Package '{}' has no types, add '{}'",
+            missing.name,
+            missing.types_package
+        );
+    }
     for export in &result.issues.exports {
         println!(
             "::warning file={},line={},col={}::Unused export '{}'",
@@ -579,6 +976,24 @@ fn print_github(result: &pior::AnalysisResult) {
             t.name
         );
     }
+    for export in &result.issues.ns_exports {
+        println!(
+            "::warning file={},line={},col={}::Unused export in namespace '{}'",
+            export.path.display(),
+            export.line,
+            export.col,
+            export.name
+        );
+    }
+    for t in &result.issues.ns_types {
+        println!(
+            "::warning file={},line={},col={}::Unused type in namespace '{}'",
+            t.path.display(),
+            t.line,
+            t.col,
+            t.name
+        );
+    }
     for dep in &result.issues.unlisted {
         println!("::error::Unlisted dependency '{}'", dep.name);
     }
@@ -591,6 +1006,28 @@ fn print_github(result: &pior::AnalysisResult) {
             import.specifier
         );
     }
+    for asset in &result.issues.assets {
+        println!("::warning file={}::Unused asset", asset.path.display());
+    }
+    for class in &result.issues.css_classes {
+        println!(
+            "::warning file={},line={},col={}::Unused CSS class '{}'",
+            class.path.display(),
+            class.line,
+            class.col,
+            class.name
+        );
+    }
+    for class in &result.issues.unknown_css_classes {
+        println!(
+            "::error file={},line={},col={}::CSS class '{}' is not defined in {}",
+            class.path.display(),
+            class.line,
+            class.col,
+            class.name,
+            class.stylesheet.display()
+        );
+    }
 }
 
 fn print_codeclimate(result: &pior::AnalysisResult) -> Result<()> {
@@ -640,6 +1077,76 @@ fn print_codeclimate(result: &pior::AnalysisResult) -> Result<()> {
         }));
     }
 
+    for export in &result.issues.ns_exports {
+        issues.push(json!({
+            "type": "issue",
+            "check_name": "unused-ns-export",
+            "description": format!("Unused export in namespace: {}", export.name),
+            "categories": ["Clarity"],
+            "severity": "minor",
+            "location": {
+                "path": export.path.display().to_string(),
+                "lines": { "begin": export.line, "end": export.line }
+            }
+        }));
+    }
+
+    for t in &result.issues.ns_types {
+        issues.push(json!({
+            "type": "issue",
+            "check_name": "unused-ns-type",
+            "description": format!("Unused type in namespace: {}", t.name),
+            "categories": ["Clarity"],
+            "severity": "minor",
+            "location": {
+                "path": t.path.display().to_string(),
+                "lines": { "begin": t.line, "end": t.line }
+            }
+        }));
+    }
+
+    for asset in &result.issues.assets {
+        issues.push(json!({
+            "type": "issue",
+            "check_name": "unused-asset",
+            "description": "Unused asset",
+            "categories": ["Clarity"],
+            "severity": "minor",
+            "location": {
+                "path": asset.path.display().to_string(),
+                "lines": { "begin": 1, "end": 1 }
+            }
+        }));
+    }
+
+    for class in &result.issues.css_classes {
+        issues.push(json!({
+            "type": "issue",
+            "check_name": "unused-css-class",
+            "description": format!("Unused CSS class: {}", class.name),
+            "categories": ["Clarity"],
+            "severity": "minor",
+            "location": {
+                "path": class.path.display().to_string(),
+                "lines": { "begin": class.line, "end": class.line }
+            }
+        }));
+    }
+
+    for class in &result.issues.unknown_css_classes {
+        issues.push(json!({
+            "type": "issue",
+            "check_name": "unknown-css-class",
+            "description": format!("Unknown CSS class: {}", class.name),
+            "categories": ["Bug Risk"],
+            "severity": "major",
+            "location": {
+                "path": class.path.display().to_string(),
+                "lines": { "begin": class.line, "end": class.line }
+            }
+        }));
+    }
+
     println!("{}", serde_json::to_string_pretty(&issues)?);
     Ok(())
 }
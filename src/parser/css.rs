@@ -0,0 +1,435 @@
+use std::path::Path;
+
+use super::exports::{Export, ExportKind};
+use super::imports::{Import, ImportedName};
+use super::typescript::ParsedModule;
+
+pub const STYLESHEET_EXTENSIONS: &[&str] = &["css", "scss", "sass", "less"];
+
+pub const ASSET_EXTENSIONS: &[&str] = &[
+    "png", "jpg", "jpeg", "gif", "svg", "webp", "avif", "ico", "bmp", "woff", "woff2", "ttf",
+    "otf", "eot", "mp4", "webm", "mp3", "wav",
+];
+
+pub fn is_stylesheet(path: &Path) -> bool {
+    has_extension(path, STYLESHEET_EXTENSIONS)
+}
+
+pub fn is_css_module(path: &Path) -> bool {
+    is_stylesheet(path)
+        && path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .is_some_and(|stem| stem.ends_with(".module"))
+}
+
+pub fn is_asset(path: &Path) -> bool {
+    has_extension(path, ASSET_EXTENSIONS) || is_stylesheet(path)
+}
+
+fn has_extension(path: &Path, extensions: &[&str]) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|ext| extensions.contains(&ext.to_ascii_lowercase().as_str()))
+}
+
+pub fn parse_stylesheet(source: &str, path: &Path) -> ParsedModule {
+    let source = strip_comments(source);
+    let mut imports = Vec::new();
+    let mut at_rule_spans = Vec::new();
+
+    for keyword in ["@import", "@use", "@forward"] {
+        let mut search = 0;
+        while let Some(found) = source[search..].find(keyword) {
+            let start = search + found;
+            let after = start + keyword.len();
+            search = after;
+
+            if !source[after..].starts_with(|c: char| c.is_whitespace()) {
+                continue;
+            }
+
+            let end = source[after..]
+                .find([';', '\n', '{'])
+                .map_or(source.len(), |i| after + i);
+            at_rule_spans.push((start, end));
+
+            for raw in rule_specifiers(&source[after..end]) {
+                if let Some(specifier) = normalize_specifier(&raw, false) {
+                    imports.push(import(&source, start, specifier));
+                }
+            }
+        }
+    }
+
+    let mut search = 0;
+    while let Some(found) = source[search..].find("url(") {
+        let start = search + found;
+        let inner_start = start + "url(".len();
+        let Some(close) = source[inner_start..].find(')') else {
+            break;
+        };
+        search = inner_start + close + 1;
+
+        if at_rule_spans.iter().any(|&(s, e)| start >= s && start < e) {
+            continue;
+        }
+
+        if let Some(specifier) = normalize_specifier(&source[inner_start..inner_start + close], true) {
+            imports.push(import(&source, start, specifier));
+        }
+    }
+
+    let mut exports = Vec::new();
+    if is_css_module(path) {
+        exports = extract_classes(&source);
+        imports.extend(extract_composes(&source, path));
+    }
+
+    imports.sort_by_key(|i| (i.line, i.col));
+
+    ParsedModule {
+        imports,
+        exports,
+        re_exports: Vec::new(),
+        glob_imports: Vec::new(),
+    }
+}
+
+fn extract_classes(source: &str) -> Vec<Export> {
+    let mut classes: Vec<Export> = Vec::new();
+    let mut parents: Vec<Option<String>> = Vec::new();
+    let mut segment_start = 0;
+
+    for (i, c) in source.char_indices() {
+        match c {
+            '{' => {
+                let segment = &source[segment_start..i];
+                let mut first = None;
+                if !segment.trim_start().starts_with('@') {
+                    let parent = parents.iter().rev().flatten().next().cloned();
+                    for (offset, name) in selector_classes(segment, parent.as_deref()) {
+                        first.get_or_insert_with(|| name.clone());
+                        if !classes.iter().any(|e| e.name == name) {
+                            let (line, col) = line_col(source, segment_start + offset);
+                            classes.push(Export {
+                                name,
+                                kind: ExportKind::CssClass,
+                                is_type: false,
+                                is_default: false,
+                                line,
+                                col,
+                            });
+                        }
+                    }
+                }
+                parents.push(first);
+                segment_start = i + 1;
+            }
+            '}' => {
+                parents.pop();
+                segment_start = i + 1;
+            }
+            ';' => segment_start = i + 1,
+            _ => {}
+        }
+    }
+
+    classes
+}
+
+fn selector_classes(selector: &str, parent: Option<&str>) -> Vec<(usize, String)> {
+    let bytes = selector.as_bytes();
+    let mut classes = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'[' => i = selector[i..].find(']').map_or(bytes.len(), |end| i + end + 1),
+            b':' if selector[i..].starts_with(":global") => {
+                let after = i + ":global".len();
+                i = if bytes.get(after) == Some(&b'(') {
+                    selector[after..].find(')').map_or(bytes.len(), |end| after + end + 1)
+                } else {
+                    selector[after..].find(',').map_or(bytes.len(), |end| after + end)
+                };
+            }
+            b'.' => {
+                let name = ident_at(selector, i + 1);
+                if !name.is_empty() && !name.starts_with(|c: char| c.is_ascii_digit()) {
+                    classes.push((i, name.to_string()));
+                }
+                i += 1 + name.len();
+            }
+            b'&' => {
+                let suffix = ident_at(selector, i + 1);
+                if let (Some(parent), false) = (parent, suffix.is_empty()) {
+                    classes.push((i, format!("{}{}", parent, suffix)));
+                }
+                i += 1 + suffix.len();
+            }
+            _ => i += 1,
+        }
+    }
+
+    classes
+}
+
+fn ident_at(source: &str, start: usize) -> &str {
+    let end = source[start..]
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '-' || c == '_' || !c.is_ascii()))
+        .map_or(source.len(), |len| start + len);
+    &source[start..end]
+}
+
+fn extract_composes(source: &str, path: &Path) -> Vec<Import> {
+    let mut imports = Vec::new();
+    let own_file = path.file_name().map(|n| n.to_string_lossy().into_owned());
+
+    let mut search = 0;
+    while let Some(found) = source[search..].find("composes:") {
+        let start = search + found;
+        let value_start = start + "composes:".len();
+        let end = source[value_start..]
+            .find([';', '}', '\n'])
+            .map_or(source.len(), |i| value_start + i);
+        search = end;
+
+        let value = &source[value_start..end];
+        let (names, from) = match value.split_once(" from ") {
+            Some((names, from)) => (names, Some(from.trim().trim_matches(['"', '\'']))),
+            None => (value, None),
+        };
+        let specifier = match (from, &own_file) {
+            (Some("global"), _) | (None, None) => continue,
+            (Some(from), _) => from.to_string(),
+            (None, Some(own_file)) => format!("./{}", own_file),
+        };
+
+        let (line, col) = line_col(source, start);
+        imports.push(Import {
+            specifier,
+            imported_names: names
+                .split_whitespace()
+                .map(|name| ImportedName {
+                    name: name.to_string(),
+                    alias: None,
+                    is_type: false,
+                    members: None,
+                })
+                .collect(),
+            is_type_only: false,
+            is_side_effect: false,
+            line,
+            col,
+        });
+    }
+
+    imports
+}
+
+fn rule_specifiers(rule: &str) -> Vec<String> {
+    let mut specifiers = Vec::new();
+    let mut rest = rule.trim();
+
+    while !rest.is_empty() {
+        if let Some(inner) = rest.strip_prefix("url(") {
+            let close = inner.find(')').unwrap_or(inner.len());
+            specifiers.push(inner[..close].to_string());
+            rest = &inner[(close + 1).min(inner.len())..];
+        } else if let Some(quote) = rest.chars().next().filter(|c| *c == '"' || *c == '\'') {
+            let inner = &rest[1..];
+            let close = inner.find(quote).unwrap_or(inner.len());
+            specifiers.push(inner[..close].to_string());
+            rest = &inner[(close + 1).min(inner.len())..];
+        } else {
+            let end = rest.find([',', ' ']).unwrap_or(rest.len());
+            if specifiers.is_empty() {
+                specifiers.push(rest[..end].to_string());
+            }
+            rest = &rest[end..];
+        }
+
+        rest = rest.trim_start();
+        match rest.strip_prefix(',') {
+            Some(next) => rest = next.trim_start(),
+            None => break,
+        }
+    }
+
+    specifiers
+}
+
+fn normalize_specifier(raw: &str, is_url: bool) -> Option<String> {
+    let specifier = raw.trim().trim_matches(|c| c == '"' || c == '\'').trim();
+
+    if specifier.is_empty()
+        || specifier.starts_with('#')
+        || specifier.starts_with('/')
+        || specifier.starts_with("data:")
+        || specifier.starts_with("sass:")
+        || specifier.contains("://")
+        || specifier.contains("#{")
+        || specifier.contains("@{")
+        || specifier.contains("$")
+    {
+        return None;
+    }
+
+    if let Some(package) = specifier.strip_prefix('~') {
+        return Some(package.trim_start_matches('/').to_string());
+    }
+
+    if is_url && !specifier.starts_with("./") && !specifier.starts_with("../") {
+        return Some(format!("./{}", specifier));
+    }
+
+    Some(specifier.to_string())
+}
+
+fn line_col(source: &str, offset: usize) -> (u32, u32) {
+    let before = &source[..offset];
+    let line = before.matches('\n').count() as u32 + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let col = source[line_start..offset].chars().count() as u32 + 1;
+    (line, col)
+}
+
+fn import(source: &str, offset: usize, specifier: String) -> Import {
+    let (line, col) = line_col(source, offset);
+
+    Import {
+        specifier,
+        imported_names: Vec::new(),
+        is_type_only: false,
+        is_side_effect: true,
+        line,
+        col,
+    }
+}
+
+fn strip_comments(source: &str) -> String {
+    let mut stripped = String::with_capacity(source.len());
+    let mut rest = source;
+
+    while let Some(start) = rest.find("/*") {
+        stripped.push_str(&rest[..start]);
+        let end = rest[start..].find("*/").map_or(rest.len(), |i| start + i + 2);
+        stripped.extend(rest[start..end].chars().map(|c| if c == '\n' { '\n' } else { ' ' }));
+        rest = &rest[end..];
+    }
+    stripped.push_str(rest);
+
+    stripped
+        .split_inclusive('\n')
+        .map(|line| {
+            if line.trim_start().starts_with("//") {
+                line.chars().map(|c| if c == '\n' { '\n' } else { ' ' }).collect()
+            } else {
+                line.to_string()
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn specifiers(source: &str) -> Vec<String> {
+        parse_stylesheet(source, Path::new("styles.scss"))
+            .imports
+            .into_iter()
+            .map(|i| i.specifier)
+            .collect()
+    }
+
+    #[test]
+    fn test_at_rules_and_urls() {
+        let source = r#"@use "sass:math";
+@use 'variables' as vars;
+@import url("./reset.css"), "theme";
+@forward "~bootstrap/scss/mixins";
+/* @import "commented"; */
+// @import "line-comment";
+.logo { background: url(images/logo.png) no-repeat; }
+@font-face { src: url('../fonts/inter.woff2') format('woff2'), url(data:font/woff2;base64,AAA); }
+.hero { background-image: url(https://example.com/a.png), url(/public.png), url(#mask); }
+"#;
+        assert_eq!(
+            specifiers(source),
+            vec![
+                "variables",
+                "./reset.css",
+                "theme",
+                "bootstrap/scss/mixins",
+                "./images/logo.png",
+                "../fonts/inter.woff2",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_import_positions() {
+        let module = parse_stylesheet("body {}\n  @import './a.css';\n", Path::new("a.css"));
+        assert_eq!((module.imports[0].line, module.imports[0].col), (2, 3));
+    }
+
+    #[test]
+    fn test_css_module_classes() {
+        let source = r#".button { color: red; margin: 0.5em; }
+.button:hover, a.link:not(.disabled) { background: url(./img.png); }
+:global(.theme-dark) .button { }
+[data-kind=".ignored"] { }
+.card {
+  &__title { composes: heading from './typography.module.css'; }
+  &.active { composes: button; }
+  @media (min-width: 1.5em) { .wide { } }
+}
+"#;
+        let module = parse_stylesheet(source, Path::new("Button.module.scss"));
+
+        let classes: Vec<(&str, u32)> = module
+            .exports
+            .iter()
+            .map(|e| (e.name.as_str(), e.line))
+            .collect();
+        assert_eq!(
+            classes,
+            vec![
+                ("button", 1),
+                ("link", 2),
+                ("disabled", 2),
+                ("card", 5),
+                ("card__title", 6),
+                ("active", 7),
+                ("wide", 8),
+            ]
+        );
+
+        let composes: Vec<(&str, &str)> = module
+            .imports
+            .iter()
+            .filter(|i| !i.imported_names.is_empty())
+            .map(|i| (i.specifier.as_str(), i.imported_names[0].name.as_str()))
+            .collect();
+        assert_eq!(
+            composes,
+            vec![
+                ("./typography.module.css", "heading"),
+                ("./Button.module.scss", "button"),
+            ]
+        );
+
+        assert!(parse_stylesheet(source, Path::new("global.scss"))
+            .exports
+            .is_empty());
+    }
+
+    #[test]
+    fn test_is_asset() {
+        assert!(is_asset(Path::new("logo.SVG")));
+        assert!(is_asset(Path::new("styles.scss")));
+        assert!(!is_asset(Path::new("index.ts")));
+    }
+}
@@ -27,6 +27,7 @@ pub enum ExportKind {
     Enum,
     Namespace,
     Default,
+    CssClass,
 }
 
 #[derive(Debug, Clone)]
@@ -60,51 +61,52 @@ pub fn extract_exports(module: &Module, cm: &SourceMap) -> (Vec<Export>, Vec<ReE
     let mut re_exports = Vec::new();
 
     for item in &module.body {
-        match item {
-            ModuleItem::ModuleDecl(decl) => match decl {
-                ModuleDecl::ExportDecl(export_decl) => {
-                    exports.extend(extract_export_decl(export_decl, cm));
-                }
-                ModuleDecl::ExportDefaultDecl(default_decl) => {
-                    exports.push(extract_default_decl(default_decl, cm));
-                }
-                ModuleDecl::ExportDefaultExpr(default_expr) => {
-                    exports.push(extract_default_expr(default_expr, cm));
-                }
-                ModuleDecl::ExportNamed(named) => {
-                    if named.src.is_some() {
-                        re_exports.push(extract_named_re_export(named, cm));
-                    } else {
-                        exports.extend(extract_named_export(named, cm));
-                    }
-                }
-                ModuleDecl::ExportAll(all) => {
-                    let (line, col) = get_line_col(cm, all.span);
-                    re_exports.push(ReExport {
-                        specifier: wtf8_to_string(&all.src.value),
-                        exported_names: vec![ReExportedName {
-                            name: "*".to_string(),
-                            alias: None,
-                            is_type: all.type_only,
-                        }],
-                        is_type_only: all.type_only,
-                        line,
-                        col,
-                    });
-                }
-                ModuleDecl::TsExportAssignment(assign) => {
-                    let (line, col) = get_line_col(cm, assign.span);
-                    exports.push(Export {
-                        name: "default".to_string(),
-                        kind: ExportKind::Default,
-                        is_type: false,
-                        is_default: true,
-                        line,
-                        col,
-                    });
+        let ModuleItem::ModuleDecl(decl) = item else {
+            continue;
+        };
+
+        match decl {
+            ModuleDecl::ExportDecl(export_decl) => {
+                exports.extend(extract_export_decl(export_decl, cm));
+            }
+            ModuleDecl::ExportDefaultDecl(default_decl) => {
+                exports.push(extract_default_decl(default_decl, cm));
+            }
+            ModuleDecl::ExportDefaultExpr(default_expr) => {
+                exports.push(extract_default_expr(default_expr, cm));
+            }
+            ModuleDecl::ExportNamed(named) => {
+                if named.src.is_some() {
+                    re_exports.push(extract_named_re_export(named, cm));
+                } else {
+                    exports.extend(extract_named_export(named, cm));
                 }
-                _ => {}
-            },
+            }
+            ModuleDecl::ExportAll(all) => {
+                let (line, col) = get_line_col(cm, all.span);
+                re_exports.push(ReExport {
+                    specifier: wtf8_to_string(&all.src.value),
+                    exported_names: vec![ReExportedName {
+                        name: "*".to_string(),
+                        alias: None,
+                        is_type: all.type_only,
+                    }],
+                    is_type_only: all.type_only,
+                    line,
+                    col,
+                });
+            }
+            ModuleDecl::TsExportAssignment(assign) => {
+                let (line, col) = get_line_col(cm, assign.span);
+                exports.push(Export {
+                    name: "default".to_string(),
+                    kind: ExportKind::Default,
+                    is_type: false,
+                    is_default: true,
+                    line,
+                    col,
+                });
+            }
             _ => {}
         }
     }
@@ -0,0 +1,413 @@
+use std::path::{Component, Path, PathBuf};
+
+use globset::{Glob, GlobBuilder, GlobSet, GlobSetBuilder};
+use regex::Regex;
+use swc_common::SourceMap;
+use swc_ecma_ast::{
+    CallExpr, Callee, Expr, Lit, MemberProp, MetaPropKind, Module, ObjectLit, Prop, PropName,
+    PropOrSpread, Str, Tpl,
+};
+
+use super::imports::ImportedName;
+use super::typescript::get_line_col;
+use super::walk::{walk_module_items, Visitor};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GlobKind {
+    Patterns(Vec<String>),
+    Context {
+        directory: String,
+        recursive: bool,
+        filter: Option<String>,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct GlobImport {
+    pub kind: GlobKind,
+    pub imported_names: Vec<ImportedName>,
+    pub eager: bool,
+    pub line: u32,
+    pub col: u32,
+}
+
+pub enum GlobMatcher {
+    Patterns {
+        include: GlobSet,
+        exclude: GlobSet,
+    },
+    Context {
+        directory: PathBuf,
+        recursive: bool,
+        filter: Option<Regex>,
+    },
+}
+
+impl GlobImport {
+    pub fn matcher(&self, from: &Path, root: &Path) -> GlobMatcher {
+        let base = from.parent().unwrap_or(root);
+        match &self.kind {
+            GlobKind::Patterns(patterns) => {
+                let mut include = GlobSetBuilder::new();
+                let mut exclude = GlobSetBuilder::new();
+                for pattern in patterns {
+                    let (builder, pattern) = match pattern.strip_prefix('!') {
+                        Some(pattern) => (&mut exclude, pattern),
+                        None => (&mut include, pattern.as_str()),
+                    };
+                    if let Some(glob) = absolute_glob(pattern, base, root) {
+                        builder.add(glob);
+                    }
+                }
+                GlobMatcher::Patterns {
+                    include: include.build().unwrap_or_else(|_| GlobSet::empty()),
+                    exclude: exclude.build().unwrap_or_else(|_| GlobSet::empty()),
+                }
+            }
+            GlobKind::Context {
+                directory,
+                recursive,
+                filter,
+            } => GlobMatcher::Context {
+                directory: normalize(&base.join(directory)),
+                recursive: *recursive,
+                filter: filter.as_deref().and_then(|filter| Regex::new(filter).ok()),
+            },
+        }
+    }
+}
+
+impl GlobMatcher {
+    pub fn is_match(&self, candidate: &Path) -> bool {
+        match self {
+            GlobMatcher::Patterns { include, exclude } => {
+                include.is_match(candidate) && !exclude.is_match(candidate)
+            }
+            GlobMatcher::Context {
+                directory,
+                recursive,
+                filter,
+            } => {
+                let Ok(relative) = candidate.strip_prefix(directory) else {
+                    return false;
+                };
+                if !recursive && relative.components().count() != 1 {
+                    return false;
+                }
+                let relative = format!("./{}", relative.to_string_lossy().replace('\\', "/"));
+                filter
+                    .as_ref()
+                    .is_none_or(|filter| filter.is_match(&relative))
+            }
+        }
+    }
+}
+
+fn absolute_glob(pattern: &str, base: &Path, root: &Path) -> Option<Glob> {
+    let (mut dir, mut rest) = if pattern.starts_with("./") || pattern.starts_with("../") {
+        (base.to_path_buf(), pattern)
+    } else if let Some(rooted) = pattern.strip_prefix('/') {
+        (root.to_path_buf(), rooted)
+    } else {
+        return None;
+    };
+
+    loop {
+        if let Some(stripped) = rest.strip_prefix("./") {
+            rest = stripped;
+        } else if let Some(stripped) = rest.strip_prefix("../") {
+            dir = normalize(&dir.join(".."));
+            rest = stripped;
+        } else {
+            break;
+        }
+    }
+
+    // Folders like `[id]` are matched literally.
+    let dir = globset::escape(&dir.to_string_lossy());
+    let absolute = format!("{}/{}", dir.trim_end_matches('/'), rest);
+
+    GlobBuilder::new(&absolute)
+        .literal_separator(true)
+        .build()
+        .ok()
+}
+
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+struct GlobCollector<'a> {
+    cm: &'a SourceMap,
+    globs: Vec<GlobImport>,
+}
+
+pub fn extract_glob_imports(module: &Module, cm: &SourceMap) -> Vec<GlobImport> {
+    let mut collector = GlobCollector {
+        cm,
+        globs: Vec::new(),
+    };
+    walk_module_items(&module.body, &mut collector);
+    collector.globs
+}
+
+impl Visitor for GlobCollector<'_> {
+    fn enter_expr(&mut self, expr: &Expr) -> bool {
+        let Expr::Call(call) = expr else {
+            return true;
+        };
+
+        let glob = match &call.callee {
+            Callee::Import(_) => template_import(call),
+            Callee::Expr(callee) => match &**callee {
+                Expr::Member(member) => match (&*member.obj, &member.prop) {
+                    (Expr::MetaProp(meta), MemberProp::Ident(prop))
+                        if meta.kind == MetaPropKind::ImportMeta
+                            && (prop.sym == "glob" || prop.sym == "globEager") =>
+                    {
+                        meta_glob(call, prop.sym == "globEager")
+                    }
+                    (Expr::Ident(object), MemberProp::Ident(prop))
+                        if object.sym == "require" && prop.sym == "context" =>
+                    {
+                        require_context(call)
+                    }
+                    _ => None,
+                },
+                _ => None,
+            },
+            Callee::Super(_) => None,
+        };
+
+        if let Some((kind, imported_names, eager)) = glob {
+            let (line, col) = get_line_col(self.cm, call.span);
+            self.globs.push(GlobImport {
+                kind,
+                imported_names,
+                eager,
+                line,
+                col,
+            });
+        }
+        true
+    }
+}
+
+type Extracted = (GlobKind, Vec<ImportedName>, bool);
+
+fn meta_glob(call: &CallExpr, eager: bool) -> Option<Extracted> {
+    let patterns = match call.args.first().map(|arg| &*arg.expr)? {
+        Expr::Lit(Lit::Str(s)) => vec![string(s)],
+        Expr::Array(array) => array
+            .elems
+            .iter()
+            .flatten()
+            .filter_map(|elem| match &*elem.expr {
+                Expr::Lit(Lit::Str(s)) => Some(string(s)),
+                _ => None,
+            })
+            .collect(),
+        _ => return None,
+    };
+
+    let mut eager = eager;
+    let mut imported_names = vec![imported_name("*")];
+    if let Some(Expr::Object(options)) = call.args.get(1).map(|arg| &*arg.expr) {
+        if let Some(Expr::Lit(Lit::Bool(b))) = option(options, "eager") {
+            eager = b.value;
+        }
+        if let Some(Expr::Lit(Lit::Str(s))) = option(options, "import") {
+            imported_names = vec![imported_name(&string(s))];
+        }
+        let loads_source = |value: Option<&Expr>| match value {
+            Some(Expr::Lit(Lit::Str(s))) => {
+                matches!(string(s).trim_start_matches('?'), "raw" | "url")
+            }
+            _ => false,
+        };
+        if loads_source(option(options, "query")) || loads_source(option(options, "as")) {
+            imported_names.clear();
+        }
+    }
+
+    Some((GlobKind::Patterns(patterns), imported_names, eager))
+}
+
+fn require_context(call: &CallExpr) -> Option<Extracted> {
+    let mut args = call.args.iter().map(|arg| &*arg.expr);
+    let directory = match args.next()? {
+        Expr::Lit(Lit::Str(s)) => string(s),
+        _ => return None,
+    };
+    let recursive = !matches!(args.next(), Some(Expr::Lit(Lit::Bool(b))) if !b.value);
+    let filter = match args.next() {
+        Some(Expr::Lit(Lit::Regex(regex))) => Some(if regex.flags.contains('i') {
+            format!("(?i){}", regex.exp)
+        } else {
+            regex.exp.to_string()
+        }),
+        _ => None,
+    };
+
+    Some((
+        GlobKind::Context {
+            directory,
+            recursive,
+            filter,
+        },
+        vec![imported_name("*")],
+        false,
+    ))
+}
+
+fn template_import(call: &CallExpr) -> Option<Extracted> {
+    let Expr::Tpl(tpl) = call.args.first().map(|arg| &*arg.expr)? else {
+        return None;
+    };
+    let pattern = template_pattern(tpl)?;
+
+    let mut patterns = vec![pattern.clone()];
+    let last = pattern.rsplit('/').next().unwrap_or_default();
+    if !last.contains('.') && !last.ends_with('*') {
+        patterns.push(format!("{}.*", pattern));
+    }
+
+    Some((
+        GlobKind::Patterns(patterns),
+        vec![imported_name("*")],
+        false,
+    ))
+}
+
+fn template_pattern(tpl: &Tpl) -> Option<String> {
+    let mut pattern = String::new();
+    for (i, quasi) in tpl.quasis.iter().enumerate() {
+        if i > 0 && !pattern.ends_with('*') {
+            pattern.push('*');
+        }
+        pattern.push_str(&globset::escape(&quasi.raw));
+    }
+
+    let relative = pattern.starts_with("./") || pattern.starts_with("../");
+    (relative && !tpl.exprs.is_empty()).then_some(pattern)
+}
+
+fn option<'a>(options: &'a ObjectLit, key: &str) -> Option<&'a Expr> {
+    options.props.iter().find_map(|prop| match prop {
+        PropOrSpread::Prop(prop) => match &**prop {
+            Prop::KeyValue(kv) => match &kv.key {
+                PropName::Ident(ident) if ident.sym == key => Some(&*kv.value),
+                PropName::Str(s) if string(s) == key => Some(&*kv.value),
+                _ => None,
+            },
+            _ => None,
+        },
+        PropOrSpread::Spread(_) => None,
+    })
+}
+
+fn string(s: &Str) -> String {
+    s.value.as_str().unwrap_or_default().to_string()
+}
+
+fn imported_name(name: &str) -> ImportedName {
+    ImportedName {
+        name: name.to_string(),
+        alias: None,
+        is_type: false,
+        members: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_source;
+    use std::path::PathBuf;
+
+    fn globs(source: &str) -> Vec<GlobImport> {
+        parse_source(source, Path::new("/app/src/main.ts"))
+            .unwrap()
+            .glob_imports
+    }
+
+    #[test]
+    fn test_extract_glob_imports() {
+        let source = r#"
+const pages = import.meta.glob(['./pages/**/*.tsx', '!./pages/**/*.test.tsx'], { eager: true, import: 'default' });
+const raw = import.meta.glob('./docs/*.md', { query: '?raw' });
+const icons = require.context('./icons', false, /\.svg$/i);
+export const load = (lang: string) => import(`./locales/${lang}.json`);
+"#;
+        let globs = globs(source);
+        assert_eq!(globs.len(), 4);
+
+        assert_eq!(
+            globs[0].kind,
+            GlobKind::Patterns(vec![
+                "./pages/**/*.tsx".to_string(),
+                "!./pages/**/*.test.tsx".to_string()
+            ])
+        );
+        assert!(globs[0].eager);
+        assert_eq!(globs[0].imported_names[0].name, "default");
+        assert!(globs[1].imported_names.is_empty());
+        assert_eq!(
+            globs[2].kind,
+            GlobKind::Context {
+                directory: "./icons".to_string(),
+                recursive: false,
+                filter: Some(r"(?i)\.svg$".to_string()),
+            }
+        );
+        assert_eq!(
+            globs[3].kind,
+            GlobKind::Patterns(vec!["./locales/*.json".to_string()])
+        );
+        assert!(!globs[3].eager);
+    }
+
+    #[test]
+    fn test_glob_matches() {
+        let root = Path::new("/app");
+        let from = Path::new("/app/src/main.ts");
+        let globs = globs(
+            r#"
+import.meta.glob(['./pages/**/*.tsx', '!./pages/**/*.test.tsx']);
+require.context('../assets', true, /\.svg$/);
+import(`./locales/${lang}`);
+import.meta.glob('/content/*.md');
+"#,
+        );
+        let matches =
+            |glob: &GlobImport, path: &str| glob.matcher(from, root).is_match(&PathBuf::from(path));
+
+        assert!(matches(&globs[0], "/app/src/pages/blog/post.tsx"));
+        assert!(!matches(&globs[0], "/app/src/pages/blog/post.test.tsx"));
+        assert!(!matches(&globs[0], "/app/src/other/post.tsx"));
+        assert!(matches(&globs[1], "/app/assets/icons/logo.svg"));
+        assert!(!matches(&globs[1], "/app/assets/logo.png"));
+        assert!(matches(&globs[2], "/app/src/locales/en.ts"));
+        assert!(!matches(&globs[2], "/app/src/locales/en/extra.ts"));
+        assert!(matches(&globs[3], "/app/content/intro.md"));    }
+
+    #[test]
+    fn test_glob_base_dir_is_literal() {
+        let root = Path::new("/app");
+        let from = Path::new("/app/src/[slug]/(group)/page.ts");
+        let glob = &globs("import.meta.glob('../widgets/*.ts');")[0];
+        let matcher = glob.matcher(from, root);
+        assert!(matcher.is_match(Path::new("/app/src/[slug]/widgets/chart.ts")));
+        assert!(!matcher.is_match(Path::new("/app/src/s/widgets/chart.ts")));
+    }
+}
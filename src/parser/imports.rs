@@ -1,9 +1,13 @@
-use swc_common::SourceMap;
+use swc_common::comments::{CommentKind, SingleThreadedComments};
+use swc_common::{SourceMap, Spanned};
 use swc_ecma_ast::{
-    Callee, Expr, ImportDecl, ImportSpecifier, Module, ModuleDecl, ModuleItem,
+    CallExpr, Callee, Expr, ImportDecl, ImportSpecifier, Lit, MemberProp, Module, ModuleDecl,
+    ModuleItem, ObjectPatProp, Pat, PropName, TsEntityName, TsImportEqualsDecl, TsImportType,
+    TsModuleRef, VarDeclarator,
 };
 
 use super::typescript::get_line_col;
+use super::walk::{walk_expr, walk_member_prop, walk_module_items, walk_pat, Visitor};
 
 #[derive(Debug, Clone)]
 pub struct Import {
@@ -20,6 +24,14 @@ pub struct ImportedName {
     pub name: String,
     pub alias: Option<String>,
     pub is_type: bool,
+    pub members: Option<Vec<MemberRef>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemberRef {
+    pub name: String,
+    pub line: u32,
+    pub col: u32,
 }
 
 pub fn extract_imports(module: &Module, cm: &SourceMap) -> Vec<Import> {
@@ -30,16 +42,84 @@ pub fn extract_imports(module: &Module, cm: &SourceMap) -> Vec<Import> {
             ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)) => {
                 imports.push(extract_import_decl(import_decl, cm));
             }
-            ModuleItem::Stmt(stmt) => {
-                extract_dynamic_imports(stmt, cm, &mut imports);
+            ModuleItem::ModuleDecl(ModuleDecl::TsImportEquals(decl)) => {
+                if let Some(import) = extract_import_equals(decl, cm) {
+                    imports.push(import);
+                }
+            }
+            item => {
+                let mut collector = DynamicImportCollector {
+                    cm,
+                    imports: &mut imports,
+                };
+                walk_module_items(std::slice::from_ref(item), &mut collector);
             }
-            _ => {}
         }
     }
 
     imports
 }
 
+pub fn extract_reference_directives(
+    module: &Module,
+    comments: &SingleThreadedComments,
+    cm: &SourceMap,
+) -> Vec<Import> {
+    let header_end = module.body.first().map(|item| item.span().lo);
+    let (leading, trailing) = comments.borrow_all();
+
+    let mut directives: Vec<_> = leading
+        .values()
+        .chain(trailing.values())
+        .flatten()
+        .filter(|comment| {
+            comment.kind == CommentKind::Line && header_end.is_none_or(|end| comment.span.hi <= end)
+        })
+        .filter_map(|comment| Some((comment.span, reference_directive(&comment.text)?)))
+        .collect();
+    directives.sort_by_key(|(span, _)| span.lo);
+
+    directives
+        .into_iter()
+        .map(|(span, (kind, value))| {
+            let (line, col) = get_line_col(cm, span);
+            let relative =
+                value.starts_with("./") || value.starts_with("../") || value.starts_with('/');
+            let specifier = if kind == "path" && !relative {
+                format!("./{}", value)
+            } else {
+                value.to_string()
+            };
+            Import {
+                specifier,
+                imported_names: Vec::new(),
+                is_type_only: true,
+                is_side_effect: true,
+                line,
+                col,
+            }
+        })
+        .collect()
+}
+
+fn reference_directive(text: &str) -> Option<(&str, &str)> {
+    let mut rest = text
+        .strip_prefix('/')?
+        .trim_start()
+        .strip_prefix("<reference")?;
+
+    loop {
+        let (name, value) = rest.split_once('=')?;
+        let value = value.trim_start();
+        let quote = value.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+        let (value, tail) = value[1..].split_once(quote)?;
+        match name.trim() {
+            kind @ ("path" | "types") => return Some((kind, value)),
+            _ => rest = tail,
+        }
+    }
+}
+
 fn atom_to_string(atom: &swc_atoms::Atom) -> String {
     format!("{}", atom)
 }
@@ -79,6 +159,7 @@ fn extract_import_decl(decl: &ImportDecl, cm: &SourceMap) -> Import {
                     name,
                     alias,
                     is_type: named.is_type_only || is_type_only,
+                    members: None,
                 });
             }
             ImportSpecifier::Default(default) => {
@@ -86,6 +167,7 @@ fn extract_import_decl(decl: &ImportDecl, cm: &SourceMap) -> Import {
                     name: "default".to_string(),
                     alias: Some(atom_to_string(&default.local.sym)),
                     is_type: is_type_only,
+                    members: None,
                 });
             }
             ImportSpecifier::Namespace(ns) => {
@@ -93,6 +175,7 @@ fn extract_import_decl(decl: &ImportDecl, cm: &SourceMap) -> Import {
                     name: "*".to_string(),
                     alias: Some(atom_to_string(&ns.local.sym)),
                     is_type: is_type_only,
+                    members: None,
                 });
             }
         }
@@ -108,81 +191,187 @@ fn extract_import_decl(decl: &ImportDecl, cm: &SourceMap) -> Import {
     }
 }
 
-fn extract_dynamic_imports(stmt: &swc_ecma_ast::Stmt, cm: &SourceMap, imports: &mut Vec<Import>) {
-    use swc_ecma_ast::Stmt;
+fn extract_import_equals(decl: &TsImportEqualsDecl, cm: &SourceMap) -> Option<Import> {
+    let TsModuleRef::TsExternalModuleRef(module_ref) = &decl.module_ref else {
+        return None;
+    };
+    let (line, col) = get_line_col(cm, decl.span);
+
+    Some(Import {
+        specifier: wtf8_to_string(&module_ref.expr.value),
+        imported_names: vec![ImportedName {
+            name: "*".to_string(),
+            alias: Some(atom_to_string(&decl.id.sym)),
+            is_type: decl.is_type_only,
+            members: None,
+        }],
+        is_type_only: decl.is_type_only,
+        is_side_effect: false,
+        line,
+        col,
+    })
+}
+
+struct DynamicImportCollector<'a> {
+    cm: &'a SourceMap,
+    imports: &'a mut Vec<Import>,
+}
+
+impl DynamicImportCollector<'_> {
+    fn push(&mut self, call: &CallExpr, imported_names: Vec<ImportedName>) {
+        let Some(Expr::Lit(Lit::Str(s))) = call.args.first().map(|arg| &*arg.expr) else {
+            return;
+        };
+        let (line, col) = get_line_col(self.cm, call.span);
+        self.imports.push(Import {
+            specifier: wtf8_to_string(&s.value),
+            imported_names,
+            is_type_only: false,
+            is_side_effect: false,
+            line,
+            col,
+        });
+    }
+}
 
-    match stmt {
-        Stmt::Expr(expr_stmt) => {
-            extract_dynamic_import_from_expr(&expr_stmt.expr, cm, imports);
+impl Visitor for DynamicImportCollector<'_> {
+    fn enter_expr(&mut self, expr: &Expr) -> bool {
+        if let Some(call) = dynamic_import(expr) {
+            self.push(call, vec![imported_name("*", None)]);
+            return false;
         }
-        Stmt::Decl(decl) => {
-            if let swc_ecma_ast::Decl::Var(var_decl) = decl {
-                for decl in &var_decl.decls {
-                    if let Some(init) = &decl.init {
-                        extract_dynamic_import_from_expr(init, cm, imports);
+
+        match expr {
+            Expr::Member(member) => {
+                let Some(call) = dynamic_import(&member.obj) else {
+                    return true;
+                };
+                let name = match &member.prop {
+                    MemberProp::Ident(ident) => atom_to_string(&ident.sym),
+                    MemberProp::Computed(computed) => match &*computed.expr {
+                        Expr::Lit(Lit::Str(s)) => wtf8_to_string(&s.value),
+                        _ => "*".to_string(),
+                    },
+                    MemberProp::PrivateName(_) => "*".to_string(),
+                };
+                self.push(call, vec![imported_name(&name, None)]);
+                walk_member_prop(&member.prop, self);
+                false
+            }
+            Expr::Call(call) => {
+                let Callee::Expr(callee) = &call.callee else {
+                    return true;
+                };
+                let Expr::Member(member) = &**callee else {
+                    return true;
+                };
+                let Some(import) = dynamic_import(&member.obj) else {
+                    return true;
+                };
+
+                let is_then = matches!(&member.prop, MemberProp::Ident(prop) if prop.sym == "then");
+                let imported_names = match call.args.first().map(|arg| &*arg.expr) {
+                    Some(Expr::Arrow(arrow)) if is_then => {
+                        arrow.params.first().map(pattern_names).unwrap_or_default()
                     }
+                    Some(Expr::Fn(func)) if is_then => func
+                        .function
+                        .params
+                        .first()
+                        .map(|param| pattern_names(&param.pat))
+                        .unwrap_or_default(),
+                    _ => vec![imported_name("*", None)],
+                };
+                self.push(import, imported_names);
+
+                for arg in &call.args {
+                    walk_expr(&arg.expr, self);
                 }
+                false
             }
+            _ => true,
         }
-        Stmt::Block(block) => {
-            for stmt in &block.stmts {
-                extract_dynamic_imports(stmt, cm, imports);
-            }
-        }
-        Stmt::If(if_stmt) => {
-            extract_dynamic_imports(&if_stmt.cons, cm, imports);
-            if let Some(alt) = &if_stmt.alt {
-                extract_dynamic_imports(alt, cm, imports);
-            }
-        }
-        Stmt::Return(ret) => {
-            if let Some(arg) = &ret.arg {
-                extract_dynamic_import_from_expr(arg, cm, imports);
-            }
-        }
-        _ => {}
+    }
+
+    fn visit_import_type(&mut self, import: &TsImportType) {
+        let name = match &import.qualifier {
+            Some(qualifier) => atom_to_string(&leftmost(qualifier).sym),
+            None => "*".to_string(),
+        };
+        let (line, col) = get_line_col(self.cm, import.span);
+        self.imports.push(Import {
+            specifier: wtf8_to_string(&import.arg.value),
+            imported_names: vec![ImportedName {
+                name,
+                alias: None,
+                is_type: true,
+                members: None,
+            }],
+            is_type_only: true,
+            is_side_effect: false,
+            line,
+            col,
+        });
+    }
+
+    fn enter_var_declarator(&mut self, decl: &VarDeclarator) -> bool {
+        let Some(call) = decl.init.as_deref().and_then(dynamic_import) else {
+            return true;
+        };
+        self.push(call, pattern_names(&decl.name));
+        walk_pat(&decl.name, self);
+        false
     }
 }
 
-fn extract_dynamic_import_from_expr(expr: &Expr, cm: &SourceMap, imports: &mut Vec<Import>) {
+fn dynamic_import(expr: &Expr) -> Option<&CallExpr> {
     match expr {
-        Expr::Call(call) => {
-            if let Callee::Import(_) = &call.callee {
-                if let Some(arg) = call.args.first() {
-                    if let Expr::Lit(swc_ecma_ast::Lit::Str(s)) = &*arg.expr {
-                        let (line, col) = get_line_col(cm, call.span);
-                        imports.push(Import {
-                            specifier: wtf8_to_string(&s.value),
-                            imported_names: vec![ImportedName {
-                                name: "*".to_string(),
-                                alias: None,
-                                is_type: false,
-                            }],
-                            is_type_only: false,
-                            is_side_effect: false,
-                            line,
-                            col,
-                        });
+        Expr::Await(await_expr) => dynamic_import(&await_expr.arg),
+        Expr::Paren(paren) => dynamic_import(&paren.expr),
+        Expr::Call(call) if matches!(call.callee, Callee::Import(_)) => Some(call),
+        _ => None,
+    }
+}
+
+fn leftmost(name: &TsEntityName) -> &swc_ecma_ast::Ident {
+    match name {
+        TsEntityName::Ident(ident) => ident,
+        TsEntityName::TsQualifiedName(qualified) => leftmost(&qualified.left),
+    }
+}
+
+fn pattern_names(pat: &Pat) -> Vec<ImportedName> {
+    match pat {
+        Pat::Ident(ident) => vec![imported_name("*", Some(atom_to_string(&ident.id.sym)))],
+        Pat::Object(object) => object
+            .props
+            .iter()
+            .map(|prop| match prop {
+                ObjectPatProp::KeyValue(kv) => {
+                    let alias = match &*kv.value {
+                        Pat::Ident(ident) => Some(atom_to_string(&ident.id.sym)),
+                        _ => None,
+                    };
+                    match &kv.key {
+                        PropName::Ident(ident) => imported_name(&ident.sym, alias),
+                        PropName::Str(s) => imported_name(&wtf8_to_string(&s.value), alias),
+                        _ => imported_name("*", None),
                     }
                 }
-            } else {
-                for arg in &call.args {
-                    extract_dynamic_import_from_expr(&arg.expr, cm, imports);
-                }
-            }
-        }
-        Expr::Arrow(arrow) => {
-            if let swc_ecma_ast::BlockStmtOrExpr::Expr(e) = &*arrow.body {
-                extract_dynamic_import_from_expr(e, cm, imports);
-            }
-        }
-        Expr::Await(await_expr) => {
-            extract_dynamic_import_from_expr(&await_expr.arg, cm, imports);
-        }
-        Expr::Paren(paren) => {
-            extract_dynamic_import_from_expr(&paren.expr, cm, imports);
-        }
-        _ => {}
+                ObjectPatProp::Assign(assign) => imported_name(&assign.key.sym, None),
+                ObjectPatProp::Rest(_) => imported_name("*", None),
+            })
+            .collect(),
+        _ => vec![imported_name("*", None)],
+    }
+}
+
+fn imported_name(name: &str, alias: Option<String>) -> ImportedName {
+    ImportedName {
+        name: name.to_string(),
+        alias,
+        is_type: false,
+        members: None,
     }
 }
 
@@ -260,4 +449,126 @@ mod tests {
         assert_eq!(result.imports.len(), 1);
         assert_eq!(result.imports[0].specifier, "./dynamic");
     }
+
+    #[test]
+    fn test_nested_dynamic_imports() {
+        let source = r#"
+class Loader {
+    async load(kind) {
+        try {
+            for (const key of keys) {
+                const mod = kind ? await import('./a') : await import('./b');
+            }
+        } catch {
+            return { fallback: () => import('./c') };
+        }
+    }
+}
+export const lazy = () => import('./d');
+"#;
+        let result = parse_source(source, &PathBuf::from("test.ts")).unwrap();
+        let specifiers: Vec<&str> = result
+            .imports
+            .iter()
+            .map(|i| i.specifier.as_str())
+            .collect();
+        assert_eq!(specifiers, vec!["./a", "./b", "./c", "./d"]);
+    }
+
+    #[test]
+    fn test_dynamic_import_narrowing() {
+        let source = r#"
+async function run() {
+    const { parse, format: fmt } = await import('./destructured');
+    const { version } = (await import('./member')).default;
+    import('./then').then(({ render }) => render());
+    import('./callback').then((m) => m.start(m.config));
+    const all = await import('./escaped');
+    console.log(all);
+}
+"#;
+        let result = parse_source(source, &PathBuf::from("test.ts")).unwrap();
+        let names = |specifier: &str| -> Vec<(String, Option<Vec<String>>)> {
+            result
+                .imports
+                .iter()
+                .find(|i| i.specifier == specifier)
+                .unwrap()
+                .imported_names
+                .iter()
+                .map(|n| {
+                    let members = n
+                        .members
+                        .as_ref()
+                        .map(|m| m.iter().map(|m| m.name.clone()).collect());
+                    (n.name.clone(), members)
+                })
+                .collect()
+        };
+
+        assert_eq!(
+            names("./destructured"),
+            vec![("parse".to_string(), None), ("format".to_string(), None)]
+        );
+        assert_eq!(names("./member"), vec![("default".to_string(), None)]);
+        assert_eq!(names("./then"), vec![("render".to_string(), None)]);
+        assert_eq!(
+            names("./callback"),
+            vec![(
+                "*".to_string(),
+                Some(vec!["start".to_string(), "config".to_string()])
+            )]
+        );
+        assert_eq!(names("./escaped"), vec![("*".to_string(), None)]);
+    }
+
+    #[test]
+    fn test_typescript_import_forms() {
+        let source = r#"/// <reference path="globals.d.ts" />
+/// <reference types="vite/client" />
+/// <reference lib="dom" />
+import fs = require('fs');
+import type Config = require('./config');
+type Loader = typeof import('./loader');
+export function read(options: import('./options').Options.Read): Loader {
+    return fs.readFileSync(options.path);
+}
+// <reference path="./ignored.d.ts" />
+"#;
+        let result = parse_source(source, &PathBuf::from("test.ts")).unwrap();
+        let imports: Vec<(&str, Vec<&str>, bool, bool)> = result
+            .imports
+            .iter()
+            .map(|i| {
+                let names = i.imported_names.iter().map(|n| n.name.as_str()).collect();
+                (
+                    i.specifier.as_str(),
+                    names,
+                    i.is_type_only,
+                    i.is_side_effect,
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            imports,
+            vec![
+                ("./globals.d.ts", vec![], true, true),
+                ("vite/client", vec![], true, true),
+                ("fs", vec!["*"], false, false),
+                ("./config", vec!["*"], true, false),
+                ("./loader", vec!["*"], true, false),
+                ("./options", vec!["Options"], true, false),
+            ]
+        );
+        assert_eq!((result.imports[0].line, result.imports[1].line), (1, 2));
+
+        let members: Vec<&str> = result.imports[2].imported_names[0]
+            .members
+            .iter()
+            .flatten()
+            .map(|m| m.name.as_str())
+            .collect();
+        assert_eq!(members, vec!["readFileSync"]);
+    }
 }
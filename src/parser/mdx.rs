@@ -0,0 +1,217 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use super::imports::Import;
+
+pub const MARKDOWN_EXTENSIONS: &[&str] = &["mdx", "md"];
+
+#[derive(Debug)]
+pub struct MdxSource {
+    pub esm: String,
+    pub references: HashSet<String>,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Statement {
+    Import,
+    Export,
+}
+
+pub fn is_markdown(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|ext| MARKDOWN_EXTENSIONS.contains(&ext))
+}
+
+pub fn extract_mdx(content: &str) -> MdxSource {
+    let mut esm = String::with_capacity(content.len());
+    let mut body = String::new();
+    let mut references = HashSet::new();
+
+    let mut fence: Option<&str> = None;
+    let mut statement: Option<Statement> = None;
+
+    for line in content.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+
+        if let Some(marker) = fence {
+            if trimmed.starts_with(marker) {
+                fence = None;
+            }
+            esm.push_str(&blank(line));
+            continue;
+        }
+
+        if statement.is_some() && line.trim().is_empty() {
+            statement = None;
+        }
+
+        if statement.is_none() && (trimmed.starts_with("```") || trimmed.starts_with("~~~")) {
+            fence = Some(&trimmed[..3]);
+            esm.push_str(&blank(line));
+            continue;
+        }
+
+        if starts_statement(line, "import") {
+            statement = Some(Statement::Import);
+        } else if starts_statement(line, "export") {
+            statement = Some(Statement::Export);
+        }
+
+        match statement {
+            Some(kind) => {
+                esm.push_str(line);
+                if kind == Statement::Export {
+                    references.extend(identifiers(line));
+                }
+            }
+            None => {
+                esm.push_str(&blank(line));
+                body.push_str(line);
+            }
+        }
+    }
+
+    collect_body_references(&strip_inline_code(&body), &mut references);
+
+    MdxSource { esm, references }
+}
+
+pub fn retain_referenced(imports: &mut [Import], references: &HashSet<String>) {
+    for import in imports {
+        import.imported_names.retain(|imported| {
+            let local = imported.alias.as_ref().unwrap_or(&imported.name);
+            references.contains(local)
+        });
+    }
+}
+
+fn starts_statement(line: &str, keyword: &str) -> bool {
+    line.strip_prefix(keyword)
+        .is_some_and(|rest| rest.starts_with(|c: char| c.is_whitespace() || c == '{' || c == '*'))
+}
+
+fn blank(line: &str) -> String {
+    line.chars()
+        .map(|c| if c == '\n' || c == '\r' { c } else { ' ' })
+        .collect()
+}
+
+fn strip_inline_code(body: &str) -> String {
+    let mut stripped = String::with_capacity(body.len());
+    let mut in_code = false;
+    for c in body.chars() {
+        if c == '`' {
+            in_code = !in_code;
+        } else if !in_code {
+            stripped.push(c);
+        }
+    }
+    stripped
+}
+
+fn collect_body_references(body: &str, references: &mut HashSet<String>) {
+    let chars: Vec<char> = body.chars().collect();
+    let mut depth = 0usize;
+    let mut expression = String::new();
+
+    for (i, &c) in chars.iter().enumerate() {
+        match c {
+            '{' => {
+                depth += 1;
+                if depth > 1 {
+                    expression.push(c);
+                }
+            }
+            '}' if depth > 0 => {
+                depth -= 1;
+                if depth == 0 {
+                    references.extend(identifiers(&expression));
+                    expression.clear();
+                } else {
+                    expression.push(c);
+                }
+            }
+            _ if depth > 0 => expression.push(c),
+            '<' => {
+                let name: String = chars[i + 1..]
+                    .iter()
+                    .take_while(|c| is_ident_char(**c))
+                    .collect();
+                if name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_' || c == '$') {
+                    references.insert(name);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || c == '$'
+}
+
+fn identifiers(text: &str) -> Vec<String> {
+    text.split(|c: char| !is_ident_char(c))
+        .filter(|word| word.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_' || c == '$'))
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::ImportedName;
+
+    #[test]
+    fn test_extract_esm_and_references() {
+        let content = "import { Button, Unused } from './Button';\nimport * as Icons from './icons';\n\n# Title\n\n<Button kind={Icons.Star} />\n\n```js\nimport { Fake } from './fake';\n<Fake />\n```\n\nText about `<Unused />`.\n\nexport const meta = { theme };\n";
+        let mdx = extract_mdx(content);
+
+        assert!(mdx
+            .esm
+            .contains("import { Button, Unused } from './Button';"));
+        assert!(mdx.esm.contains("export const meta = { theme };"));
+        assert!(!mdx.esm.contains("Fake"));
+        assert!(!mdx.esm.contains("# Title"));
+        assert_eq!(mdx.esm.len(), content.len());
+
+        for name in ["Button", "Icons", "theme"] {
+            assert!(mdx.references.contains(name), "missing {}", name);
+        }
+        assert!(!mdx.references.contains("Unused"));
+        assert!(!mdx.references.contains("Fake"));
+    }
+
+    #[test]
+    fn test_retain_referenced() {
+        let name = |name: &str, alias: Option<&str>| ImportedName {
+            name: name.to_string(),
+            alias: alias.map(str::to_string),
+            is_type: false,
+            members: None,
+        };
+        let mut imports = vec![Import {
+            specifier: "./Button".to_string(),
+            imported_names: vec![
+                name("Button", None),
+                name("default", Some("Card")),
+                name("Unused", None),
+            ],
+            is_type_only: false,
+            is_side_effect: false,
+            line: 1,
+            col: 1,
+        }];
+
+        let references = HashSet::from(["Button".to_string(), "Card".to_string()]);
+        retain_referenced(&mut imports, &references);
+
+        let names: Vec<_> = imports[0]
+            .imported_names
+            .iter()
+            .map(|n| n.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["Button", "default"]);
+    }
+}
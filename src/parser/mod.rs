@@ -1,7 +1,14 @@
 mod typescript;
 mod imports;
+mod globs;
+mod usage;
+mod walk;
+pub mod css;
+pub mod mdx;
+pub mod sfc;
 pub mod exports;
 
-pub use typescript::{parse_file, parse_source, ParsedModule};
-pub use imports::{Import, ImportedName};
+pub use typescript::{parse_file, parse_module_ast, parse_source, ParsedModule};
+pub use imports::{Import, ImportedName, MemberRef};
+pub use globs::{GlobImport, GlobKind};
 pub use exports::{Export, ReExport, ExportKind, ReExportedName};
@@ -0,0 +1,268 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+pub const SFC_EXTENSIONS: &[&str] = &["vue", "svelte", "astro"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScriptLang {
+    Js,
+    Ts,
+    Tsx,
+}
+
+impl ScriptLang {
+    pub fn extension(self) -> &'static str {
+        match self {
+            ScriptLang::Js => "js",
+            ScriptLang::Ts => "ts",
+            ScriptLang::Tsx => "tsx",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ScriptSource {
+    pub source: String,
+    pub lang: ScriptLang,
+    pub src_imports: Vec<(String, u32, u32)>,
+}
+
+pub fn is_sfc(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|ext| SFC_EXTENSIONS.contains(&ext))
+}
+
+pub fn extract_script(content: &str, path: &Path) -> ScriptSource {
+    let is_astro = path.extension().and_then(|e| e.to_str()) == Some("astro");
+
+    let mut keep: Vec<(usize, usize)> = Vec::new();
+    let mut lang = ScriptLang::Js;
+    let mut src_imports = Vec::new();
+    let mut offset = 0;
+    let lower = content.to_ascii_lowercase();
+
+    if is_astro {
+        if let Some((start, end, body_end)) = frontmatter(content) {
+            keep.push((start, end));
+            lang = ScriptLang::Ts;
+            offset = body_end;
+        }
+    }
+
+    while let Some(tag) = next_script_tag(content, &lower, offset) {
+        offset = tag.end;
+
+        if is_astro && tag.attrs.contains_key("is:inline") {
+            continue;
+        }
+
+        if let Some(src) = tag.attrs.get("src") {
+            let (line, col) = line_col(content, tag.start);
+            src_imports.push((src.clone(), line, col));
+        }
+
+        match tag.attrs.get("lang").map(String::as_str) {
+            Some("ts") if lang != ScriptLang::Tsx => lang = ScriptLang::Ts,
+            Some("tsx") => lang = ScriptLang::Tsx,
+            _ => {}
+        }
+
+        if let Some(body) = tag.body {
+            keep.push(body);
+        }
+    }
+
+    ScriptSource {
+        source: mask(content, &keep),
+        lang,
+        src_imports,
+    }
+}
+
+fn frontmatter(content: &str) -> Option<(usize, usize, usize)> {
+    let trimmed = content.trim_start();
+    let fence_start = content.len() - trimmed.len();
+    if !trimmed.starts_with("---") {
+        return None;
+    }
+
+    let start = fence_start + content[fence_start..].find('\n')? + 1;
+    let mut line_start = start;
+    for line in content[start..].split_inclusive('\n') {
+        if line.trim_end() == "---" {
+            return Some((start, line_start, line_start + line.len()));
+        }
+        line_start += line.len();
+    }
+    None
+}
+
+struct ScriptTag {
+    start: usize,
+    end: usize,
+    body: Option<(usize, usize)>,
+    attrs: HashMap<String, String>,
+}
+
+fn next_script_tag(content: &str, lower: &str, from: usize) -> Option<ScriptTag> {
+    let mut search = from;
+
+    loop {
+        let open = lower[search..].find('<')? + search;
+
+        if lower[open..].starts_with("<!--") {
+            search = lower[open..]
+                .find("-->")
+                .map_or(lower.len(), |i| open + i + 3);
+            continue;
+        }
+
+        let after = open + "<script".len();
+        if !lower[open..].starts_with("<script")
+            || !lower[after..].starts_with(|c: char| c.is_whitespace() || c == '>' || c == '/')
+        {
+            search = open + 1;
+            continue;
+        }
+
+        let (attrs, tag_end, self_closing) = parse_attributes(content, after)?;
+        if self_closing {
+            return Some(ScriptTag {
+                start: open,
+                end: tag_end,
+                body: None,
+                attrs,
+            });
+        }
+
+        let close = lower[tag_end..]
+            .find("</script")
+            .map_or(content.len(), |i| tag_end + i);
+        let end = content[close..]
+            .find('>')
+            .map_or(content.len(), |i| close + i + 1);
+
+        return Some(ScriptTag {
+            start: open,
+            end,
+            body: Some((tag_end, close)),
+            attrs,
+        });
+    }
+}
+
+fn parse_attributes(
+    content: &str,
+    from: usize,
+) -> Option<(HashMap<String, String>, usize, bool)> {
+    let bytes = content.as_bytes();
+    let mut attrs = HashMap::new();
+    let mut i = from;
+
+    loop {
+        while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+        match bytes.get(i)? {
+            b'>' => return Some((attrs, i + 1, false)),
+            b'/' if bytes.get(i + 1) == Some(&b'>') => return Some((attrs, i + 2, true)),
+            _ => {}
+        }
+
+        let name_start = i;
+        while i < bytes.len()
+            && !bytes[i].is_ascii_whitespace()
+            && !matches!(bytes[i], b'=' | b'>' | b'/')
+        {
+            i += 1;
+        }
+        if i == name_start {
+            i += 1;
+            continue;
+        }
+        let name = content[name_start..i].to_ascii_lowercase();
+
+        let mut value = String::new();
+        if bytes.get(i) == Some(&b'=') {
+            i += 1;
+            match bytes.get(i)? {
+                quote @ (b'"' | b'\'') => {
+                    let close = content[i + 1..].find(*quote as char)? + i + 1;
+                    value = content[i + 1..close].to_string();
+                    i = close + 1;
+                }
+                _ => {
+                    let value_start = i;
+                    while i < bytes.len() && !bytes[i].is_ascii_whitespace() && bytes[i] != b'>' {
+                        i += 1;
+                    }
+                    value = content[value_start..i].to_string();
+                }
+            }
+        }
+        attrs.insert(name, value);
+    }
+}
+
+fn mask(content: &str, keep: &[(usize, usize)]) -> String {
+    let mut masked = String::with_capacity(content.len());
+    for (i, c) in content.char_indices() {
+        if c == '\n' || c == '\r' || keep.iter().any(|&(s, e)| i >= s && i < e) {
+            masked.push(c);
+        } else {
+            masked.push(' ');
+        }
+    }
+    masked
+}
+
+fn line_col(content: &str, offset: usize) -> (u32, u32) {
+    let before = &content[..offset];
+    let line = before.matches('\n').count() as u32 + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let col = content[line_start..offset].chars().count() as u32 + 1;
+    (line, col)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_vue_scripts_keeps_positions() {
+        let content = "<template>\n  <Foo />\n</template>\n<script lang=\"ts\">\nexport default {}\n</script>\n<script setup lang=\"ts\">\nimport Foo from './Foo.vue'\n</script>\n";
+        let script = extract_script(content, Path::new("App.vue"));
+
+        assert_eq!(script.lang, ScriptLang::Ts);
+        assert_eq!(script.source.len(), content.len());
+        let lines: Vec<&str> = script.source.lines().collect();
+        assert_eq!(lines[0].trim(), "");
+        assert_eq!(lines[4], "export default {}");
+        assert_eq!(lines[7], "import Foo from './Foo.vue'");
+    }
+
+    #[test]
+    fn test_extract_astro_frontmatter_and_scripts() {
+        let content = "---\nimport Card from '../components/Card.astro';\n---\n<Card />\n<script>\nimport './client';\n</script>\n<script is:inline>\nimport './inline';\n</script>\n";
+        let script = extract_script(content, Path::new("index.astro"));
+
+        assert_eq!(script.lang, ScriptLang::Ts);
+        assert!(script
+            .source
+            .contains("import Card from '../components/Card.astro';"));
+        assert!(script.source.contains("import './client';"));
+        assert!(!script.source.contains("inline"));
+        assert!(!script.source.contains("<Card />"));
+    }
+
+    #[test]
+    fn test_script_src_and_comments() {
+        let content =
+            "<!-- <script>import './commented'</script> -->\n<script src=\"./main.js\" />\n";
+        let script = extract_script(content, Path::new("Widget.svelte"));
+
+        assert!(!script.source.contains("commented"));
+        assert_eq!(script.src_imports, vec![("./main.js".to_string(), 2, 1)]);
+    }
+}
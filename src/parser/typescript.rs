@@ -1,6 +1,7 @@
 use anyhow::{Context, Result};
 use std::path::Path;
 use swc_common::{
+    comments::SingleThreadedComments,
     errors::{ColorConfig, Handler},
     input::StringInput,
     sync::Lrc,
@@ -9,14 +10,20 @@ use swc_common::{
 use swc_ecma_ast::Module;
 use swc_ecma_parser::{lexer::Lexer, EsSyntax, Parser, Syntax, TsSyntax};
 
+use super::css::{is_stylesheet, parse_stylesheet};
 use super::exports::{extract_exports, Export, ReExport};
-use super::imports::{extract_imports, Import};
+use super::globs::{extract_glob_imports, GlobImport};
+use super::imports::{extract_imports, extract_reference_directives, Import};
+use super::mdx::{extract_mdx, is_markdown, retain_referenced};
+use super::sfc::{extract_script, is_sfc};
+use super::usage::track_member_usage;
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct ParsedModule {
     pub imports: Vec<Import>,
     pub exports: Vec<Export>,
     pub re_exports: Vec<ReExport>,
+    pub glob_imports: Vec<GlobImport>,
 }
 
 pub fn parse_file(path: &Path) -> Result<ParsedModule> {
@@ -27,18 +34,75 @@ pub fn parse_file(path: &Path) -> Result<ParsedModule> {
 }
 
 pub fn parse_source(source: &str, path: &Path) -> Result<ParsedModule> {
+    if is_sfc(path) {
+        return parse_sfc(source, path);
+    }
+
+    if is_markdown(path) {
+        return parse_markdown(source, path);
+    }
+
+    if is_stylesheet(path) {
+        return Ok(parse_stylesheet(source, path));
+    }
+
+    parse_script(source, path, get_syntax_for_file(path))
+}
+
+fn parse_sfc(source: &str, path: &Path) -> Result<ParsedModule> {
+    let script = extract_script(source, path);
+    let mut module = parse_script(
+        &script.source,
+        path,
+        syntax_for_extension(script.lang.extension()),
+    )?;
+    forget_members(&mut module.imports);
+
+    for (specifier, line, col) in script.src_imports {
+        module.imports.push(Import {
+            specifier,
+            imported_names: Vec::new(),
+            is_type_only: false,
+            is_side_effect: true,
+            line,
+            col,
+        });
+    }
+
+    if path.extension().and_then(|e| e.to_str()) == Some("astro") {
+        module.exports.clear();
+    }
+
+    Ok(module)
+}
+
+fn parse_markdown(source: &str, path: &Path) -> Result<ParsedModule> {
+    let mdx = extract_mdx(source);
+    let mut module = parse_script(&mdx.esm, path, syntax_for_extension("jsx"))?;
+    retain_referenced(&mut module.imports, &mdx.references);
+    forget_members(&mut module.imports);
+    module.exports.clear();
+    Ok(module)
+}
+
+fn forget_members(imports: &mut [Import]) {
+    for imported in imports.iter_mut().flat_map(|i| &mut i.imported_names) {
+        imported.members = None;
+    }
+}
+
+fn parse_script(source: &str, path: &Path, syntax: Syntax) -> Result<ParsedModule> {
     let cm: Lrc<SourceMap> = Default::default();
     let handler = Handler::with_tty_emitter(ColorConfig::Auto, true, false, Some(cm.clone()));
 
     let fm = cm.new_source_file(Lrc::new(FileName::Real(path.to_path_buf())), source.to_string());
 
-    let syntax = get_syntax_for_file(path);
-
+    let comments = SingleThreadedComments::default();
     let lexer = Lexer::new(
         syntax,
         swc_ecma_ast::EsVersion::EsNext,
         StringInput::from(&*fm),
-        None,
+        Some(&comments),
     );
 
     let mut parser = Parser::new_from(lexer);
@@ -54,7 +118,22 @@ pub fn parse_source(source: &str, path: &Path) -> Result<ParsedModule> {
             anyhow::anyhow!("Failed to parse module: {}", path.display())
         })?;
 
-    Ok(extract_module_info(&module, &cm))
+    Ok(extract_module_info(&module, &comments, &cm))
+}
+
+pub fn parse_module_ast(source: &str, path: &Path) -> Result<Module> {
+    let cm: Lrc<SourceMap> = Default::default();
+    let fm = cm.new_source_file(Lrc::new(FileName::Real(path.to_path_buf())), source.to_string());
+    let lexer = Lexer::new(
+        get_syntax_for_file(path),
+        swc_ecma_ast::EsVersion::EsNext,
+        StringInput::from(&*fm),
+        None,
+    );
+
+    Parser::new_from(lexer)
+        .parse_module()
+        .map_err(|_| anyhow::anyhow!("Failed to parse module: {}", path.display()))
 }
 
 fn get_syntax_for_file(path: &Path) -> Syntax {
@@ -63,6 +142,10 @@ fn get_syntax_for_file(path: &Path) -> Syntax {
         .and_then(|e| e.to_str())
         .unwrap_or("");
 
+    syntax_for_extension(ext)
+}
+
+fn syntax_for_extension(ext: &str) -> Syntax {
     match ext {
         "ts" | "mts" | "cts" => Syntax::Typescript(TsSyntax {
             tsx: false,
@@ -96,14 +179,21 @@ fn get_syntax_for_file(path: &Path) -> Syntax {
     }
 }
 
-fn extract_module_info(module: &Module, cm: &SourceMap) -> ParsedModule {
-    let imports = extract_imports(module, cm);
+fn extract_module_info(
+    module: &Module,
+    comments: &SingleThreadedComments,
+    cm: &SourceMap,
+) -> ParsedModule {
+    let mut imports = extract_reference_directives(module, comments, cm);
+    imports.extend(extract_imports(module, cm));
+    track_member_usage(module, cm, &mut imports);
     let (exports, re_exports) = extract_exports(module, cm);
 
     ParsedModule {
         imports,
         exports,
         re_exports,
+        glob_imports: extract_glob_imports(module, cm),
     }
 }
 
@@ -0,0 +1,278 @@
+use std::collections::HashMap;
+
+use swc_common::{SourceMap, Span};
+use swc_ecma_ast::{
+    Expr, JSXMemberExpr, JSXObject, Lit, MemberExpr, MemberProp, Module, ObjectPatProp, Pat,
+    PropName, TsEntityName, TsQualifiedName, VarDeclarator,
+};
+
+use super::imports::{Import, MemberRef};
+use super::typescript::get_line_col;
+use super::walk::{walk_member_prop, walk_module_items, walk_pat, Visitor};
+
+#[derive(Default)]
+struct BindingUsage {
+    members: Vec<MemberRef>,
+    escaped: bool,
+}
+
+struct MemberCollector<'a> {
+    cm: &'a SourceMap,
+    bindings: HashMap<String, BindingUsage>,
+}
+
+pub fn track_member_usage(module: &Module, cm: &SourceMap, imports: &mut [Import]) {
+    let bindings: HashMap<String, BindingUsage> = imports
+        .iter()
+        .flat_map(|import| &import.imported_names)
+        .filter(|imported| imported.name == "default" || imported.name == "*")
+        .filter_map(|imported| imported.alias.clone())
+        .map(|local| (local, BindingUsage::default()))
+        .collect();
+    if bindings.is_empty() {
+        return;
+    }
+
+    let mut collector = MemberCollector { cm, bindings };
+    walk_module_items(&module.body, &mut collector);
+
+    for imported in imports
+        .iter_mut()
+        .flat_map(|import| &mut import.imported_names)
+    {
+        let Some(usage) = imported
+            .alias
+            .as_ref()
+            .filter(|_| imported.name == "default" || imported.name == "*")
+            .and_then(|local| collector.bindings.get(local))
+        else {
+            continue;
+        };
+        if !usage.escaped {
+            imported.members = Some(usage.members.clone());
+        }
+    }
+}
+
+impl MemberCollector<'_> {
+    fn binding(&mut self, expr: &Expr) -> Option<&mut BindingUsage> {
+        match expr {
+            Expr::Ident(ident) => self.bindings.get_mut(ident.sym.as_str()),
+            Expr::Paren(paren) => self.binding(&paren.expr),
+            Expr::TsAs(ts) => self.binding(&ts.expr),
+            Expr::TsNonNull(ts) => self.binding(&ts.expr),
+            _ => None,
+        }
+    }
+
+    fn member(&self, name: &str, span: Span) -> MemberRef {
+        let (line, col) = get_line_col(self.cm, span);
+        MemberRef {
+            name: name.to_string(),
+            line,
+            col,
+        }
+    }
+}
+
+impl Visitor for MemberCollector<'_> {
+    fn enter_member(&mut self, member: &MemberExpr) -> bool {
+        let name = match &member.prop {
+            MemberProp::Ident(ident) => Some(ident.sym.to_string()),
+            MemberProp::Computed(computed) => match &*computed.expr {
+                Expr::Lit(Lit::Str(s)) => s.value.as_str().map(str::to_string),
+                _ => None,
+            },
+            MemberProp::PrivateName(_) => return true,
+        };
+        let member_ref = name
+            .as_deref()
+            .map(|name| self.member(name, prop_span(&member.prop)));
+
+        let Some(usage) = self.binding(&member.obj) else {
+            return true;
+        };
+        match member_ref {
+            Some(member_ref) => usage.members.push(member_ref),
+            None => usage.escaped = true,
+        }
+
+        walk_member_prop(&member.prop, self);
+        false
+    }
+
+    fn enter_var_declarator(&mut self, decl: &VarDeclarator) -> bool {
+        let (Pat::Object(pattern), Some(init)) = (&decl.name, &decl.init) else {
+            return true;
+        };
+        if self.binding(init).is_none() {
+            return true;
+        }
+
+        let mut members = Vec::new();
+        let mut escaped = false;
+        for prop in &pattern.props {
+            match prop {
+                ObjectPatProp::KeyValue(kv) => match &kv.key {
+                    PropName::Ident(ident) => members.push(self.member(&ident.sym, ident.span)),
+                    PropName::Str(s) => {
+                        members.push(self.member(s.value.as_str().unwrap_or_default(), s.span))
+                    }
+                    _ => escaped = true,
+                },
+                ObjectPatProp::Assign(assign) => {
+                    members.push(self.member(&assign.key.sym, assign.key.span))
+                }
+                ObjectPatProp::Rest(_) => escaped = true,
+            }
+        }
+
+        if let Some(usage) = self.binding(init) {
+            usage.members.extend(members);
+            usage.escaped |= escaped;
+        }
+        walk_pat(&decl.name, self);
+        false
+    }
+
+    fn enter_jsx_member(&mut self, member: &JSXMemberExpr) -> bool {
+        let JSXObject::Ident(object) = &member.obj else {
+            return true;
+        };
+        if !self.bindings.contains_key(object.sym.as_str()) {
+            return true;
+        }
+
+        let member_ref = self.member(&member.prop.sym, member.prop.span);
+        if let Some(usage) = self.bindings.get_mut(object.sym.as_str()) {
+            usage.members.push(member_ref);
+        }
+        false
+    }
+
+    fn enter_qualified_name(&mut self, name: &TsQualifiedName) -> bool {
+        let TsEntityName::Ident(object) = &name.left else {
+            return true;
+        };
+        if !self.bindings.contains_key(object.sym.as_str()) {
+            return true;
+        }
+
+        let member_ref = self.member(&name.right.sym, name.right.span);
+        if let Some(usage) = self.bindings.get_mut(object.sym.as_str()) {
+            usage.members.push(member_ref);
+        }
+        false
+    }
+
+    fn visit_ident(&mut self, ident: &swc_ecma_ast::Ident) {
+        if let Some(usage) = self.bindings.get_mut(ident.sym.as_str()) {
+            usage.escaped = true;
+        }
+    }
+}
+
+fn prop_span(prop: &MemberProp) -> Span {
+    match prop {
+        MemberProp::Ident(ident) => ident.span,
+        MemberProp::Computed(computed) => computed.span,
+        MemberProp::PrivateName(private) => private.span,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::parse_source;
+    use std::path::Path;
+
+    fn members(source: &str, local: &str) -> Option<Vec<String>> {
+        let parsed = parse_source(source, Path::new("test.tsx")).unwrap();
+        parsed
+            .imports
+            .iter()
+            .flat_map(|import| &import.imported_names)
+            .find(|imported| imported.alias.as_deref() == Some(local))
+            .and_then(|imported| imported.members.as_ref())
+            .map(|members| members.iter().map(|m| m.name.clone()).collect())
+    }
+
+    #[test]
+    fn test_member_access_is_recorded() {
+        let source = r#"
+import styles from './Button.module.css';
+export function Button({ active }) {
+    const { icon, 'label-text': label } = styles;
+    return <button className={active ? styles.active : styles['base']}>{icon}{label}</button>;
+}
+"#;
+        assert_eq!(
+            members(source, "styles"),
+            Some(vec![
+                "icon".to_string(),
+                "label-text".to_string(),
+                "active".to_string(),
+                "base".to_string(),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_escaping_binding_is_fully_used() {
+        assert_eq!(
+            members(
+                "import styles from './a.module.css';\nconst key = 'x';\nconsole.log(styles[key]);",
+                "styles"
+            ),
+            None
+        );
+        assert_eq!(
+            members(
+                "import * as utils from './utils';\nregister(utils);",
+                "utils"
+            ),
+            None
+        );
+        assert_eq!(
+            members(
+                "import * as utils from './utils';\nconst { a, ...rest } = utils;",
+                "utils"
+            ),
+            None
+        );
+        assert_eq!(
+            members("import * as ui from './ui';\nconst el = <ui.Card />;", "ui"),
+            Some(vec!["Card".to_string()])
+        );
+        assert_eq!(
+            members("import styles from './a.module.css';", "styles"),
+            Some(vec![])
+        );
+    }
+
+    #[test]
+    fn test_type_positions_are_recorded() {
+        let source = r#"
+import * as api from './api';
+export function load(id: api.Id): Promise<api.User[]> {
+    return fetch(id) as unknown as Promise<api.User[]>;
+}
+export type Query = typeof api.query;
+"#;
+        assert_eq!(
+            members(source, "api"),
+            Some(vec![
+                "Id".to_string(),
+                "User".to_string(),
+                "User".to_string(),
+                "query".to_string(),
+            ])
+        );
+        assert_eq!(
+            members(
+                "import * as api from './api';\ntype Api = typeof api;",
+                "api"
+            ),
+            None
+        );
+    }
+}
@@ -0,0 +1,781 @@
+use swc_ecma_ast::{
+    AssignTarget, AssignTargetPat, BlockStmt, BlockStmtOrExpr, Callee, Class, ClassMember, Decl,
+    DefaultDecl, ExportSpecifier, Expr, ExprOrSpread, ForHead, Function, Ident, JSXAttrOrSpread,
+    JSXAttrValue, JSXElement, JSXElementChild, JSXElementName, JSXExpr, JSXMemberExpr, JSXObject,
+    MemberExpr, MemberProp, ModuleDecl, ModuleExportName, ModuleItem, ObjectPat, ObjectPatProp,
+    OptChainBase, ParamOrTsParamProp, Pat, Prop, PropName, PropOrSpread, SimpleAssignTarget, Stmt,
+    SuperProp, TsEntityName, TsFnOrConstructorType, TsFnParam, TsImportType, TsInterfaceDecl,
+    TsLit, TsModuleRef, TsNamespaceBody, TsParamPropParam, TsQualifiedName, TsType, TsTypeAnn,
+    TsTypeElement, TsTypeParam, TsTypeParamDecl, TsTypeParamInstantiation, TsTypeQueryExpr,
+    TsUnionOrIntersectionType, VarDecl, VarDeclOrExpr, VarDeclarator,
+};
+
+pub trait Visitor {
+    fn enter_expr(&mut self, _expr: &Expr) -> bool {
+        true
+    }
+
+    fn enter_var_declarator(&mut self, _decl: &VarDeclarator) -> bool {
+        true
+    }
+
+    fn enter_member(&mut self, _member: &MemberExpr) -> bool {
+        true
+    }
+
+    fn enter_jsx_member(&mut self, _member: &JSXMemberExpr) -> bool {
+        true
+    }
+
+    fn enter_qualified_name(&mut self, _name: &TsQualifiedName) -> bool {
+        true
+    }
+
+    fn visit_import_type(&mut self, _import: &TsImportType) {}
+
+    fn visit_ident(&mut self, _ident: &Ident) {}
+}
+
+pub fn walk_module_items<V: Visitor>(items: &[ModuleItem], v: &mut V) {
+    for item in items {
+        match item {
+            ModuleItem::ModuleDecl(decl) => walk_module_decl(decl, v),
+            ModuleItem::Stmt(stmt) => walk_stmt(stmt, v),
+        }
+    }
+}
+
+fn walk_module_decl<V: Visitor>(decl: &ModuleDecl, v: &mut V) {
+    match decl {
+        ModuleDecl::ExportDecl(export) => walk_decl(&export.decl, v),
+        ModuleDecl::ExportDefaultDecl(export) => match &export.decl {
+            DefaultDecl::Class(class) => walk_class(&class.class, v),
+            DefaultDecl::Fn(func) => walk_function(&func.function, v),
+            DefaultDecl::TsInterfaceDecl(interface) => walk_interface(interface, v),
+        },
+        ModuleDecl::ExportDefaultExpr(export) => walk_expr(&export.expr, v),
+        ModuleDecl::TsExportAssignment(export) => walk_expr(&export.expr, v),
+        ModuleDecl::TsImportEquals(import) => {
+            if let TsModuleRef::TsEntityName(name) = &import.module_ref {
+                walk_entity_name(name, v);
+            }
+        }
+        ModuleDecl::ExportNamed(named) if named.src.is_none() => {
+            for spec in &named.specifiers {
+                if let ExportSpecifier::Named(spec) = spec {
+                    if let ModuleExportName::Ident(ident) = &spec.orig {
+                        v.visit_ident(ident);
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+pub fn walk_stmt<V: Visitor>(stmt: &Stmt, v: &mut V) {
+    match stmt {
+        Stmt::Block(block) => walk_block(block, v),
+        Stmt::With(with) => {
+            walk_expr(&with.obj, v);
+            walk_stmt(&with.body, v);
+        }
+        Stmt::Return(ret) => walk_opt_expr(ret.arg.as_deref(), v),
+        Stmt::Labeled(labeled) => walk_stmt(&labeled.body, v),
+        Stmt::If(if_stmt) => {
+            walk_expr(&if_stmt.test, v);
+            walk_stmt(&if_stmt.cons, v);
+            if let Some(alt) = &if_stmt.alt {
+                walk_stmt(alt, v);
+            }
+        }
+        Stmt::Switch(switch) => {
+            walk_expr(&switch.discriminant, v);
+            for case in &switch.cases {
+                walk_opt_expr(case.test.as_deref(), v);
+                for stmt in &case.cons {
+                    walk_stmt(stmt, v);
+                }
+            }
+        }
+        Stmt::Throw(throw) => walk_expr(&throw.arg, v),
+        Stmt::Try(try_stmt) => {
+            walk_block(&try_stmt.block, v);
+            if let Some(handler) = &try_stmt.handler {
+                if let Some(param) = &handler.param {
+                    walk_pat(param, v);
+                }
+                walk_block(&handler.body, v);
+            }
+            if let Some(finalizer) = &try_stmt.finalizer {
+                walk_block(finalizer, v);
+            }
+        }
+        Stmt::While(while_stmt) => {
+            walk_expr(&while_stmt.test, v);
+            walk_stmt(&while_stmt.body, v);
+        }
+        Stmt::DoWhile(do_while) => {
+            walk_stmt(&do_while.body, v);
+            walk_expr(&do_while.test, v);
+        }
+        Stmt::For(for_stmt) => {
+            match &for_stmt.init {
+                Some(VarDeclOrExpr::VarDecl(decl)) => walk_var_decl(decl, v),
+                Some(VarDeclOrExpr::Expr(expr)) => walk_expr(expr, v),
+                None => {}
+            }
+            walk_opt_expr(for_stmt.test.as_deref(), v);
+            walk_opt_expr(for_stmt.update.as_deref(), v);
+            walk_stmt(&for_stmt.body, v);
+        }
+        Stmt::ForIn(for_in) => {
+            walk_for_head(&for_in.left, v);
+            walk_expr(&for_in.right, v);
+            walk_stmt(&for_in.body, v);
+        }
+        Stmt::ForOf(for_of) => {
+            walk_for_head(&for_of.left, v);
+            walk_expr(&for_of.right, v);
+            walk_stmt(&for_of.body, v);
+        }
+        Stmt::Decl(decl) => walk_decl(decl, v),
+        Stmt::Expr(expr) => walk_expr(&expr.expr, v),
+        Stmt::Empty(_) | Stmt::Debugger(_) | Stmt::Break(_) | Stmt::Continue(_) => {}
+    }
+}
+
+fn walk_block<V: Visitor>(block: &BlockStmt, v: &mut V) {
+    for stmt in &block.stmts {
+        walk_stmt(stmt, v);
+    }
+}
+
+fn walk_for_head<V: Visitor>(head: &ForHead, v: &mut V) {
+    match head {
+        ForHead::VarDecl(decl) => walk_var_decl(decl, v),
+        ForHead::UsingDecl(decl) => walk_var_declarators(&decl.decls, v),
+        ForHead::Pat(pat) => walk_pat(pat, v),
+    }
+}
+
+fn walk_decl<V: Visitor>(decl: &Decl, v: &mut V) {
+    match decl {
+        Decl::Class(class) => walk_class(&class.class, v),
+        Decl::Fn(func) => walk_function(&func.function, v),
+        Decl::Var(var) => walk_var_decl(var, v),
+        Decl::Using(using) => walk_var_declarators(&using.decls, v),
+        Decl::TsEnum(ts_enum) => {
+            for member in &ts_enum.members {
+                walk_opt_expr(member.init.as_deref(), v);
+            }
+        }
+        Decl::TsModule(module) => {
+            let mut body = module.body.as_ref();
+            while let Some(namespace) = body {
+                match namespace {
+                    TsNamespaceBody::TsModuleBlock(block) => {
+                        walk_module_items(&block.body, v);
+                        body = None;
+                    }
+                    TsNamespaceBody::TsNamespaceDecl(decl) => body = Some(&decl.body),
+                }
+            }
+        }
+        Decl::TsInterface(interface) => walk_interface(interface, v),
+        Decl::TsTypeAlias(alias) => {
+            walk_type_params(alias.type_params.as_deref(), v);
+            walk_type(&alias.type_ann, v);
+        }
+    }
+}
+
+fn walk_var_decl<V: Visitor>(decl: &VarDecl, v: &mut V) {
+    walk_var_declarators(&decl.decls, v);
+}
+
+fn walk_var_declarators<V: Visitor>(decls: &[VarDeclarator], v: &mut V) {
+    for decl in decls {
+        if v.enter_var_declarator(decl) {
+            walk_pat(&decl.name, v);
+            walk_opt_expr(decl.init.as_deref(), v);
+        }
+    }
+}
+
+fn walk_function<V: Visitor>(function: &Function, v: &mut V) {
+    for decorator in &function.decorators {
+        walk_expr(&decorator.expr, v);
+    }
+    walk_type_params(function.type_params.as_deref(), v);
+    for param in &function.params {
+        for decorator in &param.decorators {
+            walk_expr(&decorator.expr, v);
+        }
+        walk_pat(&param.pat, v);
+    }
+    walk_type_ann(function.return_type.as_deref(), v);
+    if let Some(body) = &function.body {
+        walk_block(body, v);
+    }
+}
+
+fn walk_class<V: Visitor>(class: &Class, v: &mut V) {
+    for decorator in &class.decorators {
+        walk_expr(&decorator.expr, v);
+    }
+    walk_type_params(class.type_params.as_deref(), v);
+    walk_opt_expr(class.super_class.as_deref(), v);
+    walk_type_args(class.super_type_params.as_deref(), v);
+    for implemented in &class.implements {
+        walk_expr(&implemented.expr, v);
+        walk_type_args(implemented.type_args.as_deref(), v);
+    }
+
+    for member in &class.body {
+        match member {
+            ClassMember::Constructor(constructor) => {
+                walk_prop_name(&constructor.key, v);
+                for param in &constructor.params {
+                    match param {
+                        ParamOrTsParamProp::Param(param) => walk_pat(&param.pat, v),
+                        ParamOrTsParamProp::TsParamProp(prop) => match &prop.param {
+                            TsParamPropParam::Ident(ident) => {
+                                walk_type_ann(ident.type_ann.as_deref(), v)
+                            }
+                            TsParamPropParam::Assign(assign) => {
+                                walk_pat(&assign.left, v);
+                                walk_expr(&assign.right, v);
+                            }
+                        },
+                    }
+                }
+                if let Some(body) = &constructor.body {
+                    walk_block(body, v);
+                }
+            }
+            ClassMember::Method(method) => {
+                walk_prop_name(&method.key, v);
+                walk_function(&method.function, v);
+            }
+            ClassMember::PrivateMethod(method) => walk_function(&method.function, v),
+            ClassMember::ClassProp(prop) => {
+                for decorator in &prop.decorators {
+                    walk_expr(&decorator.expr, v);
+                }
+                walk_prop_name(&prop.key, v);
+                walk_type_ann(prop.type_ann.as_deref(), v);
+                walk_opt_expr(prop.value.as_deref(), v);
+            }
+            ClassMember::PrivateProp(prop) => {
+                walk_type_ann(prop.type_ann.as_deref(), v);
+                walk_opt_expr(prop.value.as_deref(), v);
+            }
+            ClassMember::StaticBlock(block) => walk_block(&block.body, v),
+            ClassMember::AutoAccessor(accessor) => {
+                walk_type_ann(accessor.type_ann.as_deref(), v);
+                walk_opt_expr(accessor.value.as_deref(), v);
+            }
+            ClassMember::TsIndexSignature(signature) => {
+                walk_fn_params(&signature.params, v);
+                walk_type_ann(signature.type_ann.as_deref(), v);
+            }
+            ClassMember::Empty(_) => {}
+        }
+    }
+}
+
+fn walk_prop_name<V: Visitor>(name: &PropName, v: &mut V) {
+    if let PropName::Computed(computed) = name {
+        walk_expr(&computed.expr, v);
+    }
+}
+
+pub fn walk_pat<V: Visitor>(pat: &Pat, v: &mut V) {
+    match pat {
+        Pat::Array(array) => {
+            for elem in array.elems.iter().flatten() {
+                walk_pat(elem, v);
+            }
+            walk_type_ann(array.type_ann.as_deref(), v);
+        }
+        Pat::Rest(rest) => {
+            walk_pat(&rest.arg, v);
+            walk_type_ann(rest.type_ann.as_deref(), v);
+        }
+        Pat::Object(object) => walk_object_pat(object, v),
+        Pat::Assign(assign) => {
+            walk_pat(&assign.left, v);
+            walk_expr(&assign.right, v);
+        }
+        Pat::Expr(expr) => walk_expr(expr, v),
+        Pat::Ident(ident) => walk_type_ann(ident.type_ann.as_deref(), v),
+        Pat::Invalid(_) => {}
+    }
+}
+
+fn walk_object_pat<V: Visitor>(object: &ObjectPat, v: &mut V) {
+    walk_type_ann(object.type_ann.as_deref(), v);
+    for prop in &object.props {
+        match prop {
+            ObjectPatProp::KeyValue(kv) => {
+                walk_prop_name(&kv.key, v);
+                walk_pat(&kv.value, v);
+            }
+            ObjectPatProp::Assign(assign) => walk_opt_expr(assign.value.as_deref(), v),
+            ObjectPatProp::Rest(rest) => walk_pat(&rest.arg, v),
+        }
+    }
+}
+
+fn walk_opt_expr<V: Visitor>(expr: Option<&Expr>, v: &mut V) {
+    if let Some(expr) = expr {
+        walk_expr(expr, v);
+    }
+}
+
+fn walk_args<V: Visitor>(args: &[ExprOrSpread], v: &mut V) {
+    for arg in args {
+        walk_expr(&arg.expr, v);
+    }
+}
+
+fn walk_member<V: Visitor>(member: &MemberExpr, v: &mut V) {
+    if !v.enter_member(member) {
+        return;
+    }
+    walk_expr(&member.obj, v);
+    walk_member_prop(&member.prop, v);
+}
+
+pub fn walk_member_prop<V: Visitor>(prop: &MemberProp, v: &mut V) {
+    if let MemberProp::Computed(computed) = prop {
+        walk_expr(&computed.expr, v);
+    }
+}
+
+pub fn walk_expr<V: Visitor>(expr: &Expr, v: &mut V) {
+    if !v.enter_expr(expr) {
+        return;
+    }
+
+    match expr {
+        Expr::Ident(ident) => v.visit_ident(ident),
+        Expr::Array(array) => {
+            for elem in array.elems.iter().flatten() {
+                walk_expr(&elem.expr, v);
+            }
+        }
+        Expr::Object(object) => {
+            for prop in &object.props {
+                match prop {
+                    PropOrSpread::Spread(spread) => walk_expr(&spread.expr, v),
+                    PropOrSpread::Prop(prop) => match &**prop {
+                        Prop::Shorthand(ident) => v.visit_ident(ident),
+                        Prop::KeyValue(kv) => {
+                            walk_prop_name(&kv.key, v);
+                            walk_expr(&kv.value, v);
+                        }
+                        Prop::Assign(assign) => walk_expr(&assign.value, v),
+                        Prop::Getter(getter) => {
+                            walk_prop_name(&getter.key, v);
+                            if let Some(body) = &getter.body {
+                                walk_block(body, v);
+                            }
+                        }
+                        Prop::Setter(setter) => {
+                            walk_prop_name(&setter.key, v);
+                            walk_pat(&setter.param, v);
+                            if let Some(body) = &setter.body {
+                                walk_block(body, v);
+                            }
+                        }
+                        Prop::Method(method) => {
+                            walk_prop_name(&method.key, v);
+                            walk_function(&method.function, v);
+                        }
+                    },
+                }
+            }
+        }
+        Expr::Fn(func) => walk_function(&func.function, v),
+        Expr::Unary(unary) => walk_expr(&unary.arg, v),
+        Expr::Update(update) => walk_expr(&update.arg, v),
+        Expr::Bin(bin) => {
+            walk_expr(&bin.left, v);
+            walk_expr(&bin.right, v);
+        }
+        Expr::Assign(assign) => {
+            match &assign.left {
+                AssignTarget::Simple(target) => match target {
+                    SimpleAssignTarget::Ident(ident) => v.visit_ident(&ident.id),
+                    SimpleAssignTarget::Member(member) => walk_member(member, v),
+                    SimpleAssignTarget::SuperProp(super_prop) => {
+                        if let SuperProp::Computed(computed) = &super_prop.prop {
+                            walk_expr(&computed.expr, v);
+                        }
+                    }
+                    SimpleAssignTarget::Paren(paren) => walk_expr(&paren.expr, v),
+                    SimpleAssignTarget::OptChain(chain) => walk_opt_chain(&chain.base, v),
+                    SimpleAssignTarget::TsAs(ts) => walk_expr(&ts.expr, v),
+                    SimpleAssignTarget::TsSatisfies(ts) => walk_expr(&ts.expr, v),
+                    SimpleAssignTarget::TsNonNull(ts) => walk_expr(&ts.expr, v),
+                    SimpleAssignTarget::TsTypeAssertion(ts) => walk_expr(&ts.expr, v),
+                    SimpleAssignTarget::TsInstantiation(ts) => walk_expr(&ts.expr, v),
+                    SimpleAssignTarget::Invalid(_) => {}
+                },
+                AssignTarget::Pat(pat) => match pat {
+                    AssignTargetPat::Array(array) => {
+                        for elem in array.elems.iter().flatten() {
+                            walk_pat(elem, v);
+                        }
+                    }
+                    AssignTargetPat::Object(object) => walk_object_pat(object, v),
+                    AssignTargetPat::Invalid(_) => {}
+                },
+            }
+            walk_expr(&assign.right, v);
+        }
+        Expr::Member(member) => walk_member(member, v),
+        Expr::SuperProp(super_prop) => {
+            if let SuperProp::Computed(computed) = &super_prop.prop {
+                walk_expr(&computed.expr, v);
+            }
+        }
+        Expr::Cond(cond) => {
+            walk_expr(&cond.test, v);
+            walk_expr(&cond.cons, v);
+            walk_expr(&cond.alt, v);
+        }
+        Expr::Call(call) => {
+            if let Callee::Expr(callee) = &call.callee {
+                walk_expr(callee, v);
+            }
+            walk_type_args(call.type_args.as_deref(), v);
+            walk_args(&call.args, v);
+        }
+        Expr::New(new) => {
+            walk_expr(&new.callee, v);
+            walk_type_args(new.type_args.as_deref(), v);
+            if let Some(args) = &new.args {
+                walk_args(args, v);
+            }
+        }
+        Expr::Seq(seq) => {
+            for expr in &seq.exprs {
+                walk_expr(expr, v);
+            }
+        }
+        Expr::Tpl(tpl) => {
+            for expr in &tpl.exprs {
+                walk_expr(expr, v);
+            }
+        }
+        Expr::TaggedTpl(tagged) => {
+            walk_expr(&tagged.tag, v);
+            walk_type_args(tagged.type_params.as_deref(), v);
+            for expr in &tagged.tpl.exprs {
+                walk_expr(expr, v);
+            }
+        }
+        Expr::Arrow(arrow) => {
+            walk_type_params(arrow.type_params.as_deref(), v);
+            for param in &arrow.params {
+                walk_pat(param, v);
+            }
+            walk_type_ann(arrow.return_type.as_deref(), v);
+            match &*arrow.body {
+                BlockStmtOrExpr::BlockStmt(block) => walk_block(block, v),
+                BlockStmtOrExpr::Expr(expr) => walk_expr(expr, v),
+            }
+        }
+        Expr::Class(class) => walk_class(&class.class, v),
+        Expr::Yield(yield_expr) => walk_opt_expr(yield_expr.arg.as_deref(), v),
+        Expr::Await(await_expr) => walk_expr(&await_expr.arg, v),
+        Expr::Paren(paren) => walk_expr(&paren.expr, v),
+        Expr::JSXMember(member) => walk_jsx_member(member, v),
+        Expr::JSXElement(element) => walk_jsx_element(element, v),
+        Expr::JSXFragment(fragment) => walk_jsx_children(&fragment.children, v),
+        Expr::TsTypeAssertion(ts) => {
+            walk_expr(&ts.expr, v);
+            walk_type(&ts.type_ann, v);
+        }
+        Expr::TsConstAssertion(ts) => walk_expr(&ts.expr, v),
+        Expr::TsNonNull(ts) => walk_expr(&ts.expr, v),
+        Expr::TsAs(ts) => {
+            walk_expr(&ts.expr, v);
+            walk_type(&ts.type_ann, v);
+        }
+        Expr::TsInstantiation(ts) => {
+            walk_expr(&ts.expr, v);
+            walk_type_args(Some(&ts.type_args), v);
+        }
+        Expr::TsSatisfies(ts) => {
+            walk_expr(&ts.expr, v);
+            walk_type(&ts.type_ann, v);
+        }
+        Expr::OptChain(chain) => walk_opt_chain(&chain.base, v),
+        Expr::This(_)
+        | Expr::Lit(_)
+        | Expr::MetaProp(_)
+        | Expr::JSXNamespacedName(_)
+        | Expr::JSXEmpty(_)
+        | Expr::PrivateName(_)
+        | Expr::Invalid(_) => {}
+    }
+}
+
+fn walk_opt_chain<V: Visitor>(base: &OptChainBase, v: &mut V) {
+    match base {
+        OptChainBase::Member(member) => walk_member(member, v),
+        OptChainBase::Call(call) => {
+            walk_expr(&call.callee, v);
+            walk_type_args(call.type_args.as_deref(), v);
+            walk_args(&call.args, v);
+        }
+    }
+}
+
+fn walk_jsx_member<V: Visitor>(member: &JSXMemberExpr, v: &mut V) {
+    if !v.enter_jsx_member(member) {
+        return;
+    }
+    match &member.obj {
+        JSXObject::Ident(ident) => v.visit_ident(ident),
+        JSXObject::JSXMemberExpr(inner) => walk_jsx_member(inner, v),
+    }
+}
+
+fn walk_jsx_element<V: Visitor>(element: &JSXElement, v: &mut V) {
+    match &element.opening.name {
+        JSXElementName::Ident(ident) => v.visit_ident(ident),
+        JSXElementName::JSXMemberExpr(member) => walk_jsx_member(member, v),
+        JSXElementName::JSXNamespacedName(_) => {}
+    }
+
+    for attr in &element.opening.attrs {
+        match attr {
+            JSXAttrOrSpread::SpreadElement(spread) => walk_expr(&spread.expr, v),
+            JSXAttrOrSpread::JSXAttr(attr) => match &attr.value {
+                Some(JSXAttrValue::JSXExprContainer(container)) => {
+                    if let JSXExpr::Expr(expr) = &container.expr {
+                        walk_expr(expr, v);
+                    }
+                }
+                Some(JSXAttrValue::JSXElement(element)) => walk_jsx_element(element, v),
+                Some(JSXAttrValue::JSXFragment(fragment)) => {
+                    walk_jsx_children(&fragment.children, v)
+                }
+                Some(JSXAttrValue::Str(_)) | None => {}
+            },
+        }
+    }
+
+    walk_jsx_children(&element.children, v);
+}
+
+fn walk_jsx_children<V: Visitor>(children: &[JSXElementChild], v: &mut V) {
+    for child in children {
+        match child {
+            JSXElementChild::JSXExprContainer(container) => {
+                if let JSXExpr::Expr(expr) = &container.expr {
+                    walk_expr(expr, v);
+                }
+            }
+            JSXElementChild::JSXSpreadChild(spread) => walk_expr(&spread.expr, v),
+            JSXElementChild::JSXElement(element) => walk_jsx_element(element, v),
+            JSXElementChild::JSXFragment(fragment) => walk_jsx_children(&fragment.children, v),
+            JSXElementChild::JSXText(_) => {}
+        }
+    }
+}
+
+fn walk_interface<V: Visitor>(interface: &TsInterfaceDecl, v: &mut V) {
+    walk_type_params(interface.type_params.as_deref(), v);
+    for extended in &interface.extends {
+        walk_expr(&extended.expr, v);
+        walk_type_args(extended.type_args.as_deref(), v);
+    }
+    walk_type_elements(&interface.body.body, v);
+}
+
+fn walk_type_elements<V: Visitor>(elements: &[TsTypeElement], v: &mut V) {
+    for element in elements {
+        match element {
+            TsTypeElement::TsCallSignatureDecl(signature) => {
+                walk_type_params(signature.type_params.as_deref(), v);
+                walk_fn_params(&signature.params, v);
+                walk_type_ann(signature.type_ann.as_deref(), v);
+            }
+            TsTypeElement::TsConstructSignatureDecl(signature) => {
+                walk_type_params(signature.type_params.as_deref(), v);
+                walk_fn_params(&signature.params, v);
+                walk_type_ann(signature.type_ann.as_deref(), v);
+            }
+            TsTypeElement::TsPropertySignature(signature) => {
+                if signature.computed {
+                    walk_expr(&signature.key, v);
+                }
+                walk_type_ann(signature.type_ann.as_deref(), v);
+            }
+            TsTypeElement::TsGetterSignature(signature) => {
+                if signature.computed {
+                    walk_expr(&signature.key, v);
+                }
+                walk_type_ann(signature.type_ann.as_deref(), v);
+            }
+            TsTypeElement::TsSetterSignature(signature) => {
+                if signature.computed {
+                    walk_expr(&signature.key, v);
+                }
+                walk_fn_params(std::slice::from_ref(&signature.param), v);
+            }
+            TsTypeElement::TsMethodSignature(signature) => {
+                if signature.computed {
+                    walk_expr(&signature.key, v);
+                }
+                walk_type_params(signature.type_params.as_deref(), v);
+                walk_fn_params(&signature.params, v);
+                walk_type_ann(signature.type_ann.as_deref(), v);
+            }
+            TsTypeElement::TsIndexSignature(signature) => {
+                walk_fn_params(&signature.params, v);
+                walk_type_ann(signature.type_ann.as_deref(), v);
+            }
+        }
+    }
+}
+
+fn walk_fn_params<V: Visitor>(params: &[TsFnParam], v: &mut V) {
+    for param in params {
+        match param {
+            TsFnParam::Ident(ident) => walk_type_ann(ident.type_ann.as_deref(), v),
+            TsFnParam::Array(array) => walk_type_ann(array.type_ann.as_deref(), v),
+            TsFnParam::Rest(rest) => walk_type_ann(rest.type_ann.as_deref(), v),
+            TsFnParam::Object(object) => walk_type_ann(object.type_ann.as_deref(), v),
+        }
+    }
+}
+
+fn walk_type_params<V: Visitor>(params: Option<&TsTypeParamDecl>, v: &mut V) {
+    for param in params.iter().flat_map(|params| &params.params) {
+        walk_type_param(param, v);
+    }
+}
+
+fn walk_type_param<V: Visitor>(param: &TsTypeParam, v: &mut V) {
+    if let Some(constraint) = &param.constraint {
+        walk_type(constraint, v);
+    }
+    if let Some(default) = &param.default {
+        walk_type(default, v);
+    }
+}
+
+fn walk_type_args<V: Visitor>(args: Option<&TsTypeParamInstantiation>, v: &mut V) {
+    for ty in args.iter().flat_map(|args| &args.params) {
+        walk_type(ty, v);
+    }
+}
+
+fn walk_type_ann<V: Visitor>(ann: Option<&TsTypeAnn>, v: &mut V) {
+    if let Some(ann) = ann {
+        walk_type(&ann.type_ann, v);
+    }
+}
+
+fn walk_entity_name<V: Visitor>(name: &TsEntityName, v: &mut V) {
+    match name {
+        TsEntityName::Ident(ident) => v.visit_ident(ident),
+        TsEntityName::TsQualifiedName(qualified) => {
+            if v.enter_qualified_name(qualified) {
+                walk_entity_name(&qualified.left, v);
+            }
+        }
+    }
+}
+
+fn walk_type<V: Visitor>(ty: &TsType, v: &mut V) {
+    match ty {
+        TsType::TsFnOrConstructorType(TsFnOrConstructorType::TsFnType(fn_type)) => {
+            walk_type_params(fn_type.type_params.as_deref(), v);
+            walk_fn_params(&fn_type.params, v);
+            walk_type(&fn_type.type_ann.type_ann, v);
+        }
+        TsType::TsFnOrConstructorType(TsFnOrConstructorType::TsConstructorType(ctor)) => {
+            walk_type_params(ctor.type_params.as_deref(), v);
+            walk_fn_params(&ctor.params, v);
+            walk_type(&ctor.type_ann.type_ann, v);
+        }
+        TsType::TsTypeRef(type_ref) => {
+            walk_entity_name(&type_ref.type_name, v);
+            walk_type_args(type_ref.type_params.as_deref(), v);
+        }
+        TsType::TsTypeQuery(query) => {
+            match &query.expr_name {
+                TsTypeQueryExpr::TsEntityName(name) => walk_entity_name(name, v),
+                TsTypeQueryExpr::Import(import) => {
+                    v.visit_import_type(import);
+                    walk_type_args(import.type_args.as_deref(), v);
+                }
+            }
+            walk_type_args(query.type_args.as_deref(), v);
+        }
+        TsType::TsTypeLit(lit) => walk_type_elements(&lit.members, v),
+        TsType::TsArrayType(array) => walk_type(&array.elem_type, v),
+        TsType::TsTupleType(tuple) => {
+            for elem in &tuple.elem_types {
+                walk_type(&elem.ty, v);
+            }
+        }
+        TsType::TsOptionalType(optional) => walk_type(&optional.type_ann, v),
+        TsType::TsRestType(rest) => walk_type(&rest.type_ann, v),
+        TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsUnionType(union)) => {
+            for ty in &union.types {
+                walk_type(ty, v);
+            }
+        }
+        TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsIntersectionType(
+            intersection,
+        )) => {
+            for ty in &intersection.types {
+                walk_type(ty, v);
+            }
+        }
+        TsType::TsConditionalType(conditional) => {
+            walk_type(&conditional.check_type, v);
+            walk_type(&conditional.extends_type, v);
+            walk_type(&conditional.true_type, v);
+            walk_type(&conditional.false_type, v);
+        }
+        TsType::TsInferType(infer) => walk_type_param(&infer.type_param, v),
+        TsType::TsParenthesizedType(paren) => walk_type(&paren.type_ann, v),
+        TsType::TsTypeOperator(operator) => walk_type(&operator.type_ann, v),
+        TsType::TsIndexedAccessType(indexed) => {
+            walk_type(&indexed.obj_type, v);
+            walk_type(&indexed.index_type, v);
+        }
+        TsType::TsMappedType(mapped) => {
+            walk_type_param(&mapped.type_param, v);
+            if let Some(name_type) = &mapped.name_type {
+                walk_type(name_type, v);
+            }
+            if let Some(type_ann) = &mapped.type_ann {
+                walk_type(type_ann, v);
+            }
+        }
+        TsType::TsLitType(lit) => {
+            if let TsLit::Tpl(tpl) = &lit.lit {
+                for ty in &tpl.types {
+                    walk_type(ty, v);
+                }
+            }
+        }
+        TsType::TsTypePredicate(predicate) => walk_type_ann(predicate.type_ann.as_deref(), v),
+        TsType::TsImportType(import) => {
+            v.visit_import_type(import);
+            walk_type_args(import.type_args.as_deref(), v);
+        }
+        TsType::TsKeywordType(_) | TsType::TsThisType(_) => {}
+    }
+}
@@ -0,0 +1,233 @@
+use std::path::Path;
+
+use anyhow::Result;
+
+use super::traits::{
+    collect_import_references, find_config_file, normalize_package_name, package_name_of,
+    read_config_file, read_package_json_field, Plugin, PluginContext, PluginResult,
+};
+
+pub struct BabelPlugin;
+
+impl Plugin for BabelPlugin {
+    fn name(&self) -> &'static str {
+        "babel"
+    }
+
+    fn is_enabled(&self, root: &Path, ctx: &PluginContext) -> bool {
+        find_config_file(root, self.config_patterns()).is_some()
+            || ctx.has_dependency("@babel/core")
+    }
+
+    fn config_patterns(&self) -> &[&str] {
+        &[
+            "babel.config.json",
+            "babel.config.js",
+            "babel.config.cjs",
+            "babel.config.mjs",
+            ".babelrc",
+            ".babelrc.json",
+            ".babelrc.js",
+            ".babelrc.cjs",
+            ".babelrc.mjs",
+        ]
+    }
+
+    fn has_config(&self, root: &Path) -> bool {
+        find_config_file(root, self.config_patterns()).is_some()
+            || read_package_json_field(root, "babel").is_some()
+    }
+
+    fn entry_patterns(&self) -> &[&str] {
+        &[]
+    }
+
+    fn resolve_config(&self, root: &Path, _ctx: &PluginContext) -> Result<PluginResult> {
+        let mut result = PluginResult::new();
+
+        if let Some(config_path) = find_config_file(root, self.config_patterns()) {
+            if let Some(filename) = config_path.file_name().and_then(|f| f.to_str()) {
+                result.add_entry(filename.to_string());
+            }
+        }
+
+        result.dependencies.push("@babel/core".to_string());
+
+        for name in self.config_patterns() {
+            let path = root.join(name);
+            if let Some(config) = read_config_file(&path) {
+                collect_references(&config, &path, &mut result);
+            }
+            collect_import_references(&path, &mut result);
+        }
+
+        if let Some(config) = read_package_json_field(root, "babel") {
+            collect_references(&config, &root.join("package.json"), &mut result);
+        }
+
+        Ok(result)
+    }
+}
+
+fn collect_references(config: &serde_json::Value, source: &Path, result: &mut PluginResult) {
+    for (field, kind) in [("presets", "preset"), ("plugins", "plugin")] {
+        let Some(items) = config.get(field).and_then(|v| v.as_array()) else {
+            continue;
+        };
+
+        for item in items {
+            let (name, options) = match item {
+                serde_json::Value::String(name) => (name.as_str(), None),
+                serde_json::Value::Array(pair) => match pair.first().and_then(|v| v.as_str()) {
+                    Some(name) => (name, pair.get(1)),
+                    None => continue,
+                },
+                _ => continue,
+            };
+
+            let Some(package) = babel_package_name(name, kind) else {
+                continue;
+            };
+
+            if let Some(runtime) = runtime_dependency(&package, options) {
+                result.add_reference(runtime, source, field);
+            }
+            result.add_reference(package, source, field);
+        }
+    }
+
+    if let Some(envs) = config.get("env").and_then(|v| v.as_object()) {
+        for env in envs.values() {
+            collect_references(env, source, result);
+        }
+    }
+
+    if let Some(overrides) = config.get("overrides").and_then(|v| v.as_array()) {
+        for entry in overrides {
+            collect_references(entry, source, result);
+        }
+    }
+}
+
+fn runtime_dependency(package: &str, options: Option<&serde_json::Value>) -> Option<&'static str> {
+    match package {
+        "@babel/preset-env" => {
+            let uses_builtins = options
+                .and_then(|o| o.get("useBuiltIns"))
+                .is_some_and(|v| v.as_bool() != Some(false));
+            uses_builtins.then_some("core-js")
+        }
+        "@babel/plugin-transform-runtime" => match options.and_then(|o| o.get("corejs")) {
+            Some(serde_json::Value::Number(n)) if n.as_u64() == Some(3) => {
+                Some("@babel/runtime-corejs3")
+            }
+            Some(serde_json::Value::Number(n)) if n.as_u64() == Some(2) => {
+                Some("@babel/runtime-corejs2")
+            }
+            _ => Some("@babel/runtime"),
+        },
+        _ => None,
+    }
+}
+
+fn babel_package_name(name: &str, kind: &str) -> Option<String> {
+    if let Some(module) = name.strip_prefix("module:") {
+        return package_name_of(module);
+    }
+
+    if let Some(rest) = name.strip_prefix("@babel/") {
+        let package = rest.split('/').next().unwrap_or(rest);
+        if package.starts_with(&format!("{}-", kind)) {
+            return Some(format!("@babel/{}", package));
+        }
+        return Some(format!("@babel/{}-{}", kind, package));
+    }
+
+    normalize_package_name(name, &format!("babel-{}", kind))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_babel_plugin_enabled_with_config() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(temp.path().join(".babelrc"), "{}").unwrap();
+
+        let plugin = BabelPlugin;
+        assert!(plugin.is_enabled(temp.path(), &PluginContext::default()));
+    }
+
+    #[test]
+    fn test_babel_plugin_resolves_presets_and_plugins() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("babel.config.json"),
+            r#"{
+                "presets": [["@babel/env", {"useBuiltIns": "usage"}], "@babel/preset-react"],
+                "plugins": ["@babel/plugin-transform-runtime", "macros"],
+                "env": {"test": {"plugins": ["module:babel-jest-hoist/plugin"]}}
+            }"#,
+        )
+        .unwrap();
+
+        let plugin = BabelPlugin;
+        let result = plugin
+            .resolve_config(temp.path(), &PluginContext::default())
+            .unwrap();
+        let names: Vec<&str> = result.references.iter().map(|r| r.name.as_str()).collect();
+
+        assert_eq!(
+            names,
+            vec![
+                "core-js",
+                "@babel/preset-env",
+                "@babel/preset-react",
+                "@babel/runtime",
+                "@babel/plugin-transform-runtime",
+                "babel-plugin-macros",
+                "babel-jest-hoist",
+            ]
+        );
+        assert!(result.dependencies.contains(&"@babel/core".to_string()));
+    }
+
+    #[test]
+    fn test_babel_plugin_reads_js_config() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("babel.config.js"),
+            "module.exports = { presets: ['@babel/preset-typescript'], plugins: ['styled-components'] };",
+        )
+        .unwrap();
+
+        let result = BabelPlugin
+            .resolve_config(temp.path(), &PluginContext::default())
+            .unwrap();
+        let names: Vec<&str> = result.references.iter().map(|r| r.name.as_str()).collect();
+
+        assert_eq!(
+            names,
+            vec!["@babel/preset-typescript", "babel-plugin-styled-components"]
+        );
+    }
+
+    #[test]
+    fn test_babel_package_name() {
+        assert_eq!(
+            babel_package_name("@babel/env", "preset"),
+            Some("@babel/preset-env".to_string())
+        );
+        assert_eq!(
+            babel_package_name("@emotion", "plugin"),
+            Some("@emotion/babel-plugin".to_string())
+        );
+        assert_eq!(
+            babel_package_name("@org/babel-preset-base", "preset"),
+            Some("@org/babel-preset-base".to_string())
+        );
+        assert_eq!(babel_package_name("./local-plugin", "plugin"), None);
+    }
+}
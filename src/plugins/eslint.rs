@@ -2,7 +2,10 @@ use std::path::Path;
 
 use anyhow::Result;
 
-use super::traits::{find_config_file, Plugin, PluginContext, PluginResult};
+use super::traits::{
+    collect_import_references, find_config_file, normalize_package_name, package_name_of,
+    read_config_file, read_package_json_field, string_values, Plugin, PluginContext, PluginResult,
+};
 
 pub struct EslintPlugin;
 
@@ -33,6 +36,11 @@ impl Plugin for EslintPlugin {
         ]
     }
 
+    fn has_config(&self, root: &Path) -> bool {
+        find_config_file(root, self.config_patterns()).is_some()
+            || read_package_json_field(root, "eslintConfig").is_some()
+    }
+
     fn entry_patterns(&self) -> &[&str] {
         &[]
     }
@@ -50,10 +58,69 @@ impl Plugin for EslintPlugin {
 
         result.dependencies.push("eslint".to_string());
 
+        for name in self.config_patterns() {
+            let path = root.join(name);
+            match read_config_file(&path) {
+                Some(serde_json::Value::Array(configs)) => {
+                    for config in &configs {
+                        collect_references(config, &path, &mut result);
+                    }
+                }
+                Some(config) => collect_references(&config, &path, &mut result),
+                None => {}
+            }
+            collect_import_references(&path, &mut result);
+        }
+
+        if let Some(config) = read_package_json_field(root, "eslintConfig") {
+            collect_references(&config, &root.join("package.json"), &mut result);
+        }
+
         Ok(result)
     }
 }
 
+fn collect_references(config: &serde_json::Value, source: &Path, result: &mut PluginResult) {
+    for name in string_values(config.get("extends")) {
+        if let Some(package) = resolve_extends(&name) {
+            result.add_reference(package, source, "extends");
+        }
+    }
+
+    for name in string_values(config.get("plugins")) {
+        if let Some(package) = normalize_package_name(&name, "eslint-plugin") {
+            result.add_reference(package, source, "plugins");
+        }
+    }
+
+    if let Some(package) = config
+        .get("parser")
+        .and_then(|v| v.as_str())
+        .and_then(package_name_of)
+    {
+        result.add_reference(package, source, "parser");
+    }
+
+    if let Some(overrides) = config.get("overrides").and_then(|v| v.as_array()) {
+        for entry in overrides {
+            collect_references(entry, source, result);
+        }
+    }
+}
+
+fn resolve_extends(name: &str) -> Option<String> {
+    if name.starts_with("eslint:") {
+        return None;
+    }
+
+    if let Some(plugin_config) = name.strip_prefix("plugin:") {
+        let (plugin, _) = plugin_config.rsplit_once('/')?;
+        return normalize_package_name(plugin, "eslint-plugin");
+    }
+
+    normalize_package_name(name, "eslint-config")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -124,6 +191,126 @@ mod tests {
         assert!(result.dependencies.contains(&"eslint".to_string()));
     }
 
+    #[test]
+    fn test_eslint_plugin_resolves_legacy_references() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join(".eslintrc.json"),
+            r#"{
+                // comments are allowed
+                "extends": ["eslint:recommended", "airbnb", "plugin:react/recommended", "./local.js"],
+                "plugins": ["@typescript-eslint", "eslint-plugin-import"],
+                "parser": "@typescript-eslint/parser",
+                "overrides": [{ "files": ["*.test.ts"], "extends": ["plugin:jest/recommended"] }]
+            }"#,
+        )
+        .unwrap();
+
+        let plugin = EslintPlugin;
+        let result = plugin
+            .resolve_config(temp.path(), &PluginContext::default())
+            .unwrap();
+        let names: Vec<&str> = result.references.iter().map(|r| r.name.as_str()).collect();
+
+        assert_eq!(
+            names,
+            vec![
+                "eslint-config-airbnb",
+                "eslint-plugin-react",
+                "@typescript-eslint/eslint-plugin",
+                "eslint-plugin-import",
+                "@typescript-eslint/parser",
+                "eslint-plugin-jest",
+            ]
+        );
+        assert_eq!(result.references[0].field, "extends");
+    }
+
+    #[test]
+    fn test_eslint_plugin_reads_package_json_config() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("package.json"),
+            r#"{"eslintConfig": {"extends": "react-app"}}"#,
+        )
+        .unwrap();
+
+        let plugin = EslintPlugin;
+        let result = plugin
+            .resolve_config(temp.path(), &PluginContext::default())
+            .unwrap();
+
+        assert_eq!(result.references.len(), 1);
+        assert_eq!(result.references[0].name, "eslint-config-react-app");
+        assert_eq!(result.references[0].source, temp.path().join("package.json"));
+    }
+
+    #[test]
+    fn test_eslint_plugin_reads_yaml_and_js_configs() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join(".eslintrc.yml"),
+            "extends:\n  - plugin:vue/recommended\nplugins: [unicorn]\n",
+        )
+        .unwrap();
+        std::fs::write(
+            temp.path().join(".eslintrc.cjs"),
+            "module.exports = { extends: ['prettier'], parser: 'vue-eslint-parser' };",
+        )
+        .unwrap();
+
+        let result = EslintPlugin
+            .resolve_config(temp.path(), &PluginContext::default())
+            .unwrap();
+        let names: HashSet<&str> = result.references.iter().map(|r| r.name.as_str()).collect();
+
+        assert_eq!(
+            names,
+            HashSet::from([
+                "eslint-plugin-vue",
+                "eslint-plugin-unicorn",
+                "eslint-config-prettier",
+                "vue-eslint-parser",
+            ])
+        );
+    }
+
+    #[test]
+    fn test_eslint_plugin_reads_flat_config() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("eslint.config.js"),
+            r#"
+            import js from '@eslint/js';
+            import react from 'eslint-plugin-react';
+            import tseslint from 'typescript-eslint';
+            import local from './rules/local.js';
+
+            export default [
+                js.configs.recommended,
+                { plugins: { react, local }, rules: {} },
+                { extends: ['airbnb'] },
+            ];
+            "#,
+        )
+        .unwrap();
+
+        let result = EslintPlugin
+            .resolve_config(temp.path(), &PluginContext::default())
+            .unwrap();
+        let names: HashSet<&str> = result.references.iter().map(|r| r.name.as_str()).collect();
+
+        assert_eq!(
+            names,
+            HashSet::from([
+                "@eslint/js",
+                "eslint-plugin-react",
+                "typescript-eslint",
+                "eslint-config-airbnb",
+            ])
+        );
+    }
+
     #[test]
     fn test_eslint_plugin_config_patterns() {
         let plugin = EslintPlugin;
@@ -0,0 +1,472 @@
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use serde_json::{Map, Number, Value};
+use swc_ecma_ast::{
+    ArrowExpr, AssignTarget, BlockStmtOrExpr, Callee, Decl, Expr, ImportSpecifier, Lit, MemberExpr,
+    MemberProp, MetaPropKind, Module, ModuleDecl, ModuleExportName, ModuleItem, Pat, Prop,
+    PropName, PropOrSpread, SimpleAssignTarget, Stmt, Str,
+};
+
+use crate::parser::parse_module_ast;
+
+const REGEX_KEY: &str = "$regex";
+
+/// Statically evaluates the exported value of a JS/TS config file.
+pub fn evaluate_config_file(path: &Path) -> Option<Value> {
+    let source = std::fs::read_to_string(path).ok()?;
+    evaluate_config_source(&source, path)
+}
+
+pub fn evaluate_config_source(source: &str, path: &Path) -> Option<Value> {
+    let module = parse_module_ast(source, path).ok()?;
+    let evaluator = Evaluator::new(&module, path, path.parent()?);
+    evaluator.eval_config_function(evaluator.exported(&module)?)
+}
+
+pub fn regex_source(value: &Value) -> Option<&str> {
+    let object = value.as_object()?;
+    (object.len() == 1).then(|| object.get(REGEX_KEY)?.as_str())?
+}
+
+struct Evaluator<'a> {
+    path: &'a Path,
+    dir: &'a Path,
+    bindings: HashMap<String, &'a Expr>,
+    /// Bindings being evaluated, to stop on cycles.
+    resolving: RefCell<HashSet<String>>,
+    path_modules: Vec<String>,
+    path_functions: HashMap<String, String>,
+}
+
+impl<'a> Evaluator<'a> {
+    fn new(module: &'a Module, path: &'a Path, dir: &'a Path) -> Self {
+        let mut evaluator = Self {
+            path,
+            dir,
+            bindings: HashMap::new(),
+            resolving: RefCell::new(HashSet::new()),
+            path_modules: Vec::new(),
+            path_functions: HashMap::new(),
+        };
+
+        for item in &module.body {
+            match item {
+                ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => {
+                    let source = string(&import.src);
+                    if matches!(source.as_str(), "path" | "node:path") {
+                        evaluator.record_path_import(&import.specifiers);
+                    }
+                }
+                ModuleItem::Stmt(Stmt::Decl(Decl::Var(var)))
+                | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(swc_ecma_ast::ExportDecl {
+                    decl: Decl::Var(var),
+                    ..
+                })) => {
+                    for decl in &var.decls {
+                        let (Pat::Ident(name), Some(init)) = (&decl.name, &decl.init) else {
+                            continue;
+                        };
+                        let name = name.id.sym.to_string();
+                        if is_require(init, &["path", "node:path"]) {
+                            evaluator.path_modules.push(name);
+                        } else {
+                            evaluator.bindings.insert(name, init);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        evaluator
+    }
+
+    fn record_path_import(&mut self, specifiers: &[ImportSpecifier]) {
+        for specifier in specifiers {
+            match specifier {
+                ImportSpecifier::Default(default) => {
+                    self.path_modules.push(default.local.sym.to_string())
+                }
+                ImportSpecifier::Namespace(namespace) => {
+                    self.path_modules.push(namespace.local.sym.to_string())
+                }
+                ImportSpecifier::Named(named) => {
+                    let imported = match &named.imported {
+                        Some(ModuleExportName::Ident(ident)) => ident.sym.to_string(),
+                        Some(ModuleExportName::Str(s)) => string(s),
+                        None => named.local.sym.to_string(),
+                    };
+                    self.path_functions
+                        .insert(named.local.sym.to_string(), imported);
+                }
+            }
+        }
+    }
+
+    fn exported(&self, module: &'a Module) -> Option<&'a Expr> {
+        module.body.iter().rev().find_map(|item| match item {
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(export)) => Some(&*export.expr),
+            ModuleItem::Stmt(Stmt::Expr(stmt)) => match &*stmt.expr {
+                Expr::Assign(assign) if is_module_exports(&assign.left) => Some(&*assign.right),
+                _ => None,
+            },
+            _ => None,
+        })
+    }
+
+    fn eval(&self, expr: &Expr) -> Option<Value> {
+        match expr {
+            Expr::Lit(Lit::Str(s)) => Some(Value::String(string(s))),
+            Expr::Lit(Lit::Bool(b)) => Some(Value::Bool(b.value)),
+            Expr::Lit(Lit::Null(_)) => Some(Value::Null),
+            Expr::Lit(Lit::Num(n)) => number(n.value).map(Value::Number),
+            Expr::Lit(Lit::Regex(regex)) => {
+                let mut object = Map::new();
+                object.insert(REGEX_KEY.to_string(), Value::String(regex.exp.to_string()));
+                Some(Value::Object(object))
+            }
+            Expr::Tpl(tpl) if tpl.exprs.is_empty() => tpl
+                .quasis
+                .first()
+                .and_then(|quasi| quasi.cooked.as_ref())
+                .map(|cooked| Value::String(cooked.as_str().unwrap_or_default().to_string())),
+            Expr::Array(array) => {
+                let mut items = Vec::new();
+                for elem in array.elems.iter().flatten() {
+                    match (elem.spread, self.eval(&elem.expr)) {
+                        (Some(_), Some(Value::Array(spread))) => items.extend(spread),
+                        (None, Some(value)) => items.push(value),
+                        _ => {}
+                    }
+                }
+                Some(Value::Array(items))
+            }
+            Expr::Object(object) => {
+                let mut map = Map::new();
+                for prop in &object.props {
+                    let prop = match prop {
+                        PropOrSpread::Prop(prop) => prop,
+                        PropOrSpread::Spread(spread) => {
+                            if let Some(Value::Object(spread)) = self.eval(&spread.expr) {
+                                map.extend(spread);
+                            }
+                            continue;
+                        }
+                    };
+                    let (key, value) = match &**prop {
+                        Prop::KeyValue(kv) => (prop_name(&kv.key), self.eval(&kv.value)),
+                        Prop::Shorthand(ident) => (
+                            Some(ident.sym.to_string()),
+                            self.eval_binding(&ident.sym),
+                        ),
+                        _ => continue,
+                    };
+                    if let (Some(key), Some(value)) = (key, value) {
+                        map.insert(key, value);
+                    }
+                }
+                Some(Value::Object(map))
+            }
+            Expr::Ident(ident) if ident.sym == "__dirname" => Some(self.dir_value()),
+            Expr::Ident(ident) => self.eval_binding(&ident.sym),
+            Expr::Member(member) if is_import_meta(member, "dirname") => Some(self.dir_value()),
+            Expr::Member(member) if is_import_meta(member, "url") => Some(Value::String(
+                format!("file://{}", self.path.to_string_lossy()),
+            )),
+            Expr::New(new) => {
+                let Expr::Ident(callee) = &*new.callee else {
+                    return None;
+                };
+                let args = new.args.as_ref()?;
+                let url = self.eval(&args.first()?.expr)?;
+                let base = self.eval(&args.get(1)?.expr)?;
+                let base = Path::new(base.as_str()?.strip_prefix("file://")?);
+                let url = base.parent()?.join(url.as_str()?);
+                (callee.sym == "URL").then(|| {
+                    let url = crate::resolver::normalize_path(&url);
+                    Value::String(format!("file://{}", url.to_string_lossy()))
+                })
+            }
+            Expr::Member(member) => {
+                let object = self.eval(&member.obj)?;
+                match &member.prop {
+                    MemberProp::Ident(prop) => object.get(prop.sym.as_str()).cloned(),
+                    MemberProp::Computed(computed) => match self.eval(&computed.expr)? {
+                        Value::String(key) => object.get(key).cloned(),
+                        Value::Number(index) => object.get(index.as_u64()? as usize).cloned(),
+                        _ => None,
+                    },
+                    _ => None,
+                }
+            }
+            Expr::Paren(paren) => self.eval(&paren.expr),
+            Expr::TsAs(ts) => self.eval(&ts.expr),
+            Expr::TsSatisfies(ts) => self.eval(&ts.expr),
+            Expr::TsConstAssertion(ts) => self.eval(&ts.expr),
+            Expr::Call(call) => {
+                let Callee::Expr(callee) = &call.callee else {
+                    return None;
+                };
+                let args: Vec<&Expr> = call.args.iter().map(|arg| &*arg.expr).collect();
+
+                if let Some(function) = self.path_function(callee) {
+                    return self.eval_path_call(&function, &args);
+                }
+
+                match &**callee {
+                    Expr::Ident(ident) if ident.sym.starts_with("define") => {
+                        self.eval_config_function(args.first()?)
+                    }
+                    Expr::Ident(ident) if ident.sym == "fileURLToPath" => {
+                        let url = self.eval(args.first()?)?;
+                        let path = url.as_str()?.strip_prefix("file://")?;
+                        Some(Value::String(path.to_string()))
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn eval_config_function(&self, expr: &Expr) -> Option<Value> {
+        match expr {
+            Expr::Arrow(arrow) => self.eval(arrow_result(arrow)?),
+            Expr::Fn(function) => {
+                let body = function.function.body.as_ref()?;
+                self.eval(returned(&body.stmts)?)
+            }
+            other => self.eval(other),
+        }
+    }
+
+    fn eval_path_call(&self, function: &str, args: &[&Expr]) -> Option<Value> {
+        let parts = args
+            .iter()
+            .map(|arg| self.eval(arg)?.as_str().map(PathBuf::from))
+            .collect::<Option<Vec<PathBuf>>>()?;
+
+        if function == "dirname" {
+            let dir = parts.first()?.parent()?;
+            return Some(Value::String(dir.to_string_lossy().into_owned()));
+        }
+
+        let mut joined = PathBuf::new();
+        for part in parts {
+            if function == "join" && !joined.as_os_str().is_empty() {
+                joined.push(part.strip_prefix("/").unwrap_or(&part));
+            } else {
+                joined.push(part);
+            }
+        }
+        if function == "resolve" {
+            joined = self.dir.join(joined);
+        }
+
+        let joined = crate::resolver::normalize_path(&joined);
+        Some(Value::String(joined.to_string_lossy().into_owned()))
+    }
+
+    fn path_function(&self, callee: &Expr) -> Option<String> {
+        let function = match callee {
+            Expr::Ident(ident) => self.path_functions.get(ident.sym.as_str())?.clone(),
+            Expr::Member(member) => {
+                let Expr::Ident(object) = &*member.obj else {
+                    return None;
+                };
+                let MemberProp::Ident(prop) = &member.prop else {
+                    return None;
+                };
+                if !self.path_modules.iter().any(|name| object.sym == *name) {
+                    return None;
+                }
+                prop.sym.to_string()
+            }
+            _ => return None,
+        };
+        matches!(function.as_str(), "resolve" | "join" | "dirname").then_some(function)
+    }
+
+    fn eval_binding(&self, name: &str) -> Option<Value> {
+        let init = self.bindings.get(name).copied()?;
+        if !self.resolving.borrow_mut().insert(name.to_string()) {
+            return None;
+        }
+        let value = self.eval(init);
+        self.resolving.borrow_mut().remove(name);
+        value
+    }
+
+    fn dir_value(&self) -> Value {
+        Value::String(self.dir.to_string_lossy().into_owned())
+    }
+}
+
+fn arrow_result(arrow: &ArrowExpr) -> Option<&Expr> {
+    match &*arrow.body {
+        BlockStmtOrExpr::Expr(expr) => Some(expr),
+        BlockStmtOrExpr::BlockStmt(block) => returned(&block.stmts),
+    }
+}
+
+fn returned(stmts: &[Stmt]) -> Option<&Expr> {
+    stmts.iter().find_map(|stmt| match stmt {
+        Stmt::Return(ret) => ret.arg.as_deref(),
+        _ => None,
+    })
+}
+
+fn is_module_exports(target: &AssignTarget) -> bool {
+    let AssignTarget::Simple(SimpleAssignTarget::Member(member)) = target else {
+        return false;
+    };
+    matches!(
+        (&*member.obj, &member.prop),
+        (Expr::Ident(object), MemberProp::Ident(prop)) if object.sym == "module" && prop.sym == "exports"
+    )
+}
+
+fn is_import_meta(member: &MemberExpr, property: &str) -> bool {
+    matches!(
+        (&*member.obj, &member.prop),
+        (Expr::MetaProp(meta), MemberProp::Ident(prop))
+            if meta.kind == MetaPropKind::ImportMeta && prop.sym == property
+    )
+}
+
+fn is_require(expr: &Expr, modules: &[&str]) -> bool {
+    let Expr::Call(call) = expr else {
+        return false;
+    };
+    let Callee::Expr(callee) = &call.callee else {
+        return false;
+    };
+    matches!(&**callee, Expr::Ident(ident) if ident.sym == "require")
+        && matches!(
+            call.args.first().map(|arg| &*arg.expr),
+            Some(Expr::Lit(Lit::Str(s))) if modules.contains(&string(s).as_str())
+        )
+}
+
+fn prop_name(key: &PropName) -> Option<String> {
+    match key {
+        PropName::Ident(ident) => Some(ident.sym.to_string()),
+        PropName::Str(s) => Some(string(s)),
+        PropName::Num(n) => Some(n.value.to_string()),
+        _ => None,
+    }
+}
+
+fn string(s: &Str) -> String {
+    s.value.as_str().unwrap_or_default().to_string()
+}
+
+fn number(value: f64) -> Option<Number> {
+    const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_991.0;
+    if value.fract() == 0.0 && value.abs() <= MAX_SAFE_INTEGER {
+        Some(Number::from(value as i64))
+    } else {
+        Number::from_f64(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn eval(source: &str) -> Value {
+        evaluate_config_source(source, Path::new("/app/vite.config.ts")).unwrap()
+    }
+
+    #[test]
+    fn test_evaluate_export_forms() {
+        assert_eq!(eval("export default { a: 1 }"), json!({ "a": 1 }));
+        assert_eq!(
+            eval("module.exports = { 'b': ['x', `y`], c: true }"),
+            json!({ "b": ["x", "y"], "c": true })
+        );
+        assert_eq!(
+            eval("import { defineConfig } from 'vite';\nexport default defineConfig({ base: '/' });"),
+            json!({ "base": "/" })
+        );
+        assert_eq!(
+            eval("export default defineConfig(({ mode }) => { return { mode: 'x' } });"),
+            json!({ "mode": "x" })
+        );
+        assert_eq!(
+            eval("const config = { root: 'src' } satisfies Config;\nexport default config;"),
+            json!({ "root": "src" })
+        );
+        assert_eq!(
+            eval("module.exports = (env) => ({ mode: 'production' });"),
+            json!({ "mode": "production" })
+        );
+    }
+
+    #[test]
+    fn test_evaluate_paths_and_regex() {
+        let value = eval(
+            r#"
+            import path from 'node:path';
+            import { resolve } from 'path';
+            export default {
+                a: path.resolve(__dirname, './src'),
+                b: resolve(import.meta.dirname, 'lib', '..', 'utils'),
+                c: path.join('a', 'b'),
+                d: /^~\//,
+                e: someCall(),
+            };
+            "#,
+        );
+        assert_eq!(value["a"], "/app/src");
+        assert_eq!(value["b"], "/app/utils");
+        assert_eq!(value["c"], "a/b");
+        assert_eq!(regex_source(&value["d"]), Some(r"^~\/"));
+        assert!(value.get("e").is_none());
+    }
+
+    #[test]
+    fn test_evaluate_spreads_and_urls() {
+        let value = eval(
+            r#"
+            import { fileURLToPath, URL } from 'node:url';
+            import path from 'path';
+
+            const dir = path.dirname(fileURLToPath(import.meta.url));
+            const shared = { a: 1, b: ['x'] };
+            const extra = ['y', /* 'z', */ 'w'];
+
+            export default {
+                ...shared,
+                b: [...shared.b, ...extra],
+                first: shared.b[0],
+                src: fileURLToPath(new URL('./src', import.meta.url)),
+                lib: path.join(dir, 'lib'),
+            };
+            "#,
+        );
+        assert_eq!(value["a"], 1);
+        assert_eq!(value["first"], "x");
+        assert_eq!(value["b"], json!(["x", "y", "w"]));
+        assert_eq!(value["src"], "/app/src");
+        assert_eq!(value["lib"], "/app/lib");
+    }
+
+    #[test]
+    fn test_evaluate_cyclic_bindings() {
+        let value = eval(
+            r#"
+            const a = { next: b };
+            const b = { next: a };
+            const self = self;
+            export default { a, self, ok: 'yes' };
+            "#,
+        );
+        assert_eq!(value["a"], json!({ "next": {} }));
+        assert!(value.get("self").is_none());
+        assert_eq!(value["ok"], "yes");
+    }
+}
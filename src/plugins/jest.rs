@@ -1,8 +1,14 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use anyhow::Result;
+use regex::Regex;
 
-use super::traits::{find_config_file, Plugin, PluginContext, PluginResult};
+use super::evaluate::evaluate_config_file;
+use super::traits::{
+    alias_target, find_config_file, package_name_of, read_json_config, read_package_json_field,
+    string_values, Plugin, PluginContext, PluginResult,
+};
+use crate::resolver::{normalize_path, AliasFind, BundlerAlias};
 
 pub struct JestPlugin;
 
@@ -26,6 +32,11 @@ impl Plugin for JestPlugin {
         ]
     }
 
+    fn has_config(&self, root: &Path) -> bool {
+        find_config_file(root, self.config_patterns()).is_some()
+            || read_package_json_field(root, "jest").is_some()
+    }
+
     fn entry_patterns(&self) -> &[&str] {
         &[
             "**/*.test.ts",
@@ -54,16 +65,22 @@ impl Plugin for JestPlugin {
             result.add_entry(pattern.to_string());
         }
 
+        let mut configs = Vec::new();
         if let Some(config_path) = find_config_file(root, self.config_patterns()) {
             if let Some(filename) = config_path.file_name().and_then(|f| f.to_str()) {
                 result.add_entry(filename.to_string());
             }
 
-            if let Ok(content) = std::fs::read_to_string(&config_path) {
-                if let Some(setup_file) = extract_setup_files(&content) {
-                    result.add_entry(setup_file);
-                }
-            }
+            let config = if config_path.extension().is_some_and(|ext| ext == "json") {
+                read_json_config(&config_path)
+            } else {
+                evaluate_config_file(&config_path)
+            };
+            configs.extend(config.map(|config| (config, config_path)));
+        }
+
+        if let Some(config) = read_package_json_field(root, "jest") {
+            configs.push((config, root.join("package.json")));
         }
 
         result.add_ignore("coverage/**".to_string());
@@ -71,35 +88,148 @@ impl Plugin for JestPlugin {
 
         result.dependencies.push("jest".to_string());
 
+        let test_files: Vec<String> = self.entry_patterns().iter().map(|p| p.to_string()).collect();
+        for (config, source) in &configs {
+            collect_references(config, source, &mut result);
+            for setup_file in extract_setup_files(config, source, root) {
+                result.add_entry(setup_file);
+            }
+            collect_module_name_mapper(config, source, root, &test_files, &mut result);
+        }
+
         Ok(result)
     }
 }
 
-fn extract_setup_files(content: &str) -> Option<String> {
-    if content.contains("setupFilesAfterEnv") {
-        if let Some(start) = content.find("setupFilesAfterEnv") {
-            let rest = &content[start..];
-            if let Some(bracket_start) = rest.find('[') {
-                let after_bracket = &rest[bracket_start + 1..];
-                if let Some(quote_start) = after_bracket.find(['\'', '"']) {
-                    let quote_char = after_bracket.chars().nth(quote_start)?;
-                    let after_quote = &after_bracket[quote_start + 1..];
-                    if let Some(quote_end) = after_quote.find(quote_char) {
-                        return Some(after_quote[..quote_end].to_string());
-                    }
-                }
+fn collect_references(config: &serde_json::Value, source: &Path, result: &mut PluginResult) {
+    if let Some(preset) = config
+        .get("preset")
+        .and_then(|v| v.as_str())
+        .and_then(package_name_of)
+    {
+        result.add_reference(preset, source, "preset");
+    }
+
+    if let Some(environment) = config.get("testEnvironment").and_then(|v| v.as_str()) {
+        let package = match environment {
+            "node" => None,
+            "jsdom" => Some("jest-environment-jsdom".to_string()),
+            name if name.starts_with("jest-environment-") || name.starts_with('@') => {
+                package_name_of(name)
             }
+            name => package_name_of(&format!("jest-environment-{}", name)),
+        };
+        if let Some(package) = package {
+            result.add_reference(package, source, "testEnvironment");
         }
     }
-    None
+
+    if let Some(transform) = config.get("transform").and_then(|v| v.as_object()) {
+        for value in transform.values() {
+            let target = match value {
+                serde_json::Value::Array(pair) => pair.first().and_then(|v| v.as_str()),
+                other => other.as_str(),
+            };
+            if let Some(package) = target.and_then(package_name_of) {
+                result.add_reference(package, source, "transform");
+            }
+        }
+    }
+
+    for field in ["setupFiles", "setupFilesAfterEnv", "reporters", "snapshotSerializers"] {
+        let Some(items) = config.get(field).and_then(|v| v.as_array()) else {
+            continue;
+        };
+        for item in items {
+            let target = match item {
+                serde_json::Value::Array(pair) => pair.first().and_then(|v| v.as_str()),
+                other => other.as_str(),
+            };
+            if let Some(package) = target
+                .filter(|t| !t.starts_with("<rootDir>") && *t != "default")
+                .and_then(package_name_of)
+            {
+                result.add_reference(package, source, field);
+            }
+        }
+    }
+}
+
+fn collect_module_name_mapper(
+    config: &serde_json::Value,
+    source: &Path,
+    root: &Path,
+    test_files: &[String],
+    result: &mut PluginResult,
+) {
+    let Some(mapper) = config.get("moduleNameMapper").and_then(|v| v.as_object()) else {
+        return;
+    };
+    let config_dir = source.parent().unwrap_or(root);
+    let root_dir = root_dir(config, source);
+    let root_dir = root_dir.to_string_lossy();
+
+    for (pattern, value) in mapper {
+        let Ok(regex) = Regex::new(pattern) else {
+            continue;
+        };
+        let targets: Vec<String> = string_values(Some(value))
+            .iter()
+            .map(|target| alias_target(&target.replace("<rootDir>", &root_dir), config_dir))
+            .collect();
+        if targets.is_empty() {
+            continue;
+        }
+
+        for package in targets.iter().filter_map(|t| package_name_of(t)) {
+            result.add_reference(package, source, "moduleNameMapper");
+        }
+        result.add_alias(
+            BundlerAlias::new(AliasFind::Mapper(regex), targets).with_files(root, test_files),
+        );
+    }
+}
+
+/// Jest's `rootDir`, relative to the config file's directory.
+fn root_dir(config: &serde_json::Value, source: &Path) -> PathBuf {
+    let config_dir = source.parent().unwrap_or(source);
+    match config.get("rootDir").and_then(|v| v.as_str()) {
+        Some(dir) => normalize_path(&config_dir.join(dir)),
+        None => config_dir.to_path_buf(),
+    }
+}
+
+fn extract_setup_files(config: &serde_json::Value, source: &Path, root: &Path) -> Vec<String> {
+    let root_dir = root_dir(config, source);
+    ["setupFiles", "setupFilesAfterEnv"]
+        .iter()
+        .flat_map(|field| string_values(config.get(*field)))
+        .filter_map(|file| {
+            let relative = match file.strip_prefix("<rootDir>/") {
+                Some(relative) => relative,
+                None if file.starts_with('.') => file.as_str(),
+                None => return None,
+            };
+            let path = normalize_path(&root_dir.join(relative));
+            let path = path.strip_prefix(root).ok()?;
+            Some(path.to_string_lossy().into_owned())
+        })
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::plugins::evaluate::evaluate_config_source;
     use tempfile::TempDir;
     use std::collections::HashSet;
 
+    fn setup_files(source: &str) -> Vec<String> {
+        let source_path = Path::new("/app/jest.config.ts");
+        let config = evaluate_config_source(source, source_path).unwrap();
+        extract_setup_files(&config, source_path, Path::new("/app"))
+    }
+
     #[test]
     fn test_jest_plugin_name() {
         let plugin = JestPlugin;
@@ -170,8 +300,8 @@ mod tests {
                 setupFilesAfterEnv: ['./jest.setup.ts'],
             };
         "#;
-        let result = extract_setup_files(content);
-        assert_eq!(result, Some("./jest.setup.ts".to_string()));
+        let result = setup_files(content);
+        assert_eq!(result, vec!["jest.setup.ts".to_string()]);
     }
 
     #[test]
@@ -181,14 +311,105 @@ mod tests {
                 setupFilesAfterEnv: ["./setup.js"],
             };
         "#;
-        let result = extract_setup_files(content);
-        assert_eq!(result, Some("./setup.js".to_string()));
+        let result = setup_files(content);
+        assert_eq!(result, vec!["setup.js".to_string()]);
     }
 
     #[test]
     fn test_extract_setup_files_none() {
         let content = r#"module.exports = {}"#;
-        let result = extract_setup_files(content);
-        assert!(result.is_none());
+        let result = setup_files(content);
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn test_jest_module_name_mapper() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        std::fs::write(
+            root.join("jest.config.ts"),
+            r#"
+            export default {
+                rootDir: './app',
+                moduleNameMapper: {
+                    '^@/(.*)$': '<rootDir>/src/$1',
+                    '\\.(css|less)$': 'identity-obj-proxy',
+                },
+            };
+            "#,
+        )
+        .unwrap();
+
+        let result = JestPlugin
+            .resolve_config(root, &PluginContext::default())
+            .unwrap();
+        assert_eq!(result.aliases.len(), 2);
+        assert!(result
+            .references
+            .iter()
+            .any(|r| r.name == "identity-obj-proxy" && r.field == "moduleNameMapper"));
+
+        let test_file = root.join("src/app.test.ts");
+        let alias = result
+            .aliases
+            .iter()
+            .find(|a| a.rewrite("@/util").is_some())
+            .unwrap();
+        assert!(alias.applies_to(&test_file));
+        assert!(!alias.applies_to(&root.join("src/app.ts")));
+        let util = root.join("app/src/util").to_string_lossy().into_owned();
+        assert_eq!(alias.rewrite("@/util"), Some(vec![util]));
+    }
+
+    #[test]
+    fn test_extract_setup_files_all_entries() {
+        let content = r#"
+            /** @type {import('jest').Config} */
+            const config = {
+                // setupFilesAfterEnv: ['./commented.ts'],
+                setupFiles: ['<rootDir>/test/polyfills.ts'],
+                setupFilesAfterEnv: [
+                    './jest.setup.ts',
+                    '@testing-library/jest-dom',
+                    './test/[id]/matchers.ts',
+                ],
+            };
+            module.exports = config;
+        "#;
+        assert_eq!(
+            setup_files(content),
+            vec!["test/polyfills.ts", "jest.setup.ts", "test/[id]/matchers.ts"]
+        );
+    }
+
+    #[test]
+    fn test_extract_setup_files_with_root_dir() {
+        let content = r#"
+            module.exports = {
+                rootDir: './app',
+                setupFiles: ['<rootDir>/test/polyfills.ts'],
+                setupFilesAfterEnv: ['./jest.setup.ts', '<rootDir>/../../outside.ts'],
+            };
+        "#;
+        assert_eq!(
+            setup_files(content),
+            vec!["app/test/polyfills.ts", "app/jest.setup.ts"]
+        );
+    }
+
+    #[test]
+    fn test_jest_plugin_credits_js_config_references() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("jest.config.js"),
+            "module.exports = { preset: 'ts-jest', setupFilesAfterEnv: ['@testing-library/jest-dom'] };",
+        )
+        .unwrap();
+
+        let result = JestPlugin
+            .resolve_config(temp.path(), &PluginContext::default())
+            .unwrap();
+        let names: Vec<&str> = result.references.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, vec!["ts-jest", "@testing-library/jest-dom"]);
     }
 }
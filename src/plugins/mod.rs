@@ -1,4 +1,5 @@
 mod traits;
+mod evaluate;
 mod next;
 mod vite;
 mod jest;
@@ -7,12 +8,19 @@ mod eslint;
 mod typescript;
 mod tailwind;
 mod storybook;
+mod babel;
+mod prettier;
+mod webpack;
 
-pub use traits::{Plugin, PluginContext, PluginResult};
+pub use traits::{DependencyReference, Plugin, PluginContext, PluginResult};
 
 use std::path::Path;
 use std::sync::Arc;
 
+use crate::config::{PluginsConfig, ResolvedConfig};
+
+pub type PluginResults = Vec<(Arc<dyn Plugin>, PluginResult)>;
+
 pub fn get_builtin_plugins() -> Vec<Arc<dyn Plugin>> {
     vec![
         Arc::new(typescript::TypeScriptPlugin),
@@ -23,6 +31,9 @@ pub fn get_builtin_plugins() -> Vec<Arc<dyn Plugin>> {
         Arc::new(eslint::EslintPlugin),
         Arc::new(tailwind::TailwindPlugin),
         Arc::new(storybook::StorybookPlugin),
+        Arc::new(babel::BabelPlugin),
+        Arc::new(prettier::PrettierPlugin),
+        Arc::new(webpack::WebpackPlugin),
     ]
 }
 
@@ -33,6 +44,31 @@ pub fn detect_plugins(root: &Path, ctx: &PluginContext) -> Vec<Arc<dyn Plugin>>
         .collect()
 }
 
+pub fn run_plugins(
+    root: &Path,
+    ctx: &PluginContext,
+    settings: &PluginsConfig,
+) -> PluginResults {
+    detect_plugins(root, ctx)
+        .into_iter()
+        .filter(|p| settings.is_enabled(p.name()))
+        .filter_map(|p| {
+            let result = p.resolve_config(root, ctx).ok()?;
+            Some((p, result))
+        })
+        .collect()
+}
+
+pub fn run_project_plugins(config: &ResolvedConfig, production: bool) -> PluginResults {
+    let mut ctx = PluginContext::new().with_production(production);
+    if let Some(pkg) = &config.package_json {
+        ctx = ctx
+            .with_dependencies(pkg.dependencies.keys().cloned().collect())
+            .with_dev_dependencies(pkg.dev_dependencies.keys().cloned().collect());
+    }
+    run_plugins(&config.root, &ctx, &config.config.plugins)
+}
+
 pub fn collect_plugin_entries(root: &Path, plugins: &[Arc<dyn Plugin>]) -> Vec<String> {
     let mut entries = Vec::new();
 
@@ -78,5 +114,22 @@ mod tests {
         assert!(names.contains(&"next"));
         assert!(names.contains(&"vite"));
         assert!(names.contains(&"jest"));
+        assert!(names.contains(&"babel"));
+        assert!(names.contains(&"prettier"));
+        assert!(names.contains(&"webpack"));
+    }
+
+    #[test]
+    fn test_run_plugins_respects_settings() {
+        let temp = tempfile::TempDir::new().unwrap();
+        std::fs::write(temp.path().join(".prettierrc"), "{}").unwrap();
+        std::fs::write(temp.path().join(".eslintrc.json"), "{}").unwrap();
+
+        let settings: PluginsConfig = serde_json::from_str(r#"{"eslint": false}"#).unwrap();
+        let results = run_plugins(temp.path(), &PluginContext::default(), &settings);
+        let names: Vec<&str> = results.iter().map(|(plugin, _)| plugin.name()).collect();
+
+        assert!(names.contains(&"prettier"));
+        assert!(!names.contains(&"eslint"));
     }
 }
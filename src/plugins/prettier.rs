@@ -0,0 +1,161 @@
+use std::path::Path;
+
+use anyhow::Result;
+
+use super::traits::{
+    collect_import_references, find_config_file, package_name_of, read_config_file,
+    read_package_json_field, string_values, Plugin, PluginContext, PluginResult,
+};
+
+pub struct PrettierPlugin;
+
+impl Plugin for PrettierPlugin {
+    fn name(&self) -> &'static str {
+        "prettier"
+    }
+
+    fn is_enabled(&self, root: &Path, ctx: &PluginContext) -> bool {
+        find_config_file(root, self.config_patterns()).is_some() || ctx.has_dependency("prettier")
+    }
+
+    fn config_patterns(&self) -> &[&str] {
+        &[
+            ".prettierrc",
+            ".prettierrc.json",
+            ".prettierrc.json5",
+            ".prettierrc.yaml",
+            ".prettierrc.yml",
+            ".prettierrc.toml",
+            ".prettierrc.js",
+            ".prettierrc.cjs",
+            ".prettierrc.mjs",
+            "prettier.config.js",
+            "prettier.config.cjs",
+            "prettier.config.mjs",
+        ]
+    }
+
+    fn has_config(&self, root: &Path) -> bool {
+        find_config_file(root, self.config_patterns()).is_some()
+            || read_package_json_field(root, "prettier").is_some()
+    }
+
+    fn entry_patterns(&self) -> &[&str] {
+        &[]
+    }
+
+    fn resolve_config(&self, root: &Path, _ctx: &PluginContext) -> Result<PluginResult> {
+        let mut result = PluginResult::new();
+
+        if let Some(config_path) = find_config_file(root, self.config_patterns()) {
+            if let Some(filename) = config_path.file_name().and_then(|f| f.to_str()) {
+                result.add_entry(filename.to_string());
+            }
+        }
+
+        result.dependencies.push("prettier".to_string());
+
+        for name in self.config_patterns() {
+            let path = root.join(name);
+            if let Some(config) = read_config_file(&path) {
+                collect_references(&config, &path, &mut result);
+            }
+            collect_import_references(&path, &mut result);
+        }
+
+        if let Some(config) = read_package_json_field(root, "prettier") {
+            collect_references(&config, &root.join("package.json"), &mut result);
+        }
+
+        Ok(result)
+    }
+}
+
+fn collect_references(config: &serde_json::Value, source: &Path, result: &mut PluginResult) {
+    if let Some(shared) = config.as_str().and_then(package_name_of) {
+        result.add_reference(shared, source, "config");
+        return;
+    }
+
+    for name in string_values(config.get("plugins")) {
+        if let Some(package) = package_name_of(&name) {
+            result.add_reference(package, source, "plugins");
+        }
+    }
+
+    if let Some(overrides) = config.get("overrides").and_then(|v| v.as_array()) {
+        for entry in overrides {
+            if let Some(options) = entry.get("options") {
+                collect_references(options, source, result);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_prettier_plugin_resolves_plugins() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join(".prettierrc.json"),
+            r#"{"plugins": ["prettier-plugin-tailwindcss", "./local-plugin.js"]}"#,
+        )
+        .unwrap();
+
+        let plugin = PrettierPlugin;
+        let ctx = PluginContext::default();
+        assert!(plugin.is_enabled(temp.path(), &ctx));
+
+        let result = plugin.resolve_config(temp.path(), &ctx).unwrap();
+        assert!(result.dependencies.contains(&"prettier".to_string()));
+        assert_eq!(result.references.len(), 1);
+        assert_eq!(result.references[0].name, "prettier-plugin-tailwindcss");
+    }
+
+    #[test]
+    fn test_prettier_plugin_reads_yaml_and_js_configs() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join(".prettierrc"),
+            "semi: false\nplugins:\n  - prettier-plugin-organize-imports\n",
+        )
+        .unwrap();
+        std::fs::write(
+            temp.path().join("prettier.config.mjs"),
+            "export default { plugins: ['prettier-plugin-svelte'] };",
+        )
+        .unwrap();
+
+        let result = PrettierPlugin
+            .resolve_config(temp.path(), &PluginContext::default())
+            .unwrap();
+        let names: Vec<&str> = result.references.iter().map(|r| r.name.as_str()).collect();
+
+        assert_eq!(
+            names,
+            vec!["prettier-plugin-organize-imports", "prettier-plugin-svelte"]
+        );
+    }
+
+    #[test]
+    fn test_prettier_plugin_shared_config_in_package_json() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("package.json"),
+            r#"{"prettier": "@company/prettier-config"}"#,
+        )
+        .unwrap();
+
+        let plugin = PrettierPlugin;
+        let result = plugin
+            .resolve_config(temp.path(), &PluginContext::default())
+            .unwrap();
+
+        assert_eq!(result.references[0].name, "@company/prettier-config");
+        assert_eq!(result.references[0].field, "config");
+    }
+}
@@ -52,7 +52,7 @@ impl Plugin for StorybookPlugin {
         }
 
         if let Some(config_path) = find_config_file(root, self.config_patterns()) {
-            if let Some(relative) = config_path.strip_prefix(root).ok() {
+            if let Ok(relative) = config_path.strip_prefix(root) {
                 result.add_entry(relative.to_string_lossy().to_string());
             }
         }
@@ -1,8 +1,10 @@
 use std::path::Path;
 
 use anyhow::Result;
+use serde_json::Value;
 
-use super::traits::{find_config_file, Plugin, PluginContext, PluginResult};
+use super::evaluate::evaluate_config_file;
+use super::traits::{find_config_file, string_values, Plugin, PluginContext, PluginResult};
 
 pub struct TailwindPlugin;
 
@@ -37,12 +39,10 @@ impl Plugin for TailwindPlugin {
                 result.add_entry(filename.to_string());
             }
 
-            if let Ok(content) = std::fs::read_to_string(&config_path) {
-                if let Some(content_patterns) = extract_content_patterns(&content) {
-                    for pattern in content_patterns {
-                        result.project_patterns.push(pattern);
-                    }
-                }
+            if let Some(content_patterns) =
+                evaluate_config_file(&config_path).and_then(|c| extract_content_patterns(&c))
+            {
+                result.project_patterns.extend(content_patterns);
             }
         }
 
@@ -62,30 +62,10 @@ impl Plugin for TailwindPlugin {
     }
 }
 
-fn extract_content_patterns(content: &str) -> Option<Vec<String>> {
-    let mut patterns = Vec::new();
-
-    if let Some(content_start) = content.find("content:") {
-        let rest = &content[content_start..];
-        if let Some(bracket_start) = rest.find('[') {
-            let after_bracket = &rest[bracket_start + 1..];
-            if let Some(bracket_end) = after_bracket.find(']') {
-                let content_array = &after_bracket[..bracket_end];
-
-                for part in content_array.split(',') {
-                    let trimmed = part.trim();
-                    let pattern = trimmed
-                        .trim_matches('\'')
-                        .trim_matches('"')
-                        .trim();
-
-                    if !pattern.is_empty() && !pattern.starts_with('{') {
-                        patterns.push(pattern.to_string());
-                    }
-                }
-            }
-        }
-    }
+fn extract_content_patterns(config: &Value) -> Option<Vec<String>> {
+    let content = config.get("content")?;
+    let files = content.get("files").unwrap_or(content);
+    let patterns = string_values(Some(files));
 
     if patterns.is_empty() {
         None
@@ -97,9 +77,15 @@ fn extract_content_patterns(content: &str) -> Option<Vec<String>> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::plugins::evaluate::evaluate_config_source;
     use tempfile::TempDir;
     use std::collections::HashSet;
 
+    fn extract(source: &str) -> Option<Vec<String>> {
+        let config = evaluate_config_source(source, Path::new("/app/tailwind.config.ts"))?;
+        extract_content_patterns(&config)
+    }
+
     #[test]
     fn test_tailwind_plugin_name() {
         let plugin = TailwindPlugin;
@@ -187,7 +173,7 @@ mod tests {
                 ],
             };
         "#;
-        let result = extract_content_patterns(content);
+        let result = extract(content);
         assert!(result.is_some());
         let patterns = result.unwrap();
         assert!(patterns.len() >= 2);
@@ -198,7 +184,24 @@ mod tests {
     #[test]
     fn test_extract_content_patterns_none() {
         let content = r#"module.exports = {}"#;
-        let result = extract_content_patterns(content);
+        let result = extract(content);
         assert!(result.is_none());
     }
+
+    #[test]
+    fn test_extract_content_patterns_object_form() {
+        let content = r#"
+            import type { Config } from 'tailwindcss';
+
+            // content: ['./ignored/**/*.html'],
+            export default {
+                content: {
+                    relative: true,
+                    files: ['./src/**/*.{html,js}', { raw: '<div class="p-4">' }],
+                },
+                theme: { extend: { spacing: { '[x]': '1px' } } },
+            } satisfies Config;
+        "#;
+        assert_eq!(extract(content), Some(vec!["./src/**/*.{html,js}".to_string()]));
+    }
 }
@@ -3,6 +3,8 @@ use std::path::{Path, PathBuf};
 
 use anyhow::Result;
 
+use crate::resolver::BundlerAlias;
+
 pub trait Plugin: Send + Sync {
     fn name(&self) -> &'static str;
 
@@ -17,6 +19,10 @@ pub trait Plugin: Send + Sync {
     fn production_entry_patterns(&self) -> &[&str] {
         self.entry_patterns()
     }
+
+    fn has_config(&self, root: &Path) -> bool {
+        find_config_file(root, self.config_patterns()).is_some()
+    }
 }
 
 #[derive(Debug, Clone, Default)]
@@ -61,6 +67,15 @@ pub struct PluginResult {
     pub dependencies: Vec<String>,
     pub ignore_patterns: Vec<String>,
     pub project_patterns: Vec<String>,
+    pub references: Vec<DependencyReference>,
+    pub aliases: Vec<BundlerAlias>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DependencyReference {
+    pub name: String,
+    pub source: PathBuf,
+    pub field: String,
 }
 
 impl PluginResult {
@@ -95,6 +110,26 @@ impl PluginResult {
     pub fn add_ignore(&mut self, pattern: impl Into<String>) {
         self.ignore_patterns.push(pattern.into());
     }
+
+    pub fn add_alias(&mut self, alias: BundlerAlias) {
+        self.aliases.push(alias);
+    }
+
+    pub fn add_reference(
+        &mut self,
+        name: impl Into<String>,
+        source: &Path,
+        field: impl Into<String>,
+    ) {
+        let reference = DependencyReference {
+            name: name.into(),
+            source: source.to_path_buf(),
+            field: field.into(),
+        };
+        if !self.references.contains(&reference) {
+            self.references.push(reference);
+        }
+    }
 }
 
 pub fn find_config_file(root: &Path, patterns: &[&str]) -> Option<PathBuf> {
@@ -124,6 +159,237 @@ pub fn glob_entries(root: &Path, patterns: &[&str]) -> Vec<String> {
     entries
 }
 
+pub fn read_json_config(path: &Path) -> Option<serde_json::Value> {
+    let content = std::fs::read_to_string(path).ok()?;
+    let content = crate::config::strip_json_comments(&content);
+    serde_json::from_str(&content).ok()
+}
+
+/// Extensionless rc files are tried as JSON first and YAML second.
+pub fn read_config_file(path: &Path) -> Option<serde_json::Value> {
+    match path.extension().and_then(|e| e.to_str()) {
+        Some("json") => read_json_config(path),
+        Some("yaml" | "yml") => read_yaml_config(path),
+        Some("js" | "cjs" | "mjs" | "ts" | "cts" | "mts") => {
+            super::evaluate::evaluate_config_file(path)
+        }
+        Some(_) => None,
+        None => read_json_config(path).or_else(|| read_yaml_config(path)),
+    }
+}
+
+pub fn read_yaml_config(path: &Path) -> Option<serde_json::Value> {
+    parse_yaml(&std::fs::read_to_string(path).ok()?)
+}
+
+/// Parses the small YAML subset used by rc files.
+pub fn parse_yaml(content: &str) -> Option<serde_json::Value> {
+    let lines: Vec<(usize, &str)> = content
+        .lines()
+        .map(strip_yaml_comment)
+        .filter(|line| !line.trim().is_empty() && line.trim() != "---")
+        .map(|line| (line.len() - line.trim_start().len(), line.trim()))
+        .collect();
+
+    if let [(_, line)] = lines.as_slice() {
+        if !line.starts_with('-') && yaml_key_value(line).is_none() {
+            return Some(yaml_scalar(line));
+        }
+    }
+
+    Some(serde_json::Value::Object(yaml_block(&lines)?))
+}
+
+fn yaml_block(lines: &[(usize, &str)]) -> Option<serde_json::Map<String, serde_json::Value>> {
+    let indent = lines.first()?.0;
+    let mut map = serde_json::Map::new();
+    let mut i = 0;
+    while i < lines.len() {
+        let (line_indent, line) = lines[i];
+        i += 1;
+        if line_indent != indent {
+            continue;
+        }
+        let Some((key, value)) = yaml_key_value(line) else {
+            continue;
+        };
+
+        let nested_end = lines[i..]
+            .iter()
+            .position(|(nested, _)| *nested <= indent)
+            .map_or(lines.len(), |end| i + end);
+        let nested = &lines[i..nested_end];
+        i = nested_end;
+
+        let value = if !value.is_empty() {
+            yaml_value(value)
+        } else if nested.first().is_some_and(|(_, line)| line.starts_with('-')) {
+            serde_json::Value::Array(
+                nested
+                    .iter()
+                    .filter_map(|(_, line)| line.strip_prefix('-'))
+                    .map(|item| yaml_scalar(item.trim()))
+                    .collect(),
+            )
+        } else {
+            match yaml_block(nested) {
+                Some(map) => serde_json::Value::Object(map),
+                None => serde_json::Value::Null,
+            }
+        };
+        map.insert(key.to_string(), value);
+    }
+    Some(map)
+}
+
+fn yaml_key_value(line: &str) -> Option<(&str, &str)> {
+    if line.starts_with('-') {
+        return None;
+    }
+    let (key, value) = line.split_once(':')?;
+    let key = key.trim().trim_matches(['"', '\'']);
+    (!key.is_empty()).then_some((key, value.trim()))
+}
+
+fn yaml_value(value: &str) -> serde_json::Value {
+    match value.strip_prefix('[').and_then(|v| v.strip_suffix(']')) {
+        Some(items) => serde_json::Value::Array(
+            items
+                .split(',')
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(yaml_scalar)
+                .collect(),
+        ),
+        None => yaml_scalar(value),
+    }
+}
+
+fn yaml_scalar(value: &str) -> serde_json::Value {
+    let quoted = value.len() >= 2
+        && (value.starts_with('"') && value.ends_with('"')
+            || value.starts_with('\'') && value.ends_with('\''));
+    if quoted {
+        return serde_json::Value::String(value[1..value.len() - 1].to_string());
+    }
+    match value {
+        "true" => serde_json::Value::Bool(true),
+        "false" => serde_json::Value::Bool(false),
+        "null" | "~" => serde_json::Value::Null,
+        _ => value
+            .parse::<i64>()
+            .map(serde_json::Value::from)
+            .unwrap_or_else(|_| serde_json::Value::String(value.to_string())),
+    }
+}
+
+fn strip_yaml_comment(line: &str) -> &str {
+    let mut quote = None;
+    for (i, c) in line.char_indices() {
+        match (c, quote) {
+            ('"' | '\'', None) => quote = Some(c),
+            (c, Some(open)) if c == open => quote = None,
+            ('#', None) if i == 0 || line[..i].ends_with(char::is_whitespace) => {
+                return &line[..i];
+            }
+            _ => {}
+        }
+    }
+    line
+}
+
+pub fn collect_import_references(path: &Path, result: &mut PluginResult) {
+    let is_script = matches!(
+        path.extension().and_then(|e| e.to_str()),
+        Some("js" | "cjs" | "mjs" | "ts" | "cts" | "mts")
+    );
+    if !is_script {
+        return;
+    }
+    let Ok(parsed) = crate::parser::parse_file(path) else {
+        return;
+    };
+    for import in &parsed.imports {
+        if let Some(package) = package_name_of(&import.specifier) {
+            result.add_reference(package, path, "import");
+        }
+    }
+}
+
+pub fn read_package_json_field(root: &Path, field: &str) -> Option<serde_json::Value> {
+    read_json_config(&root.join("package.json"))?.get(field).cloned()
+}
+
+pub fn string_values(value: Option<&serde_json::Value>) -> Vec<String> {
+    match value {
+        Some(serde_json::Value::String(s)) => vec![s.clone()],
+        Some(serde_json::Value::Array(items)) => items
+            .iter()
+            .filter_map(|v| v.as_str().map(|s| s.to_string()))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Absolute path for a relative alias target; package specifiers are kept.
+pub fn alias_target(replacement: &str, dir: &Path) -> String {
+    let path = Path::new(replacement);
+    let target = if replacement.starts_with("./") || replacement.starts_with("../") {
+        dir.join(path)
+    } else if path.is_absolute() && !path.starts_with(dir) && !path.exists() {
+        dir.join(replacement.trim_start_matches('/'))
+    } else {
+        return replacement.to_string();
+    };
+    let mut target = crate::resolver::normalize_path(&target)
+        .to_string_lossy()
+        .into_owned();
+    if replacement.ends_with('/') && !target.ends_with('/') {
+        target.push('/');
+    }
+    target
+}
+
+/// Expands `react`, `@scope` or `@scope/name` for a prefix like `eslint-plugin`.
+pub fn normalize_package_name(name: &str, prefix: &str) -> Option<String> {
+    if name.starts_with('.') || name.starts_with('/') || name.is_empty() {
+        return None;
+    }
+
+    if let Some(scoped) = name.strip_prefix('@') {
+        return match scoped.split_once('/') {
+            None => Some(format!("@{}/{}", scoped, prefix)),
+            Some((scope, rest)) => {
+                let package = rest.split('/').next().unwrap_or(rest);
+                if package.starts_with(prefix) {
+                    Some(format!("@{}/{}", scope, package))
+                } else {
+                    Some(format!("@{}/{}-{}", scope, prefix, package))
+                }
+            }
+        };
+    }
+
+    let package = name.split('/').next().unwrap_or(name);
+    if package.starts_with(prefix) {
+        Some(package.to_string())
+    } else {
+        Some(format!("{}-{}", prefix, package))
+    }
+}
+
+pub fn package_name_of(specifier: &str) -> Option<String> {
+    if specifier.is_empty() || specifier.starts_with('.') {
+        return None;
+    }
+
+    let name = crate::resolver::ModuleResolver::get_package_name(specifier)?;
+    if name.starts_with('@') && !name.contains('/') {
+        return None;
+    }
+    Some(name.to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -155,4 +421,85 @@ mod tests {
         assert_eq!(result.entries.len(), 1);
         assert_eq!(result.ignore_patterns.len(), 1);
     }
+
+    #[test]
+    fn test_add_reference_deduplicates() {
+        let mut result = PluginResult::new();
+        let source = Path::new(".eslintrc.json");
+        result.add_reference("eslint-plugin-react", source, "plugins");
+        result.add_reference("eslint-plugin-react", source, "plugins");
+        result.add_reference("eslint-plugin-react", source, "extends");
+
+        assert_eq!(result.references.len(), 2);
+    }
+
+    #[test]
+    fn test_package_name_of() {
+        assert_eq!(package_name_of("vite/client"), Some("vite".to_string()));
+        assert_eq!(
+            package_name_of("@tsconfig/node18/tsconfig.json"),
+            Some("@tsconfig/node18".to_string())
+        );
+        assert_eq!(package_name_of("./local"), None);
+        assert_eq!(package_name_of("@scope"), None);
+    }
+
+    #[test]
+    fn test_normalize_package_name() {
+        assert_eq!(
+            normalize_package_name("@vue", "eslint-config"),
+            Some("@vue/eslint-config".to_string())
+        );
+        assert_eq!(
+            normalize_package_name("@vue/typescript", "eslint-config"),
+            Some("@vue/eslint-config-typescript".to_string())
+        );
+        assert_eq!(
+            normalize_package_name("@scope/eslint-config-base/strict", "eslint-config"),
+            Some("@scope/eslint-config-base".to_string())
+        );
+        assert_eq!(
+            normalize_package_name("airbnb/hooks", "eslint-config"),
+            Some("eslint-config-airbnb".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_yaml() {
+        let yaml = r#"
+# comment
+extends:
+  - eslint:recommended
+  - "plugin:react/recommended" # trailing
+plugins: [react, '@typescript-eslint']
+parser: '@typescript-eslint/parser'
+root: true
+parserOptions:
+  ecmaVersion: 2022
+"#;
+        assert_eq!(
+            parse_yaml(yaml),
+            Some(serde_json::json!({
+                "extends": ["eslint:recommended", "plugin:react/recommended"],
+                "plugins": ["react", "@typescript-eslint"],
+                "parser": "@typescript-eslint/parser",
+                "root": true,
+                "parserOptions": { "ecmaVersion": 2022 },
+            }))
+        );
+        assert_eq!(
+            parse_yaml("\"@company/prettier-config\"\n"),
+            Some(serde_json::json!("@company/prettier-config"))
+        );
+    }
+
+    #[test]
+    fn test_alias_target() {
+        let dir = Path::new("/app");
+        assert_eq!(alias_target("./src", dir), "/app/src");
+        assert_eq!(alias_target("/src", dir), "/app/src");
+        assert_eq!(alias_target("./src/", dir), "/app/src/");
+        assert_eq!(alias_target("/app/lib", dir), "/app/lib");
+        assert_eq!(alias_target("preact/compat", dir), "preact/compat");
+    }
 }
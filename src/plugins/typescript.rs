@@ -70,6 +70,8 @@ impl Plugin for TypeScriptPlugin {
 
         result.add_ignore("**/*.d.ts".to_string());
 
+        result.dependencies.push("typescript".to_string());
+
         Ok(result)
     }
 }
@@ -1,8 +1,14 @@
 use std::path::Path;
 
 use anyhow::Result;
+use regex::Regex;
+use serde_json::Value;
 
-use super::traits::{find_config_file, Plugin, PluginContext, PluginResult};
+use super::evaluate::{evaluate_config_file, regex_source};
+use super::traits::{
+    alias_target, find_config_file, package_name_of, Plugin, PluginContext, PluginResult,
+};
+use crate::resolver::{AliasFind, BundlerAlias};
 
 pub struct VitePlugin;
 
@@ -53,6 +59,13 @@ impl Plugin for VitePlugin {
             if let Some(filename) = config_path.file_name().and_then(|f| f.to_str()) {
                 result.add_entry(filename.to_string());
             }
+
+            if let Some(alias) = evaluate_config_file(&config_path)
+                .as_ref()
+                .and_then(|config| config.pointer("/resolve/alias"))
+            {
+                collect_aliases(alias, &config_path, &mut result);
+            }
         }
 
         result.add_ignore("dist/**".to_string());
@@ -65,6 +78,40 @@ impl Plugin for VitePlugin {
     }
 }
 
+fn collect_aliases(alias: &Value, config_path: &Path, result: &mut PluginResult) {
+    let entries: Vec<(Value, &Value)> = match alias {
+        Value::Object(map) => map
+            .iter()
+            .map(|(find, replacement)| (Value::String(find.clone()), replacement))
+            .collect(),
+        Value::Array(items) => items
+            .iter()
+            .filter_map(|item| Some((item.get("find")?.clone(), item.get("replacement")?)))
+            .collect(),
+        _ => return,
+    };
+
+    let dir = config_path.parent().unwrap_or(config_path);
+    for (find, replacement) in entries {
+        let Some(replacement) = replacement.as_str() else {
+            continue;
+        };
+        let find = match (find.as_str(), regex_source(&find)) {
+            (Some(find), _) => AliasFind::Prefix(find.to_string()),
+            (None, Some(source)) => match Regex::new(source) {
+                Ok(regex) => AliasFind::Regex(regex),
+                Err(_) => continue,
+            },
+            _ => continue,
+        };
+        let target = alias_target(replacement, dir);
+        if let Some(package) = package_name_of(&target) {
+            result.add_reference(package, config_path, "resolve.alias");
+        }
+        result.add_alias(BundlerAlias::new(find, vec![target]));
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -134,4 +181,42 @@ mod tests {
         assert!(result.entries.contains(&"vite.config.ts".to_string()));
         assert!(result.ignore_patterns.contains(&"dist/**".to_string()));
     }
+
+    #[test]
+    fn test_vite_plugin_aliases() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        std::fs::write(
+            root.join("vite.config.ts"),
+            r#"
+            import { defineConfig } from 'vite';
+            import path from 'node:path';
+
+            export default defineConfig({
+                resolve: {
+                    alias: [
+                        { find: '@', replacement: path.resolve(__dirname, 'src') },
+                        { find: /^~\//, replacement: '/styles/' },
+                        { find: 'react', replacement: 'preact/compat' },
+                    ],
+                },
+            });
+            "#,
+        )
+        .unwrap();
+
+        let result = VitePlugin
+            .resolve_config(root, &PluginContext::default())
+            .unwrap();
+        let rewrite = |specifier: &str| -> Vec<Vec<String>> {
+            result.aliases.iter().filter_map(|a| a.rewrite(specifier)).collect()
+        };
+
+        let src = root.join("src/util").to_string_lossy().into_owned();
+        assert_eq!(rewrite("@/util"), vec![vec![src]]);
+        let styles = root.join("styles/app.css").to_string_lossy().into_owned();
+        assert_eq!(rewrite("~/app.css"), vec![vec![styles]]);
+        assert_eq!(rewrite("react"), vec![vec!["preact/compat".to_string()]]);
+        assert_eq!(result.references[0].name, "preact");
+    }
 }
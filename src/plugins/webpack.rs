@@ -0,0 +1,151 @@
+use std::path::Path;
+
+use anyhow::Result;
+use serde_json::Value;
+
+use super::evaluate::evaluate_config_file;
+use super::traits::{
+    alias_target, find_config_file, package_name_of, Plugin, PluginContext, PluginResult,
+};
+use crate::resolver::{AliasFind, BundlerAlias};
+
+pub struct WebpackPlugin;
+
+impl Plugin for WebpackPlugin {
+    fn name(&self) -> &'static str {
+        "webpack"
+    }
+
+    fn is_enabled(&self, root: &Path, ctx: &PluginContext) -> bool {
+        find_config_file(root, self.config_patterns()).is_some()
+            || ctx.has_dependency("webpack")
+    }
+
+    fn config_patterns(&self) -> &[&str] {
+        &[
+            "webpack.config.js",
+            "webpack.config.ts",
+            "webpack.config.mjs",
+            "webpack.config.cjs",
+        ]
+    }
+
+    fn entry_patterns(&self) -> &[&str] {
+        &[]
+    }
+
+    fn resolve_config(&self, root: &Path, _ctx: &PluginContext) -> Result<PluginResult> {
+        let mut result = PluginResult::new();
+
+        if let Some(config_path) = find_config_file(root, self.config_patterns()) {
+            if let Some(filename) = config_path.file_name().and_then(|f| f.to_str()) {
+                result.add_entry(filename.to_string());
+            }
+
+            let configs = match evaluate_config_file(&config_path) {
+                Some(Value::Array(configs)) => configs,
+                Some(config) => vec![config],
+                None => Vec::new(),
+            };
+            for alias in configs.iter().filter_map(|c| c.pointer("/resolve/alias")) {
+                collect_aliases(alias, &config_path, &mut result);
+            }
+        }
+
+        result.dependencies.push("webpack".to_string());
+
+        Ok(result)
+    }
+}
+
+fn collect_aliases(alias: &Value, config_path: &Path, result: &mut PluginResult) {
+    let entries: Vec<(&str, &Value)> = match alias {
+        Value::Object(map) => map.iter().map(|(k, v)| (k.as_str(), v)).collect(),
+        Value::Array(items) => items
+            .iter()
+            .filter_map(|item| Some((item.get("name")?.as_str()?, item.get("alias")?)))
+            .collect(),
+        _ => return,
+    };
+
+    let dir = config_path.parent().unwrap_or(config_path);
+    for (name, value) in entries {
+        let targets: Vec<String> = match value {
+            Value::String(target) => vec![alias_target(target, dir)],
+            Value::Array(targets) => targets
+                .iter()
+                .filter_map(|t| t.as_str())
+                .map(|t| alias_target(t, dir))
+                .collect(),
+            _ => continue,
+        };
+        if targets.is_empty() {
+            continue;
+        }
+
+        for package in targets.iter().filter_map(|t| package_name_of(t)) {
+            result.add_reference(package, config_path, "resolve.alias");
+        }
+        let find = match name.strip_suffix('$') {
+            Some(exact) => AliasFind::Exact(exact.to_string()),
+            None => AliasFind::Prefix(name.to_string()),
+        };
+        result.add_alias(BundlerAlias::new(find, targets));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_webpack_plugin_enabled_with_config() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(temp.path().join("webpack.config.js"), "module.exports = {}").unwrap();
+
+        assert!(WebpackPlugin.is_enabled(temp.path(), &PluginContext::default()));
+    }
+
+    #[test]
+    fn test_webpack_plugin_aliases() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        std::fs::write(
+            root.join("webpack.config.js"),
+            r#"
+            const path = require('path');
+
+            module.exports = (env) => ({
+                resolve: {
+                    alias: {
+                        Utilities: path.resolve(__dirname, 'src/utilities/'),
+                        vue$: 'vue/dist/vue.esm.js',
+                        ignored: false,
+                        'lib/legacy': path.resolve(__dirname, 'src/legacy'),
+                        lib: path.resolve(__dirname, 'src/lib'),
+                    },
+                },
+            });
+            "#,
+        )
+        .unwrap();
+
+        let result = WebpackPlugin
+            .resolve_config(root, &PluginContext::default())
+            .unwrap();
+        assert_eq!(result.aliases.len(), 4);
+        assert!(result.entries.contains(&"webpack.config.js".to_string()));
+
+        let rewrite = |specifier: &str| -> Vec<Vec<String>> {
+            result.aliases.iter().filter_map(|a| a.rewrite(specifier)).collect()
+        };
+        let utilities = root.join("src/utilities/file").to_string_lossy().into_owned();
+        assert_eq!(rewrite("Utilities/file"), vec![vec![utilities]]);
+        assert_eq!(rewrite("vue"), vec![vec!["vue/dist/vue.esm.js".to_string()]]);
+        assert!(rewrite("vue/dist/vue.js").is_empty());
+
+        let legacy = root.join("src/legacy/a").to_string_lossy().into_owned();
+        assert_eq!(rewrite("lib/legacy/a")[0], vec![legacy]);
+    }
+}
@@ -0,0 +1,310 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{AnalysisResult, Counters, Issues, Stats};
+
+pub const SCHEMA_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum PathStyle {
+    #[default]
+    Relative,
+    Absolute,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Report {
+    #[serde(default)]
+    #[schemars(
+        description = "Version of the report format. Bumped only on breaking changes; new fields may be added without a bump. Reports written before versioning was introduced have no schemaVersion and are read as version 0."
+    )]
+    pub schema_version: u32,
+
+    #[schemars(description = "Version of pior that produced the report.")]
+    pub version: String,
+
+    #[serde(default)]
+    #[schemars(description = "Absolute path of the analyzed project.")]
+    pub root: Option<PathBuf>,
+
+    #[serde(default = "legacy_path_style")]
+    #[schemars(description = "Whether file paths in the report are relative to root or absolute.")]
+    pub paths: PathStyle,
+
+    #[serde(default)]
+    pub issues: Issues,
+
+    #[serde(default)]
+    pub counters: ReportCounters,
+
+    #[serde(default)]
+    pub stats: Stats,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportCounters {
+    #[serde(flatten)]
+    pub counters: Counters,
+
+    #[serde(default)]
+    pub total: usize,
+}
+
+fn legacy_path_style() -> PathStyle {
+    PathStyle::Absolute
+}
+
+impl Report {
+    pub fn new(result: &AnalysisResult, root: &Path, paths: PathStyle) -> Self {
+        let mut issues = result.issues.clone();
+
+        if paths == PathStyle::Relative {
+            map_issue_paths(&mut issues, |path| {
+                path.strip_prefix(root).unwrap_or(path).to_path_buf()
+            });
+        }
+
+        Self {
+            schema_version: SCHEMA_VERSION,
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            root: Some(root.to_path_buf()),
+            paths,
+            issues,
+            counters: ReportCounters {
+                counters: result.counters.clone(),
+                total: result.counters.total(),
+            },
+            stats: result.stats.clone(),
+        }
+    }
+
+    pub fn from_json(content: &str) -> Result<Self> {
+        let report: Report = serde_json::from_str(content).context("Failed to parse report")?;
+
+        if report.schema_version > SCHEMA_VERSION {
+            bail!(
+                "Report uses schemaVersion {} but this version of pior only reads up to {}",
+                report.schema_version,
+                SCHEMA_VERSION
+            );
+        }
+
+        Ok(report)
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read report: {}", path.display()))?;
+        Self::from_json(&content).with_context(|| format!("Invalid report: {}", path.display()))
+    }
+
+    pub fn into_result(self) -> AnalysisResult {
+        let mut issues = self.issues;
+
+        if self.paths == PathStyle::Relative {
+            if let Some(ref root) = self.root {
+                map_issue_paths(&mut issues, |path| root.join(path));
+            }
+        }
+
+        for dep in &mut issues.dev_dependencies {
+            dep.is_dev = true;
+        }
+
+        AnalysisResult {
+            issues,
+            counters: self.counters.counters,
+            stats: self.stats,
+            dependency_credits: Vec::new(),
+        }
+    }
+
+    pub fn json_schema() -> serde_json::Value {
+        serde_json::to_value(schemars::schema_for!(Report)).unwrap_or_default()
+    }
+}
+
+fn map_issue_paths(issues: &mut Issues, f: impl Fn(&Path) -> PathBuf) {
+    let map_all = |paths: &mut Vec<PathBuf>| {
+        for path in paths.iter_mut() {
+            *path = f(path);
+        }
+    };
+
+    for file in &mut issues.files {
+        file.path = f(&file.path);
+    }
+    for dep in issues
+        .dependencies
+        .iter_mut()
+        .chain(issues.dev_dependencies.iter_mut())
+    {
+        dep.package_json = f(&dep.package_json);
+    }
+    for dep in issues
+        .misplaced_dependencies
+        .iter_mut()
+        .chain(issues.misplaced_dev_dependencies.iter_mut())
+    {
+        dep.package_json = f(&dep.package_json);
+        map_all(&mut dep.used_in);
+    }
+    for dep in &mut issues.type_only_dependencies {
+        dep.package_json = f(&dep.package_json);
+        map_all(&mut dep.used_in);
+    }
+    for types in &mut issues.unpaired_types {
+        types.package_json = f(&types.package_json);
+    }
+    for types in &mut issues.missing_types {
+        map_all(&mut types.used_in);
+    }
+    for export in issues.exports.iter_mut().chain(issues.ns_exports.iter_mut()) {
+        export.path = f(&export.path);
+    }
+    for ty in issues.types.iter_mut().chain(issues.ns_types.iter_mut()) {
+        ty.path = f(&ty.path);
+    }
+    for unlisted in &mut issues.unlisted {
+        map_all(&mut unlisted.used_in);
+    }
+    for binary in &mut issues.binaries {
+        map_all(&mut binary.used_in);
+    }
+    for unresolved in &mut issues.unresolved {
+        unresolved.path = f(&unresolved.path);
+    }
+    for duplicate in &mut issues.duplicates {
+        for location in &mut duplicate.locations {
+            location.path = f(&location.path);
+        }
+    }
+    for asset in &mut issues.assets {
+        asset.path = f(&asset.path);
+    }
+    for class in &mut issues.css_classes {
+        class.path = f(&class.path);
+    }
+    for class in &mut issues.unknown_css_classes {
+        class.path = f(&class.path);
+        class.stylesheet = f(&class.stylesheet);
+    }
+    for member in &mut issues.enum_members {
+        member.path = f(&member.path);
+    }
+    for member in &mut issues.class_members {
+        member.path = f(&member.path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{UnusedDependency, UnusedExport, UnusedFile};
+
+    fn sample_result(root: &Path) -> AnalysisResult {
+        let mut result = AnalysisResult::default();
+        result.issues.files.push(UnusedFile {
+            path: root.join("src/unused.ts"),
+        });
+        result.issues.dev_dependencies.push(UnusedDependency {
+            name: "left-pad".to_string(),
+            package_json: root.join("package.json"),
+            workspace: None,
+            is_dev: true,
+        });
+        result.issues.exports.push(UnusedExport {
+            path: root.join("src/lib.ts"),
+            name: "helper".to_string(),
+            line: 3,
+            col: 1,
+            kind: crate::ExportKind::Function,
+            is_type: false,
+        });
+        result.counters.files = 1;
+        result.counters.dev_dependencies = 1;
+        result.counters.exports = 1;
+        result
+    }
+
+    #[test]
+    fn test_report_uses_relative_paths() {
+        let root = Path::new("/project");
+        let report = Report::new(&sample_result(root), root, PathStyle::Relative);
+        let json = serde_json::to_value(&report).unwrap();
+
+        assert_eq!(json["schemaVersion"], SCHEMA_VERSION);
+        assert_eq!(json["paths"], "relative");
+        assert_eq!(json["issues"]["files"][0]["path"], "src/unused.ts");
+        assert_eq!(json["issues"]["exports"][0]["kind"], "function");
+        assert_eq!(json["counters"]["devDependencies"], 1);
+        assert_eq!(json["counters"]["total"], 3);
+        assert!(json["issues"]["duplicates"].is_array());
+        assert!(json["issues"]["classMembers"].is_array());
+    }
+
+    #[test]
+    fn test_report_round_trip() {
+        let root = Path::new("/project");
+        let report = Report::new(&sample_result(root), root, PathStyle::Relative);
+        let json = serde_json::to_string(&report).unwrap();
+
+        let result = Report::from_json(&json).unwrap().into_result();
+        assert_eq!(result.issues.files[0].path, root.join("src/unused.ts"));
+        assert_eq!(result.issues.exports[0].name, "helper");
+        assert_eq!(result.counters.total(), 3);
+    }
+
+    #[test]
+    fn test_read_legacy_report() {
+        let legacy = r#"{
+            "version": "0.1.1",
+            "issues": {
+                "files": [{"path": "/project/src/old.ts"}],
+                "devDependencies": [{"name": "jest", "packageJson": "/project/package.json", "workspace": null}],
+                "types": [{"path": "/project/src/a.ts", "name": "Props", "line": 1, "col": 1, "kind": "interface"}]
+            },
+            "counters": {"files": 1, "devDependencies": 1, "types": 1, "total": 3},
+            "stats": {"filesAnalyzed": 10, "durationMs": 5}
+        }"#;
+
+        let report = Report::from_json(legacy).unwrap();
+        assert_eq!(report.schema_version, 0);
+        assert_eq!(report.paths, PathStyle::Absolute);
+
+        let result = report.into_result();
+        assert_eq!(result.issues.files[0].path, Path::new("/project/src/old.ts"));
+        assert!(result.issues.dev_dependencies[0].is_dev);
+        assert_eq!(result.issues.types[0].kind, crate::TypeKind::Interface);
+        assert_eq!(result.stats.files_analyzed, 10);
+    }
+
+    #[test]
+    fn test_reject_newer_schema_version() {
+        let json = format!(
+            r#"{{"schemaVersion": {}, "version": "99.0.0"}}"#,
+            SCHEMA_VERSION + 1
+        );
+        assert!(Report::from_json(&json).is_err());
+    }
+
+    #[test]
+    fn test_published_schema_is_up_to_date() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("schema/report.schema.json");
+        let published: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+
+        assert_eq!(
+            published,
+            Report::json_schema(),
+            "schema/report.schema.json is stale; regenerate it with `pior schema`"
+        );
+    }
+}
@@ -0,0 +1,145 @@
+use std::path::Path;
+
+use globset::GlobSet;
+use regex::Regex;
+
+use super::node::build_glob_set;
+
+#[derive(Debug, Clone)]
+pub enum AliasFind {
+    /// Matches the specifier itself or any subpath of it (`@` matches `@/util`).
+    Prefix(String),
+    /// Matches only the specifier itself (webpack's trailing `$`).
+    Exact(String),
+    /// Replaces the matched part of the specifier, like `String.replace`.
+    Regex(Regex),
+    /// Replaces the whole specifier when it matches, like Jest's `moduleNameMapper`.
+    Mapper(Regex),
+}
+
+#[derive(Debug, Clone)]
+pub struct BundlerAlias {
+    find: AliasFind,
+    replacements: Vec<String>,
+    files: Option<GlobSet>,
+}
+
+impl BundlerAlias {
+    pub fn new(find: AliasFind, replacements: Vec<String>) -> Self {
+        Self {
+            find,
+            replacements,
+            files: None,
+        }
+    }
+
+    pub fn with_files(mut self, root: &Path, patterns: &[String]) -> Self {
+        let patterns = patterns
+            .iter()
+            .map(|pattern| root.join(pattern).to_string_lossy().into_owned());
+        self.files = Some(build_glob_set(patterns));
+        self
+    }
+
+    pub fn is_scoped(&self) -> bool {
+        self.files.is_some()
+    }
+
+    pub fn applies_to(&self, from: &Path) -> bool {
+        self.files.as_ref().is_none_or(|files| files.is_match(from))
+    }
+
+    pub fn rewrite(&self, specifier: &str) -> Option<Vec<String>> {
+        let replace = |replacement: &String| -> Option<String> {
+            match &self.find {
+                AliasFind::Exact(find) => (specifier == find).then(|| replacement.clone()),
+                AliasFind::Prefix(find) => {
+                    let rest = specifier.strip_prefix(find.as_str())?;
+                    (rest.is_empty() || rest.starts_with('/') || find.ends_with('/'))
+                        .then(|| format!("{}{}", replacement, rest))
+                }
+                AliasFind::Regex(find) => find
+                    .is_match(specifier)
+                    .then(|| find.replace(specifier, js_replacement(replacement)).into_owned()),
+                AliasFind::Mapper(find) => {
+                    let captures = find.captures(specifier)?;
+                    let mut target = String::new();
+                    captures.expand(&js_replacement(replacement), &mut target);
+                    Some(target)
+                }
+            }
+        };
+
+        let targets: Vec<String> = self.replacements.iter().filter_map(replace).collect();
+        (!targets.is_empty()).then_some(targets)
+    }
+}
+
+/// Converts JavaScript `$1` references to `${1}` for the regex crate.
+fn js_replacement(replacement: &str) -> String {
+    let mut converted = String::with_capacity(replacement.len());
+    let mut chars = replacement.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '$' {
+            converted.push(c);
+            continue;
+        }
+        let mut digits = String::new();
+        while let Some(digit) = chars.next_if(char::is_ascii_digit) {
+            digits.push(digit);
+        }
+        match (digits.is_empty(), chars.peek()) {
+            (false, _) => converted.push_str(&format!("${{{}}}", digits)),
+            (true, Some('$')) => {
+                chars.next();
+                converted.push_str("$$");
+            }
+            (true, Some('&')) => {
+                chars.next();
+                converted.push_str("${0}");
+            }
+            (true, _) => converted.push_str("$$"),
+        }
+    }
+    converted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn alias(find: AliasFind, replacement: &str) -> BundlerAlias {
+        BundlerAlias::new(find, vec![replacement.to_string()])
+    }
+
+    #[test]
+    fn test_rewrite() {
+        let prefix = alias(AliasFind::Prefix("@".to_string()), "/app/src");
+        assert_eq!(prefix.rewrite("@"), Some(vec!["/app/src".to_string()]));
+        assert_eq!(prefix.rewrite("@/util"), Some(vec!["/app/src/util".to_string()]));
+        assert_eq!(prefix.rewrite("@scope/pkg"), None);
+
+        let exact = alias(AliasFind::Exact("vue".to_string()), "vue/dist/vue.esm.js");
+        assert!(exact.rewrite("vue").is_some());
+        assert!(exact.rewrite("vue/x").is_none());
+
+        let regex = alias(AliasFind::Regex(Regex::new("^~/").unwrap()), "/app/src/");
+        assert_eq!(regex.rewrite("~/a/b"), Some(vec!["/app/src/a/b".to_string()]));
+
+        let mapper = alias(
+            AliasFind::Mapper(Regex::new(r"^@/(.*)$").unwrap()),
+            "/app/src/$1index",
+        );
+        assert_eq!(mapper.rewrite("@/lib/"), Some(vec!["/app/src/lib/index".to_string()]));
+        assert_eq!(mapper.rewrite("lodash"), None);
+    }
+
+    #[test]
+    fn test_scoped_alias() {
+        let alias = alias(AliasFind::Prefix("@".to_string()), "/app/src")
+            .with_files(Path::new("/app"), &["**/*.test.ts".to_string()]);
+        assert!(alias.is_scoped());
+        assert!(alias.applies_to(Path::new("/app/src/a.test.ts")));
+        assert!(!alias.applies_to(Path::new("/app/src/a.ts")));
+    }
+}
@@ -0,0 +1,243 @@
+const NODE_PREFIX: &str = "node:";
+
+struct NodeBuiltin {
+    id: &'static str,
+    since: (u32, u32),
+    prefix_only: bool,
+}
+
+const fn builtin(id: &'static str, since: (u32, u32)) -> NodeBuiltin {
+    NodeBuiltin {
+        id,
+        since,
+        prefix_only: false,
+    }
+}
+
+const fn prefixed(id: &'static str, since: (u32, u32)) -> NodeBuiltin {
+    NodeBuiltin {
+        id,
+        since,
+        prefix_only: true,
+    }
+}
+
+const NODE_BUILTINS: &[NodeBuiltin] = &[
+    builtin("_http_agent", (0, 11)),
+    builtin("_http_client", (0, 11)),
+    builtin("_http_common", (0, 11)),
+    builtin("_http_incoming", (0, 11)),
+    builtin("_http_outgoing", (0, 11)),
+    builtin("_http_server", (0, 11)),
+    builtin("_stream_duplex", (0, 9)),
+    builtin("_stream_passthrough", (0, 9)),
+    builtin("_stream_readable", (0, 9)),
+    builtin("_stream_transform", (0, 9)),
+    builtin("_stream_wrap", (4, 0)),
+    builtin("_stream_writable", (0, 9)),
+    builtin("_tls_common", (0, 11)),
+    builtin("_tls_wrap", (0, 11)),
+    builtin("assert", (0, 1)),
+    builtin("assert/strict", (15, 0)),
+    builtin("async_hooks", (8, 1)),
+    builtin("buffer", (0, 1)),
+    builtin("child_process", (0, 1)),
+    builtin("cluster", (0, 7)),
+    builtin("console", (0, 1)),
+    builtin("constants", (0, 1)),
+    builtin("crypto", (0, 1)),
+    builtin("dgram", (0, 1)),
+    builtin("diagnostics_channel", (15, 1)),
+    builtin("dns", (0, 1)),
+    builtin("dns/promises", (15, 0)),
+    builtin("domain", (0, 7)),
+    builtin("events", (0, 1)),
+    builtin("fs", (0, 1)),
+    builtin("fs/promises", (14, 0)),
+    builtin("http", (0, 1)),
+    builtin("http2", (8, 4)),
+    builtin("https", (0, 3)),
+    builtin("inspector", (8, 0)),
+    builtin("inspector/promises", (19, 0)),
+    builtin("module", (0, 3)),
+    builtin("net", (0, 1)),
+    builtin("os", (0, 3)),
+    builtin("path", (0, 1)),
+    builtin("path/posix", (15, 3)),
+    builtin("path/win32", (15, 3)),
+    builtin("perf_hooks", (8, 5)),
+    builtin("process", (0, 1)),
+    builtin("punycode", (0, 5)),
+    builtin("querystring", (0, 1)),
+    builtin("readline", (0, 1)),
+    builtin("readline/promises", (17, 0)),
+    builtin("repl", (0, 1)),
+    builtin("stream", (0, 1)),
+    builtin("stream/consumers", (16, 7)),
+    builtin("stream/promises", (15, 0)),
+    builtin("stream/web", (16, 5)),
+    builtin("string_decoder", (0, 1)),
+    builtin("sys", (0, 1)),
+    builtin("timers", (0, 1)),
+    builtin("timers/promises", (15, 0)),
+    builtin("tls", (0, 3)),
+    builtin("trace_events", (10, 0)),
+    builtin("tty", (0, 1)),
+    builtin("url", (0, 1)),
+    builtin("util", (0, 1)),
+    builtin("util/types", (15, 3)),
+    builtin("v8", (1, 0)),
+    builtin("vm", (0, 1)),
+    builtin("wasi", (13, 3)),
+    builtin("worker_threads", (10, 5)),
+    builtin("zlib", (0, 5)),
+    prefixed("sea", (20, 12)),
+    prefixed("sqlite", (22, 5)),
+    prefixed("test", (18, 0)),
+    prefixed("test/reporters", (19, 9)),
+];
+
+const BUN_PREFIX: &str = "bun:";
+const DENO_SCHEMES: &[&str] = &["jsr:", "npm:", "http:", "https:", "data:"];
+const WORKERD_PREFIX: &str = "cloudflare:";
+const WORKERD_MODULES: &[&str] = &["__STATIC_CONTENT_MANIFEST"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Runtime {
+    Node(Option<(u32, u32)>),
+    Bun,
+    Deno,
+    Workerd,
+}
+
+impl Runtime {
+    pub fn parse(name: &str) -> Option<Self> {
+        let (name, version) = match name.split_once('@') {
+            Some((name, version)) => (name, Some(version)),
+            None => (name, None),
+        };
+
+        match (name, version) {
+            ("node", None) => Some(Runtime::Node(None)),
+            ("node", Some(version)) => {
+                let (major, minor) = version.split_once('.').unwrap_or((version, "0"));
+                let minor = minor.split('.').next().unwrap_or("0");
+                Some(Runtime::Node(Some((major.parse().ok()?, minor.parse().ok()?))))
+            }
+            ("bun", None) => Some(Runtime::Bun),
+            ("deno", None) => Some(Runtime::Deno),
+            ("workerd" | "cloudflare", None) => Some(Runtime::Workerd),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Builtins {
+    node_version: Option<(u32, u32)>,
+    runtimes: Vec<Runtime>,
+}
+
+impl Builtins {
+    pub fn new(runtimes: &[String]) -> Self {
+        let runtimes: Vec<Runtime> = runtimes.iter().filter_map(|r| Runtime::parse(r)).collect();
+        let node_version = runtimes.iter().find_map(|runtime| match runtime {
+            Runtime::Node(version) => *version,
+            _ => None,
+        });
+        Self {
+            node_version,
+            runtimes,
+        }
+    }
+
+    pub fn contains(&self, specifier: &str) -> bool {
+        self.is_node_builtin(specifier) || self.is_runtime_builtin(specifier)
+    }
+
+    fn is_node_builtin(&self, specifier: &str) -> bool {
+        let (id, prefixed) = match specifier.strip_prefix(NODE_PREFIX) {
+            Some(id) => (id, true),
+            None => (specifier, false),
+        };
+
+        NODE_BUILTINS.iter().any(|builtin| {
+            builtin.id == id
+                && (prefixed || !builtin.prefix_only)
+                && self.node_version.is_none_or(|version| builtin.since <= version)
+        })
+    }
+
+    fn is_runtime_builtin(&self, specifier: &str) -> bool {
+        self.runtimes.iter().any(|runtime| match runtime {
+            Runtime::Node(_) => false,
+            Runtime::Bun => specifier == "bun" || specifier.starts_with(BUN_PREFIX),
+            Runtime::Deno => DENO_SCHEMES.iter().any(|scheme| specifier.starts_with(scheme)),
+            Runtime::Workerd => {
+                specifier.starts_with(WORKERD_PREFIX) || WORKERD_MODULES.contains(&specifier)
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn builtins(runtimes: &[&str]) -> Builtins {
+        Builtins::new(&runtimes.iter().map(|r| r.to_string()).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn test_node_builtins() {
+        let node = Builtins::default();
+        assert!(node.contains("fs"));
+        assert!(node.contains("fs/promises"));
+        assert!(node.contains("node:fs/promises"));
+        assert!(node.contains("diagnostics_channel"));
+        assert!(node.contains("_http_agent"));
+        assert!(node.contains("node:_stream_readable"));
+        assert!(node.contains("node:test"));
+        assert!(node.contains("node:test/reporters"));
+        assert!(!node.contains("test"));
+        assert!(!node.contains("fs/missing"));
+        assert!(!node.contains("node:missing"));
+        assert!(!node.contains("bun:sqlite"));
+    }
+
+    #[test]
+    fn test_node_version() {
+        let node16 = builtins(&["node@16"]);
+        assert!(node16.contains("fs/promises"));
+        assert!(!node16.contains("node:test"));
+        assert!(!node16.contains("node:sqlite"));
+        assert!(builtins(&["node@22.5.1"]).contains("node:sqlite"));
+        assert!(builtins(&["node"]).contains("node:sqlite"));
+    }
+
+    #[test]
+    fn test_runtime_builtins() {
+        let bun = builtins(&["bun"]);
+        assert!(bun.contains("bun"));
+        assert!(bun.contains("bun:sqlite"));
+        assert!(bun.contains("node:fs"));
+
+        let deno = builtins(&["deno"]);
+        assert!(deno.contains("jsr:@std/path"));
+        assert!(deno.contains("npm:chalk@5"));
+
+        let workerd = builtins(&["cloudflare"]);
+        assert!(workerd.contains("cloudflare:workers"));
+        assert!(workerd.contains("__STATIC_CONTENT_MANIFEST"));
+        assert!(!workerd.contains("bun:test"));
+
+        let mixed = builtins(&["node@20", "bun", "workerd"]);
+        for specifier in ["fs/promises", "node:test", "bun:sqlite", "cloudflare:workers"] {
+            assert!(mixed.contains(specifier), "{} should be a builtin", specifier);
+        }
+
+        assert_eq!(Runtime::parse("node@20.11.0"), Some(Runtime::Node(Some((20, 11)))));
+        assert_eq!(Runtime::parse("bun@1"), None);
+        assert_eq!(Runtime::parse("electron"), None);
+    }
+}
@@ -0,0 +1,82 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::path::Path;
+use std::sync::RwLock;
+
+#[derive(Debug)]
+pub(super) struct ConcurrentCache<K, V> {
+    entries: RwLock<HashMap<K, V>>,
+}
+
+impl<K, V> Default for ConcurrentCache<K, V> {
+    fn default() -> Self {
+        Self {
+            entries: RwLock::new(HashMap::new()),
+        }
+    }
+}
+
+impl<K: Eq + Hash, V: Clone> ConcurrentCache<K, V> {
+    pub(super) fn get_or_insert_with<Q>(&self, key: &Q, compute: impl FnOnce() -> V) -> V
+    where
+        K: std::borrow::Borrow<Q>,
+        Q: Eq + Hash + ToOwned<Owned = K> + ?Sized,
+    {
+        if let Some(value) = self.entries.read().ok().and_then(|e| e.get(key).cloned()) {
+            return value;
+        }
+
+        let value = compute();
+        if let Ok(mut entries) = self.entries.write() {
+            entries.insert(key.to_owned(), value.clone());
+        }
+        value
+    }
+
+    pub(super) fn clear(&self) {
+        if let Ok(mut entries) = self.entries.write() {
+            entries.clear();
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum FileKind {
+    File,
+    Dir,
+    Missing,
+}
+
+impl FileKind {
+    pub(super) fn of(path: &Path) -> Self {
+        match std::fs::metadata(path) {
+            Ok(metadata) if metadata.is_file() => FileKind::File,
+            Ok(metadata) if metadata.is_dir() => FileKind::Dir,
+            _ => FileKind::Missing,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_cache_computes_once_until_cleared() {
+        let cache: ConcurrentCache<PathBuf, FileKind> = ConcurrentCache::default();
+        let temp = tempfile::TempDir::new().unwrap();
+        let file = temp.path().join("a.ts");
+
+        let kind = cache.get_or_insert_with(file.as_path(), || FileKind::of(&file));
+        assert_eq!(kind, FileKind::Missing);
+
+        std::fs::write(&file, "").unwrap();
+        let kind = cache.get_or_insert_with(file.as_path(), || FileKind::of(&file));
+        assert_eq!(kind, FileKind::Missing);
+
+        cache.clear();
+        let kind = cache.get_or_insert_with(file.as_path(), || FileKind::of(&file));
+        assert_eq!(kind, FileKind::File);
+    }
+}
@@ -1,3 +1,12 @@
+mod alias;
+mod builtins;
+mod cache;
 mod node;
+mod pnp;
+mod trace;
 
+pub use alias::{AliasFind, BundlerAlias};
+pub use builtins::{Builtins, Runtime};
 pub use node::ModuleResolver;
+pub(crate) use node::normalize_path;
+pub use pnp::PnpManifest;
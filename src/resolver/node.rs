@@ -1,7 +1,20 @@
+use std::cmp::Reverse;
 use std::collections::HashMap;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
 
-const EXTENSIONS: &[&str] = &[".ts", ".tsx", ".js", ".jsx", ".mjs", ".cjs", ".mts", ".cts"];
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+
+use super::alias::BundlerAlias;
+use super::builtins::Builtins;
+use super::cache::{ConcurrentCache, FileKind};
+use super::pnp::PnpManifest;
+use super::trace::{self, trace};
+use crate::parser::css::{is_stylesheet, STYLESHEET_EXTENSIONS};
+
+const EXTENSIONS: &[&str] = &[
+    ".ts", ".tsx", ".js", ".jsx", ".mjs", ".cjs", ".mts", ".cts", ".vue", ".svelte", ".astro",
+];
 const INDEX_FILES: &[&str] = &[
     "index.ts",
     "index.tsx",
@@ -10,12 +23,49 @@ const INDEX_FILES: &[&str] = &[
     "index.mjs",
     "index.cjs",
 ];
+const NO_SUFFIXES: &[String] = &[String::new()];
+const DEFAULT_MAIN_FIELDS: &[&str] = &["module", "main", "types"];
+
+#[derive(Debug, Clone)]
+enum AliasTarget {
+    Replace(String),
+    Disabled,
+}
+
+#[derive(Debug, Default)]
+struct PackageAliases {
+    modules: HashMap<String, AliasTarget>,
+    files: HashMap<PathBuf, AliasTarget>,
+}
+
+#[derive(Debug, Clone)]
+struct ResolverScope {
+    dir: PathBuf,
+    include: GlobSet,
+    exclude: GlobSet,
+    resolver: ModuleResolver,
+}
 
 #[derive(Debug, Clone)]
 pub struct ModuleResolver {
     root: PathBuf,
     base_url: Option<PathBuf>,
     paths: HashMap<String, Vec<String>>,
+    root_dirs: Vec<PathBuf>,
+    module_suffixes: Vec<String>,
+    allow_ts_extensions: bool,
+    preserve_symlinks: bool,
+    workspace_packages: HashMap<String, PathBuf>,
+    pnp: Option<Arc<PnpManifest>>,
+    main_fields: Vec<String>,
+    alias_fields: Vec<String>,
+    builtins: Builtins,
+    bundler_aliases: Vec<BundlerAlias>,
+    scopes: Vec<ResolverScope>,
+    package_aliases: Arc<ConcurrentCache<PathBuf, Arc<PackageAliases>>>,
+    stats: Arc<ConcurrentCache<PathBuf, FileKind>>,
+    resolutions: Arc<ConcurrentCache<(PathBuf, String), Option<PathBuf>>>,
+    base_resolutions: Arc<ConcurrentCache<String, Option<PathBuf>>>,
 }
 
 impl ModuleResolver {
@@ -24,6 +74,21 @@ impl ModuleResolver {
             root,
             base_url: None,
             paths: HashMap::new(),
+            root_dirs: Vec::new(),
+            module_suffixes: Vec::new(),
+            allow_ts_extensions: false,
+            preserve_symlinks: false,
+            workspace_packages: HashMap::new(),
+            pnp: None,
+            main_fields: DEFAULT_MAIN_FIELDS.iter().map(|f| f.to_string()).collect(),
+            alias_fields: Vec::new(),
+            builtins: Builtins::default(),
+            bundler_aliases: Vec::new(),
+            scopes: Vec::new(),
+            package_aliases: Arc::default(),
+            stats: Arc::default(),
+            resolutions: Arc::default(),
+            base_resolutions: Arc::default(),
         }
     }
 
@@ -37,7 +102,315 @@ impl ModuleResolver {
         self
     }
 
+    pub fn with_root_dirs(mut self, root_dirs: Vec<PathBuf>) -> Self {
+        self.root_dirs = root_dirs.iter().map(|dir| normalize_path(dir)).collect();
+        self
+    }
+
+    pub fn with_module_suffixes(mut self, module_suffixes: Vec<String>) -> Self {
+        self.module_suffixes = module_suffixes;
+        self
+    }
+
+    pub fn with_allow_ts_extensions(mut self, allow_ts_extensions: bool) -> Self {
+        self.allow_ts_extensions = allow_ts_extensions;
+        self
+    }
+
+    pub fn with_preserve_symlinks(mut self, preserve_symlinks: bool) -> Self {
+        self.preserve_symlinks = preserve_symlinks;
+        self
+    }
+
+    pub fn with_workspace_packages(mut self, workspace_packages: HashMap<String, PathBuf>) -> Self {
+        self.workspace_packages = workspace_packages;
+        self
+    }
+
+    pub fn for_packages(root: &Path) -> Self {
+        Self::new(root.to_path_buf()).with_pnp(PnpManifest::find(root))
+    }
+
+    pub fn with_pnp(mut self, pnp: Option<Arc<PnpManifest>>) -> Self {
+        self.pnp = pnp;
+        self
+    }
+
+    pub fn with_main_fields(mut self, main_fields: Vec<String>) -> Self {
+        self.main_fields = main_fields;
+        self
+    }
+
+    pub fn with_alias_fields(mut self, alias_fields: Vec<String>) -> Self {
+        self.alias_fields = alias_fields;
+        self
+    }
+
+    pub fn with_builtins(mut self, builtins: Builtins) -> Self {
+        self.builtins = builtins;
+        self
+    }
+
+    pub fn with_bundler_aliases(mut self, bundler_aliases: Vec<BundlerAlias>) -> Self {
+        self.bundler_aliases = bundler_aliases;
+        self
+    }
+
+    pub fn with_scope(
+        mut self,
+        dir: PathBuf,
+        include: &[String],
+        files: &[String],
+        exclude: &[String],
+        mut resolver: ModuleResolver,
+    ) -> Self {
+        resolver.stats = self.stats.clone();
+        let include = include
+            .iter()
+            .map(|pattern| tsconfig_glob(pattern))
+            .chain(files.iter().map(|file| globset::escape(file)));
+        let exclude = exclude.iter().map(|pattern| tsconfig_glob(pattern));
+
+        self.scopes.push(ResolverScope {
+            dir,
+            include: build_glob_set(include),
+            exclude: build_glob_set(exclude),
+            resolver,
+        });
+        self
+    }
+
+    pub fn clear_cache(&self) {
+        self.stats.clear();
+        self.package_aliases.clear();
+        self.resolutions.clear();
+        self.base_resolutions.clear();
+        for scope in &self.scopes {
+            scope.resolver.clear_cache();
+        }
+    }
+
+    fn scope_for(&self, from: &Path) -> Option<&ResolverScope> {
+        self.scopes
+            .iter()
+            .filter(|scope| scope.include.is_match(from) && !scope.exclude.is_match(from))
+            .fold(None, |nearest: Option<&ResolverScope>, scope| match nearest {
+                Some(nearest)
+                    if nearest.dir.components().count() >= scope.dir.components().count() =>
+                {
+                    Some(nearest)
+                }
+                _ => Some(scope),
+            })
+    }
+
+    pub fn trace(&self, specifier: &str, from: &Path) -> (Option<PathBuf>, Vec<String>) {
+        trace::collect(|| {
+            trace(|| {
+                format!(
+                    "======== Resolving module '{}' from '{}'. ========",
+                    specifier,
+                    from.display()
+                )
+            });
+            let resolved = self.resolve(specifier, from);
+            trace(|| match &resolved {
+                Some(path) => format!(
+                    "======== Module name '{}' was successfully resolved to '{}'. ========",
+                    specifier,
+                    path.display()
+                ),
+                None => format!("======== Module name '{}' was not resolved. ========", specifier),
+            });
+            resolved
+        })
+    }
+
     pub fn resolve(&self, specifier: &str, from: &Path) -> Option<PathBuf> {
+        if let Some(scope) = self.scope_for(from) {
+            trace(|| format!("Using compiler options of the tsconfig in '{}'.", scope.dir.display()));
+            return scope.resolver.resolve(specifier, from);
+        }
+
+        let specifier = strip_query(specifier);
+
+        if is_stylesheet(from) {
+            return self.resolve_stylesheet(specifier, from);
+        }
+
+        let Some(dir) = from
+            .parent()
+            .filter(|_| !trace::is_active() && !self.has_scoped_alias(specifier))
+        else {
+            return self.resolve_script(specifier, from);
+        };
+        let key = (dir.to_path_buf(), specifier.to_string());
+        self.resolutions
+            .get_or_insert_with(&key, || self.resolve_script(specifier, from))
+    }
+
+    pub fn is_disabled(&self, specifier: &str, from: &Path) -> bool {
+        if let Some(scope) = self.scope_for(from) {
+            return scope.resolver.is_disabled(specifier, from);
+        }
+        if self.alias_fields.is_empty() || is_stylesheet(from) {
+            return false;
+        }
+
+        let specifier = strip_query(specifier);
+        let target = match self.module_alias(specifier, from) {
+            Some(target) => Some(target),
+            None => self
+                .resolve_unaliased(specifier, from)
+                .and_then(|resolved| self.file_alias(&resolved))
+                .map(|(_, target)| target),
+        };
+        matches!(target, Some(AliasTarget::Disabled))
+    }
+
+    fn resolve_script(&self, specifier: &str, from: &Path) -> Option<PathBuf> {
+        if let Some(resolved) = self.resolve_bundler_alias(specifier, from) {
+            return Some(resolved);
+        }
+
+        if self.alias_fields.is_empty() {
+            return self.resolve_unaliased(specifier, from);
+        }
+
+        let resolved = match self.module_alias(specifier, from) {
+            Some(AliasTarget::Disabled) => {
+                trace(|| format!("Module '{}' is disabled by an alias field.", specifier));
+                return None;
+            }
+            Some(AliasTarget::Replace(target)) => {
+                trace(|| format!("Module '{}' is aliased to '{}'.", specifier, target));
+                let dir = self.nearest_package_dir(from)?;
+                self.resolve_alias_target(&target, &dir)?
+            }
+            None => self.resolve_unaliased(specifier, from)?,
+        };
+
+        match self.file_alias(&resolved) {
+            Some((_, AliasTarget::Disabled)) => {
+                trace(|| format!("File '{}' is disabled by an alias field.", resolved.display()));
+                None
+            }
+            Some((dir, AliasTarget::Replace(target))) => {
+                trace(|| format!("File '{}' is aliased to '{}'.", resolved.display(), target));
+                self.resolve_alias_target(&target, &dir)
+            }
+            None => Some(resolved),
+        }
+    }
+
+    fn resolve_bundler_alias(&self, specifier: &str, from: &Path) -> Option<PathBuf> {
+        for (_, targets) in self.bundler_alias_targets(specifier, from) {
+            for target in targets {
+                trace(|| format!("Module '{}' is aliased by a bundler config to '{}'.", specifier, target));
+                let target_path = Path::new(&target);
+                let resolved = if !is_stylesheet(from) {
+                    self.resolve_unaliased(&target, from)
+                } else if target_path.is_absolute() {
+                    self.try_resolve_stylesheet(target_path)
+                } else {
+                    None
+                };
+                if resolved.is_some() {
+                    return resolved;
+                }
+            }
+        }
+        None
+    }
+
+    fn bundler_alias_targets<'a>(
+        &'a self,
+        specifier: &'a str,
+        from: &'a Path,
+    ) -> impl Iterator<Item = (&'a BundlerAlias, Vec<String>)> + 'a {
+        self.bundler_aliases
+            .iter()
+            .filter(move |alias| alias.applies_to(from))
+            .filter_map(move |alias| Some((alias, alias.rewrite(specifier)?)))
+    }
+
+    fn has_scoped_alias(&self, specifier: &str) -> bool {
+        self.bundler_aliases
+            .iter()
+            .any(|alias| alias.is_scoped() && alias.rewrite(specifier).is_some())
+    }
+
+    fn module_alias(&self, specifier: &str, from: &Path) -> Option<AliasTarget> {
+        if self.alias_fields.is_empty()
+            || specifier.starts_with("./")
+            || specifier.starts_with("../")
+            || specifier.starts_with('/')
+        {
+            return None;
+        }
+        let dir = self.nearest_package_dir(from)?;
+        self.package_aliases(&dir).modules.get(specifier).cloned()
+    }
+
+    fn file_alias(&self, resolved: &Path) -> Option<(PathBuf, AliasTarget)> {
+        let dir = self.nearest_package_dir(resolved)?;
+        let target = self.package_aliases(&dir).files.get(resolved).cloned()?;
+        Some((dir, target))
+    }
+
+    fn resolve_alias_target(&self, target: &str, package_dir: &Path) -> Option<PathBuf> {
+        if target.starts_with("./") || target.starts_with("../") {
+            self.try_resolve_file(&package_dir.join(target))
+        } else {
+            self.resolve_unaliased(target, &package_dir.join("package.json"))
+        }
+    }
+
+    fn nearest_package_dir(&self, path: &Path) -> Option<PathBuf> {
+        path.ancestors()
+            .skip(1)
+            .find(|dir| self.exists(&dir.join("package.json")))
+            .map(Path::to_path_buf)
+    }
+
+    fn package_aliases(&self, package_dir: &Path) -> Arc<PackageAliases> {
+        self.package_aliases.get_or_insert_with(package_dir, || {
+            Arc::new(trace::silence(|| self.load_package_aliases(package_dir)))
+        })
+    }
+
+    fn load_package_aliases(&self, package_dir: &Path) -> PackageAliases {
+        let mut aliases = PackageAliases::default();
+        let Some(pkg) = self.read_package_json(package_dir) else {
+            return aliases;
+        };
+
+        for field in &self.alias_fields {
+            let Some(map) = pkg.get(field).and_then(|v| v.as_object()) else {
+                continue;
+            };
+            for (key, value) in map {
+                let target = match value {
+                    serde_json::Value::String(target) => AliasTarget::Replace(target.clone()),
+                    serde_json::Value::Bool(false) => AliasTarget::Disabled,
+                    _ => continue,
+                };
+                if key.starts_with("./") || key.starts_with("../") {
+                    let path = package_dir.join(key);
+                    let path = self
+                        .try_resolve_file(&path)
+                        .unwrap_or_else(|| normalize_path(&path));
+                    aliases.files.entry(path).or_insert(target);
+                } else {
+                    aliases.modules.entry(key.clone()).or_insert(target);
+                }
+            }
+        }
+
+        aliases
+    }
+
+    fn resolve_unaliased(&self, specifier: &str, from: &Path) -> Option<PathBuf> {
         if let Some(resolved) = self.resolve_path_alias(specifier) {
             return Some(resolved);
         }
@@ -51,6 +424,13 @@ impl ModuleResolver {
         }
 
         if let Some(base_url) = &self.base_url {
+            trace(|| {
+                format!(
+                    "'baseUrl' option is set to '{}', using this value to resolve non-relative module name '{}'.",
+                    base_url.display(),
+                    specifier
+                )
+            });
             if let Some(resolved) = self.resolve_from_base(specifier, base_url) {
                 return Some(resolved);
             }
@@ -59,30 +439,152 @@ impl ModuleResolver {
         self.resolve_node_modules(specifier, from)
     }
 
-    fn resolve_path_alias(&self, specifier: &str) -> Option<PathBuf> {
-        for (pattern, replacements) in &self.paths {
-            if let Some(matched) = match_path_pattern(pattern, specifier) {
-                for replacement in replacements {
-                    let resolved_path = replacement.replace('*', matched);
-                    let full_path = if let Some(base) = &self.base_url {
-                        base.join(&resolved_path)
-                    } else {
-                        self.root.join(&resolved_path)
-                    };
-
-                    if let Some(resolved) = self.try_resolve_file(&full_path) {
-                        return Some(resolved);
-                    }
+    fn resolve_stylesheet(&self, specifier: &str, from: &Path) -> Option<PathBuf> {
+        let base_dir = from.parent()?;
+
+        if specifier.starts_with("./") || specifier.starts_with("../") {
+            return self.try_resolve_stylesheet(&base_dir.join(specifier));
+        }
+
+        if let Some(resolved) = self.resolve_path_alias(specifier) {
+            return Some(resolved);
+        }
+
+        if let Some(resolved) = self.resolve_bundler_alias(specifier, from) {
+            return Some(resolved);
+        }
+
+        if let Some(resolved) = self.try_resolve_stylesheet(&base_dir.join(specifier)) {
+            return Some(resolved);
+        }
+
+        let (package_name, subpath) = parse_package_specifier(specifier);
+        let package_dir = self.find_package_dir(package_name, base_dir)?;
+        match subpath {
+            Some(subpath) => self.try_resolve_stylesheet(&package_dir.join(subpath)),
+            None => self.resolve_style_entry(&package_dir),
+        }
+    }
+
+    fn resolve_style_entry(&self, package_dir: &Path) -> Option<PathBuf> {
+        let pkg = self.read_package_json(package_dir);
+
+        for field in ["sass", "style"] {
+            if let Some(entry) = pkg.as_ref().and_then(|p| p.get(field)).and_then(|v| v.as_str()) {
+                if let Some(resolved) = self.try_resolve_stylesheet(&package_dir.join(entry)) {
+                    return Some(resolved);
                 }
             }
         }
+
+        self.try_resolve_stylesheet(package_dir)
+            .or_else(|| self.resolve_package_entry(package_dir, None))
+    }
+
+    fn try_resolve_stylesheet(&self, path: &Path) -> Option<PathBuf> {
+        let path = &normalize_path(path);
+        if self.is_file(path) {
+            return Some(path.to_path_buf());
+        }
+
+        let name = path.file_name()?.to_string_lossy();
+        let mut candidates = Vec::new();
+        for ext in STYLESHEET_EXTENSIONS {
+            candidates.push(path.with_file_name(format!("{}.{}", name, ext)));
+            candidates.push(path.with_file_name(format!("_{}.{}", name, ext)));
+        }
+        for ext in STYLESHEET_EXTENSIONS {
+            candidates.push(path.join(format!("index.{}", ext)));
+            candidates.push(path.join(format!("_index.{}", ext)));
+        }
+
+        candidates.into_iter().find(|candidate| self.is_file(candidate))
+    }
+
+    fn resolve_path_alias(&self, specifier: &str) -> Option<PathBuf> {
+        if !self.paths.is_empty() {
+            trace(|| {
+                format!(
+                    "'paths' option is specified, looking for a pattern to match module name '{}'.",
+                    specifier
+                )
+            });
+        }
+        let (pattern, replacements, matched) = self.match_paths(specifier)?;
+        trace(|| format!("Module name '{}', matched pattern '{}'.", specifier, pattern));
+        for replacement in replacements {
+            let resolved_path = replacement.replacen('*', matched, 1);
+            let full_path = if let Some(base) = &self.base_url {
+                base.join(&resolved_path)
+            } else {
+                self.root.join(&resolved_path)
+            };
+            trace(|| {
+                format!(
+                    "Trying substitution '{}', candidate module location: '{}'.",
+                    replacement,
+                    full_path.display()
+                )
+            });
+
+            if let Some(resolved) = self.try_resolve_file(&full_path) {
+                return Some(resolved);
+            }
+        }
         None
     }
 
+    fn match_paths<'a>(&self, specifier: &'a str) -> Option<(&str, &[String], &'a str)> {
+        if let Some((pattern, replacements)) = self.paths.get_key_value(specifier) {
+            return Some((pattern, replacements, ""));
+        }
+
+        self.paths
+            .iter()
+            .filter_map(|(pattern, replacements)| {
+                let (prefix, suffix) = pattern.split_once('*')?;
+                let matched = match_path_pattern(pattern, specifier)?;
+                let specificity = (prefix.len(), suffix.len(), Reverse(pattern));
+                Some((specificity, replacements.as_slice(), matched))
+            })
+            .max_by(|a, b| a.0.cmp(&b.0))
+            .map(|((_, _, Reverse(pattern)), replacements, matched)| {
+                (pattern.as_str(), replacements, matched)
+            })
+    }
+
     fn resolve_relative(&self, specifier: &str, from: &Path) -> Option<PathBuf> {
         let base_dir = from.parent()?;
         let target = base_dir.join(specifier);
+        trace(|| {
+            format!(
+                "Loading module as file / folder, candidate module location '{}'.",
+                normalize_path(&target).display()
+            )
+        });
         self.try_resolve_file(&target)
+            .or_else(|| self.resolve_root_dirs(&target))
+    }
+
+    fn resolve_root_dirs(&self, target: &Path) -> Option<PathBuf> {
+        let target = normalize_path(target);
+        let relative = self
+            .root_dirs
+            .iter()
+            .filter_map(|dir| target.strip_prefix(dir).ok())
+            .min_by_key(|relative| relative.components().count())?;
+        trace(|| {
+            format!(
+                "'rootDirs' option is set, using it to resolve relative module name '{}'.",
+                relative.display()
+            )
+        });
+
+        self.root_dirs
+            .iter()
+            .map(|dir| dir.join(relative))
+            .filter(|candidate| *candidate != target)
+            .find_map(|candidate| self.try_resolve_file(&candidate))
     }
 
     fn resolve_absolute(&self, specifier: &str) -> Option<PathBuf> {
@@ -92,23 +594,152 @@ impl ModuleResolver {
 
     fn resolve_from_base(&self, specifier: &str, base_url: &Path) -> Option<PathBuf> {
         let target = base_url.join(specifier);
-        self.try_resolve_file(&target)
+        if trace::is_active() {
+            trace(|| {
+                format!(
+                    "Resolving module name '{}' relative to base url '{}' - '{}'.",
+                    specifier,
+                    base_url.display(),
+                    target.display()
+                )
+            });
+            return self.try_resolve_file(&target);
+        }
+        self.base_resolutions
+            .get_or_insert_with(specifier, || self.try_resolve_file(&target))
     }
 
     fn resolve_node_modules(&self, specifier: &str, from: &Path) -> Option<PathBuf> {
+        let (package_name, subpath) = parse_package_specifier(specifier);
+        if let Some(package_dir) = self.resolve_pnp_package(package_name, from) {
+            return self.resolve_package_entry(&package_dir, subpath);
+        }
+
+        trace(|| format!("Loading module '{}' from 'node_modules' folder.", specifier));
         let mut current = from.parent()?;
 
         loop {
             let node_modules = current.join("node_modules");
-            if node_modules.is_dir() {
-                let (package_name, subpath) = parse_package_specifier(specifier);
-                let package_dir = node_modules.join(package_name);
-
-                if package_dir.is_dir() {
-                    if let Some(entry) = self.resolve_package_entry(&package_dir, subpath) {
-                        return Some(entry);
-                    }
+            let package_dir = node_modules.join(package_name);
+            if self.stat(&node_modules) != FileKind::Dir {
+                trace(|| {
+                    format!(
+                        "Directory '{}' does not exist, skipping all lookups in it.",
+                        node_modules.display()
+                    )
+                });
+            } else if self.stat(&package_dir) != FileKind::Dir {
+                trace(|| format!("Directory '{}' does not exist.", package_dir.display()));
+            } else {
+                trace(|| format!("Found package directory '{}'.", package_dir.display()));
+                if let Some(workspace_dir) = self.linked_workspace(package_name, &package_dir) {
+                    trace(|| {
+                        format!(
+                            "Package directory '{}' links to workspace '{}'.",
+                            package_dir.display(),
+                            workspace_dir.display()
+                        )
+                    });
+                    return self.resolve_package_entry(workspace_dir, subpath);
                 }
+                if let Some(entry) = self.resolve_package_entry(&package_dir, subpath) {
+                    return Some(self.real_path(entry));
+                }
+            }
+
+            match current.parent() {
+                Some(parent) => current = parent,
+                None => return None,
+            }
+        }
+    }
+
+    fn resolve_pnp_package(&self, package_name: &str, from: &Path) -> Option<PathBuf> {
+        let package_dir = self.pnp.as_ref()?.resolve_package_dir(package_name, from)?;
+        trace(|| {
+            format!(
+                "Plug'n'Play maps package '{}' to '{}'.",
+                package_name,
+                package_dir.display()
+            )
+        });
+        self.is_dir(&package_dir).then_some(package_dir)
+    }
+
+    pub fn is_file(&self, path: &Path) -> bool {
+        let is_file = self.exists(path);
+        trace(|| match is_file {
+            true => format!("File '{}' exists - use it as a name resolution result.", path.display()),
+            false => format!("File '{}' does not exist.", path.display()),
+        });
+        is_file
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        self.pnp
+            .as_ref()
+            .and_then(|pnp| pnp.is_file(path))
+            .unwrap_or_else(|| self.stat(path) == FileKind::File)
+    }
+
+    fn is_dir(&self, path: &Path) -> bool {
+        self.pnp
+            .as_ref()
+            .and_then(|pnp| pnp.is_dir(path))
+            .unwrap_or_else(|| self.stat(path) == FileKind::Dir)
+    }
+
+    fn stat(&self, path: &Path) -> FileKind {
+        self.stats.get_or_insert_with(path, || FileKind::of(path))
+    }
+
+    pub fn read_package_json(&self, package_dir: &Path) -> Option<serde_json::Value> {
+        let path = package_dir.join("package.json");
+        let content = match self.pnp.as_ref().and_then(|pnp| pnp.read_to_string(&path)) {
+            Some(content) => content,
+            None => std::fs::read_to_string(&path),
+        };
+        serde_json::from_str(&content.ok()?).ok()
+    }
+
+    fn linked_workspace(&self, package_name: &str, package_dir: &Path) -> Option<&Path> {
+        if self.preserve_symlinks {
+            return None;
+        }
+        let workspace_dir = self.workspace_packages.get(package_name)?;
+        let linked = package_dir.canonicalize().ok()?;
+        (linked == *workspace_dir || workspace_dir.canonicalize().ok()? == linked)
+            .then_some(workspace_dir.as_path())
+    }
+
+    fn real_path(&self, path: PathBuf) -> PathBuf {
+        if self.preserve_symlinks {
+            return path;
+        }
+        let real = path.canonicalize().unwrap_or_else(|_| path.clone());
+        if real != path {
+            trace(|| {
+                format!(
+                    "Resolving real path for '{}', result '{}'.",
+                    path.display(),
+                    real.display()
+                )
+            });
+        }
+        real
+    }
+
+    pub fn find_package_dir(&self, package_name: &str, from_dir: &Path) -> Option<PathBuf> {
+        if let Some(package_dir) = self.resolve_pnp_package(package_name, from_dir) {
+            return Some(package_dir);
+        }
+
+        let mut current = from_dir;
+
+        loop {
+            let package_dir = current.join("node_modules").join(package_name);
+            if self.stat(&package_dir) == FileKind::Dir {
+                return Some(package_dir);
             }
 
             current = current.parent()?;
@@ -121,65 +752,124 @@ impl ModuleResolver {
             return self.try_resolve_file(&target);
         }
 
-        let pkg_json_path = package_dir.join("package.json");
-        if pkg_json_path.exists() {
-            if let Ok(content) = std::fs::read_to_string(&pkg_json_path) {
-                if let Ok(pkg) = serde_json::from_str::<serde_json::Value>(&content) {
-                    for field in ["module", "main", "types"] {
-                        if let Some(entry) = pkg.get(field).and_then(|v| v.as_str()) {
-                            let entry_path = package_dir.join(entry);
-                            if let Some(resolved) = self.try_resolve_file(&entry_path) {
-                                return Some(resolved);
-                            }
-                        }
+        if let Some(pkg) = self.read_package_json(package_dir) {
+            for field in &self.main_fields {
+                if let Some(entry) = pkg.get(field).and_then(|v| v.as_str()) {
+                    let entry_path = package_dir.join(entry);
+                    trace(|| {
+                        format!(
+                            "'package.json' has '{}' field '{}' that references '{}'.",
+                            field,
+                            entry,
+                            normalize_path(&entry_path).display()
+                        )
+                    });
+                    if let Some(resolved) = self.try_resolve_file(&entry_path) {
+                        return Some(resolved);
                     }
                 }
             }
         }
 
-        for index in INDEX_FILES {
-            let index_path = package_dir.join(index);
-            if index_path.exists() {
-                return Some(index_path);
-            }
-        }
-
-        None
+        self.try_resolve_index(package_dir)
     }
 
     fn try_resolve_file(&self, path: &Path) -> Option<PathBuf> {
-        if path.is_file() {
+        let path = &normalize_path(path);
+        let name = path.file_name()?.to_string_lossy();
+
+        if let Some((stem, ext, substitutes)) = self.extension_substitutes(&name) {
+            for suffix in self.suffixes() {
+                for ext in std::iter::once(&ext).chain(substitutes) {
+                    let candidate = path.with_file_name(format!("{}{}.{}", stem, suffix, ext));
+                    if self.is_file(&candidate) {
+                        return Some(candidate);
+                    }
+                }
+            }
+        }
+
+        if self.is_file(path) {
             return Some(path.to_path_buf());
         }
 
-        for ext in EXTENSIONS {
-            let with_ext = path.with_extension(ext.trim_start_matches('.'));
-            if with_ext.is_file() {
-                return Some(with_ext);
+        for suffix in self.suffixes() {
+            for ext in EXTENSIONS.iter().chain(&[".d.ts"]) {
+                let candidate = path.with_file_name(format!("{}{}{}", name, suffix, ext));
+                if self.is_file(&candidate) {
+                    return Some(candidate);
+                }
             }
         }
 
-        let path_str = path.to_string_lossy();
-        for ext in EXTENSIONS {
-            let with_ext = PathBuf::from(format!("{}{}", path_str, ext));
-            if with_ext.is_file() {
-                return Some(with_ext);
-            }
+        if self.is_dir(path) {
+            return self.try_resolve_index(path);
         }
 
-        if path.is_dir() {
+        None
+    }
+
+    fn try_resolve_index(&self, dir: &Path) -> Option<PathBuf> {
+        for suffix in self.suffixes() {
             for index in INDEX_FILES {
-                let index_path = path.join(index);
-                if index_path.exists() {
-                    return Some(index_path);
+                let (stem, ext) = index.split_once('.')?;
+                let candidate = dir.join(format!("{}{}.{}", stem, suffix, ext));
+                if self.is_file(&candidate) {
+                    return Some(candidate);
                 }
             }
         }
-
         None
     }
 
-    pub fn is_external(&self, specifier: &str) -> bool {
+    /// Splits `name` into stem, extension and the extensions to try in its place.
+    fn extension_substitutes<'a>(
+        &self,
+        name: &'a str,
+    ) -> Option<(&'a str, &'a str, &'static [&'static str])> {
+        let (stem, ext) = name.rsplit_once('.')?;
+        let allow_ts = self.allow_ts_extensions && !stem.is_empty() && !stem.ends_with(".d");
+        let substitutes: &[&str] = match ext {
+            "js" => &["ts", "tsx", "jsx", "d.ts"],
+            "jsx" => &["tsx", "d.ts"],
+            "mjs" => &["mts", "d.mts"],
+            "cjs" => &["cts", "d.cts"],
+            "ts" | "tsx" | "mts" | "cts" if allow_ts => &[],
+            _ => return None,
+        };
+        (!stem.is_empty()).then_some((stem, ext, substitutes))
+    }
+
+    fn suffixes(&self) -> &[String] {
+        if self.module_suffixes.is_empty() {
+            NO_SUFFIXES
+        } else {
+            &self.module_suffixes
+        }
+    }
+
+    pub fn is_builtin(&self, specifier: &str) -> bool {
+        self.builtins.contains(specifier)
+    }
+
+    pub fn is_external(&self, specifier: &str, from: &Path) -> bool {
+        if let Some(scope) = self.scope_for(from) {
+            return scope.resolver.is_external(specifier, from);
+        }
+
+        if let Some(AliasTarget::Replace(target)) = self.module_alias(specifier, from) {
+            if target.starts_with("./") || target.starts_with("../") {
+                return false;
+            }
+        }
+
+        if self
+            .bundler_alias_targets(specifier, from)
+            .any(|(_, targets)| targets.iter().any(|target| Path::new(target).is_absolute()))
+        {
+            return false;
+        }
+
         if specifier.starts_with("./") || specifier.starts_with("../") || specifier.starts_with('/')
         {
             return false;
@@ -211,18 +901,64 @@ impl ModuleResolver {
         let (package_name, _) = parse_package_specifier(specifier);
         Some(package_name)
     }
+
+    pub fn get_types_package_name(package_name: &str) -> String {
+        match package_name
+            .strip_prefix('@')
+            .and_then(|scoped| scoped.split_once('/'))
+        {
+            Some((scope, package)) => format!("@types/{}__{}", scope, package),
+            None => format!("@types/{}", package_name),
+        }
+    }
 }
 
-fn match_path_pattern<'a>(pattern: &str, specifier: &'a str) -> Option<&'a str> {
-    if pattern.contains('*') {
-        let prefix = pattern.split('*').next()?;
-        if specifier.starts_with(prefix) {
-            return Some(&specifier[prefix.len()..]);
+fn tsconfig_glob(pattern: &str) -> String {
+    let last = pattern.rsplit('/').next().unwrap_or(pattern);
+    if last.contains(['*', '?', '.']) {
+        pattern.to_string()
+    } else {
+        format!("{}/**/*", pattern.trim_end_matches('/'))
+    }
+}
+
+pub(super) fn build_glob_set(patterns: impl Iterator<Item = String>) -> GlobSet {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        if let Ok(glob) = GlobBuilder::new(&pattern).literal_separator(true).build() {
+            builder.add(glob);
         }
-    } else if pattern == specifier {
-        return Some("");
     }
-    None
+    builder.build().unwrap_or_else(|_| GlobSet::empty())
+}
+
+pub(crate) fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    normalized.push(component);
+                }
+            }
+            _ => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+fn strip_query(specifier: &str) -> &str {
+    specifier
+        .find('?')
+        .map_or(specifier, |i| &specifier[..i])
+}
+
+fn match_path_pattern<'a>(pattern: &str, specifier: &'a str) -> Option<&'a str> {
+    match pattern.split_once('*') {
+        Some((prefix, suffix)) => specifier.strip_prefix(prefix)?.strip_suffix(suffix),
+        None => (pattern == specifier).then_some(""),
+    }
 }
 
 fn parse_package_specifier(specifier: &str) -> (&str, Option<&str>) {
@@ -256,6 +992,15 @@ fn parse_package_specifier(specifier: &str) -> (&str, Option<&str>) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_utils::write_tree;
+
+    #[test]
+    fn test_normalize_path() {
+        assert_eq!(
+            normalize_path(Path::new("/project/docs/../src/./Button")),
+            PathBuf::from("/project/src/Button")
+        );
+    }
 
     #[test]
     fn test_parse_package_specifier_simple() {
@@ -300,11 +1045,70 @@ mod tests {
     #[test]
     fn test_is_external() {
         let resolver = ModuleResolver::new(PathBuf::from("/project"));
-        assert!(resolver.is_external("lodash"));
-        assert!(resolver.is_external("react"));
-        assert!(resolver.is_external("@types/node"));
-        assert!(!resolver.is_external("./utils"));
-        assert!(!resolver.is_external("../lib"));
+        let from = Path::new("/project/src/index.ts");
+        assert!(resolver.is_external("lodash", from));
+        assert!(resolver.is_external("react", from));
+        assert!(resolver.is_external("@types/node", from));
+        assert!(!resolver.is_external("./utils", from));
+        assert!(!resolver.is_external("../lib", from));
+    }
+
+    #[test]
+    fn test_find_package_dir_walks_up() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = temp.path().to_path_buf();
+        std::fs::create_dir_all(root.join("node_modules/@scope/pkg")).unwrap();
+        std::fs::create_dir_all(root.join("packages/app/src")).unwrap();
+
+        let resolver = ModuleResolver::new(root.clone());
+        let found = resolver.find_package_dir("@scope/pkg", &root.join("packages/app/src"));
+        assert_eq!(found, Some(root.join("node_modules/@scope/pkg")));
+        assert!(resolver.find_package_dir("missing", &root).is_none());
+    }
+
+    #[test]
+    fn test_resolve_stylesheets_and_assets() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = temp.path().to_path_buf();
+        write_tree(
+            &root,
+            &[
+                ("src/styles/main.scss", ""),
+                ("src/styles/_variables.scss", ""),
+                ("src/styles/mixins/_index.scss", ""),
+                ("src/logo.svg", ""),
+                ("node_modules/bootstrap/scss/_grid.scss", ""),
+                ("node_modules/theme/dist/theme.css", ""),
+            ],
+        );
+        std::fs::write(
+            root.join("node_modules/theme/package.json"),
+            r#"{"style": "dist/theme.css"}"#,
+        )
+        .unwrap();
+
+        let resolver = ModuleResolver::new(root.clone());
+        let main = root.join("src/styles/main.scss");
+        assert_eq!(
+            resolver.resolve("variables", &main),
+            Some(root.join("src/styles/_variables.scss"))
+        );
+        assert_eq!(
+            resolver.resolve("./mixins", &main),
+            Some(root.join("src/styles/mixins/_index.scss"))
+        );
+        assert_eq!(
+            resolver.resolve("bootstrap/scss/grid", &main),
+            Some(root.join("node_modules/bootstrap/scss/_grid.scss"))
+        );
+        assert_eq!(
+            resolver.resolve("theme", &main),
+            Some(root.join("node_modules/theme/dist/theme.css"))
+        );
+        assert_eq!(
+            resolver.resolve("./logo.svg?react", &root.join("src/App.tsx")),
+            Some(root.join("src/logo.svg"))
+        );
     }
 
     #[test]
@@ -314,4 +1118,311 @@ mod tests {
         assert_eq!(ModuleResolver::get_package_name("@types/node"), Some("@types/node"));
         assert_eq!(ModuleResolver::get_package_name("./utils"), None);
     }
+
+    #[test]
+    fn test_typescript_extension_substitution() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = temp.path().to_path_buf();
+        let files = [
+            "src/util.ts",
+            "src/util.js",
+            "src/view.tsx",
+            "src/esm.mts",
+            "src/common.cts",
+            "src/types.d.ts",
+            "src/legacy.js",
+            "src/legacy.d.ts",
+            "src/button.ios.ts",
+            "src/button.ts",
+            "src/screens/index.native.tsx",
+        ];
+        write_tree(&root, &files.map(|file| (file, "")));
+
+        let from = root.join("src/main.ts");
+        let resolver = ModuleResolver::new(root.clone());
+        let resolve = |resolver: &ModuleResolver, specifier: &str| {
+            resolver.resolve(specifier, &from).map(|p| {
+                p.strip_prefix(&root)
+                    .unwrap()
+                    .to_string_lossy()
+                    .into_owned()
+            })
+        };
+
+        assert_eq!(
+            resolve(&resolver, "./util.js").as_deref(),
+            Some("src/util.js")
+        );
+        assert_eq!(
+            resolve(&resolver, "./view.js").as_deref(),
+            Some("src/view.tsx")
+        );
+        assert_eq!(
+            resolve(&resolver, "./view.jsx").as_deref(),
+            Some("src/view.tsx")
+        );
+        assert_eq!(
+            resolve(&resolver, "./esm.mjs").as_deref(),
+            Some("src/esm.mts")
+        );
+        assert_eq!(
+            resolve(&resolver, "./common.cjs").as_deref(),
+            Some("src/common.cts")
+        );
+        assert_eq!(
+            resolve(&resolver, "./types.js").as_deref(),
+            Some("src/types.d.ts")
+        );
+        assert_eq!(
+            resolve(&resolver, "./types").as_deref(),
+            Some("src/types.d.ts")
+        );
+        assert_eq!(
+            resolve(&resolver, "./legacy.js").as_deref(),
+            Some("src/legacy.js")
+        );
+        assert_eq!(resolve(&resolver, "./esm.js"), None);
+        assert_eq!(
+            resolve(&resolver, "./button.ts").as_deref(),
+            Some("src/button.ts")
+        );
+
+        let native = ModuleResolver::new(root.clone())
+            .with_module_suffixes(vec![".ios".into(), ".native".into(), "".into()])
+            .with_allow_ts_extensions(true);
+        assert_eq!(
+            resolve(&native, "./button").as_deref(),
+            Some("src/button.ios.ts")
+        );
+        assert_eq!(
+            resolve(&native, "./button.js").as_deref(),
+            Some("src/button.ios.ts")
+        );
+        assert_eq!(
+            resolve(&native, "./button.ts").as_deref(),
+            Some("src/button.ios.ts")
+        );
+        assert_eq!(resolve(&native, "./util").as_deref(), Some("src/util.ts"));
+        assert_eq!(
+            resolve(&native, "./screens").as_deref(),
+            Some("src/screens/index.native.tsx")
+        );
+    }
+
+    #[test]
+    fn test_paths_pick_most_specific_pattern() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = temp.path().to_path_buf();
+        let files = [
+            "src/ui/button.ts",
+            "src/ui/forms/input.ts",
+            "legacy/ui/forms/select.ts",
+            "assets/icons/logo.svg",
+            "generated/src/api.ts",
+        ];
+        write_tree(&root, &files.map(|file| (file, "")));
+
+        let paths = HashMap::from([
+            ("@ui/*".to_string(), vec!["src/ui/*".to_string()]),
+            (
+                "@ui/forms/*".to_string(),
+                vec![
+                    "src/ui/forms/*".to_string(),
+                    "legacy/ui/forms/*".to_string(),
+                ],
+            ),
+            (
+                "@icons/*.svg".to_string(),
+                vec!["assets/icons/*.svg".to_string()],
+            ),
+        ]);
+        let resolver = ModuleResolver::new(root.clone())
+            .with_paths(paths)
+            .with_root_dirs(vec![root.join("src"), root.join("generated/src")]);
+        let from = root.join("src/main.ts");
+
+        assert_eq!(
+            resolver.resolve("@ui/button", &from),
+            Some(root.join("src/ui/button.ts"))
+        );
+        assert_eq!(
+            resolver.resolve("@ui/forms/input", &from),
+            Some(root.join("src/ui/forms/input.ts"))
+        );
+        assert_eq!(
+            resolver.resolve("@ui/forms/select", &from),
+            Some(root.join("legacy/ui/forms/select.ts"))
+        );
+        assert_eq!(
+            resolver.resolve("@icons/logo.svg", &from),
+            Some(root.join("assets/icons/logo.svg"))
+        );
+        assert_eq!(resolver.resolve("@icons/logo.png", &from), None);
+        assert_eq!(
+            resolver.resolve("./api", &from),
+            Some(root.join("generated/src/api.ts"))
+        );
+        assert_eq!(match_path_pattern("@icons/*.svg", "@icons/.svg"), Some(""));
+        assert_eq!(match_path_pattern("a*ab", "ab"), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_resolve_through_pnpm_and_workspace_symlinks() {
+        use std::os::unix::fs::symlink;
+
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = temp.path().canonicalize().unwrap();
+        write_tree(
+            &root,
+            &[
+                ("node_modules/.pnpm/lodash@4.17.21/node_modules/lodash/index.js", ""),
+                ("packages/ui/index.ts", ""),
+                ("packages/forms/index.ts", ""),
+                ("packages/app/src/main.ts", ""),
+            ],
+        );
+        std::fs::create_dir_all(root.join("packages/app/node_modules/@acme")).unwrap();
+        symlink(
+            root.join("node_modules/.pnpm/lodash@4.17.21/node_modules/lodash"),
+            root.join("packages/app/node_modules/lodash"),
+        )
+        .unwrap();
+        symlink(
+            root.join("packages/ui"),
+            root.join("packages/app/node_modules/@acme/ui"),
+        )
+        .unwrap();
+
+        let workspaces = HashMap::from([
+            ("@acme/ui".to_string(), root.join("packages/ui")),
+            ("@acme/forms".to_string(), root.join("packages/forms")),
+        ]);
+        let resolver = ModuleResolver::new(root.clone()).with_workspace_packages(workspaces.clone());
+        let from = root.join("packages/app/src/main.ts");
+
+        assert_eq!(
+            resolver.resolve("lodash", &from),
+            Some(root.join("node_modules/.pnpm/lodash@4.17.21/node_modules/lodash/index.js"))
+        );
+        assert_eq!(
+            resolver.resolve("@acme/ui", &from),
+            Some(root.join("packages/ui/index.ts"))
+        );
+        assert_eq!(resolver.resolve("@acme/forms", &from), None);
+
+        let resolver = ModuleResolver::new(root.clone())
+            .with_workspace_packages(workspaces)
+            .with_preserve_symlinks(true);
+        assert_eq!(
+            resolver.resolve("lodash", &from),
+            Some(root.join("packages/app/node_modules/lodash/index.js"))
+        );
+        assert_eq!(
+            resolver.resolve("@acme/ui", &from),
+            Some(root.join("packages/app/node_modules/@acme/ui/index.ts"))
+        );
+    }
+
+    #[test]
+    fn test_resolution_cache_and_trace() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = temp.path().to_path_buf();
+        std::fs::create_dir_all(root.join("src/ui")).unwrap();
+        std::fs::write(root.join("src/ui/button.tsx"), "").unwrap();
+
+        let paths = HashMap::from([("@ui/*".to_string(), vec!["src/ui/*".to_string()])]);
+        let resolver = ModuleResolver::new(root.clone()).with_paths(paths);
+        let from = root.join("src/main.ts");
+
+        assert_eq!(resolver.resolve("./util", &from), None);
+        std::fs::write(root.join("src/util.ts"), "").unwrap();
+        assert_eq!(resolver.resolve("./util", &from), None);
+        resolver.clear_cache();
+        assert_eq!(
+            resolver.resolve("./util", &from),
+            Some(root.join("src/util.ts"))
+        );
+
+        let (resolved, lines) = resolver.trace("@ui/button", &from);
+        assert_eq!(resolved, Some(root.join("src/ui/button.tsx")));
+        assert!(lines[0].starts_with("======== Resolving module '@ui/button' from"));
+        assert!(lines.contains(&"Module name '@ui/button', matched pattern '@ui/*'.".to_string()));
+        assert!(lines.contains(&format!(
+            "File '{}' does not exist.",
+            root.join("src/ui/button.ts").display()
+        )));
+        assert!(lines.last().unwrap().contains("was successfully resolved to"));
+
+        let (resolved, lines) = resolver.trace("missing", &from);
+        assert_eq!(resolved, None);
+        assert!(lines.contains(&"Loading module 'missing' from 'node_modules' folder.".to_string()));
+        assert_eq!(
+            lines.last().unwrap(),
+            "======== Module name 'missing' was not resolved. ========"
+        );
+    }
+
+    #[test]
+    fn test_main_fields_and_alias_fields() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = temp.path().to_path_buf();
+        write_tree(
+            &root,
+            &[
+                (
+                    "package.json",
+                    r#"{"browser": {"./src/server.ts": "./src/client.ts", "fs": false, "crypto": "./src/crypto-shim.ts"}}"#,
+                ),
+                (
+                    "node_modules/ui/package.json",
+                    r#"{"main": "lib/index.js", "react-native": "lib/native.js", "browser": {"./lib/index.js": "./lib/web.js", "./lib/node.js": false}}"#,
+                ),
+                ("src/main.ts", ""),
+                ("src/server.ts", ""),
+                ("src/client.ts", ""),
+                ("src/crypto-shim.ts", ""),
+                ("node_modules/ui/lib/index.js", ""),
+                ("node_modules/ui/lib/native.js", ""),
+                ("node_modules/ui/lib/web.js", ""),
+                ("node_modules/ui/lib/node.js", ""),
+            ],
+        );
+
+        let from = root.join("src/main.ts");
+        let node = ModuleResolver::new(root.clone());
+        assert_eq!(node.resolve("./server", &from), Some(root.join("src/server.ts")));
+        assert_eq!(
+            node.resolve("ui", &from),
+            Some(root.join("node_modules/ui/lib/index.js"))
+        );
+        assert!(!node.is_disabled("fs", &from));
+
+        let browser = ModuleResolver::new(root.clone())
+            .with_main_fields(vec!["browser".into(), "module".into(), "main".into()])
+            .with_alias_fields(vec!["browser".into()]);
+        assert_eq!(browser.resolve("./server", &from), Some(root.join("src/client.ts")));
+        assert_eq!(
+            browser.resolve("crypto", &from),
+            Some(root.join("src/crypto-shim.ts"))
+        );
+        assert!(!browser.is_external("crypto", &from));
+        assert_eq!(browser.resolve("fs", &from), None);
+        assert!(browser.is_disabled("fs", &from));
+        assert_eq!(
+            browser.resolve("ui", &from),
+            Some(root.join("node_modules/ui/lib/web.js"))
+        );
+        assert_eq!(browser.resolve("ui/lib/node", &from), None);
+        assert!(browser.is_disabled("ui/lib/node", &from));
+        assert!(!browser.is_disabled("ui/lib/missing", &from));
+
+        let native = ModuleResolver::new(root.clone())
+            .with_main_fields(vec!["react-native".into(), "browser".into(), "main".into()])
+            .with_alias_fields(vec!["react-native".into(), "browser".into()]);
+        assert_eq!(
+            native.resolve("ui", &from),
+            Some(root.join("node_modules/ui/lib/native.js"))
+        );
+    }
 }
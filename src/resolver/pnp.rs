@@ -0,0 +1,439 @@
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::Read;
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use serde_json::Value;
+
+use super::node::normalize_path;
+
+const PNP_DATA_FILE: &str = ".pnp.data.json";
+const PNP_RUNTIME_FILE: &str = ".pnp.cjs";
+const RUNTIME_STATE_MARKER: &str = "RAW_RUNTIME_STATE";
+
+type Locator = (Option<String>, Option<String>);
+
+#[derive(Debug, Clone)]
+struct PnpPackage {
+    location: PathBuf,
+    dependencies: HashMap<String, Option<Locator>>,
+}
+
+#[derive(Debug)]
+struct ZipIndex {
+    files: HashSet<String>,
+    dirs: HashSet<String>,
+    archive: Mutex<zip::ZipArchive<File>>,
+}
+
+#[derive(Debug)]
+pub struct PnpManifest {
+    packages: HashMap<Locator, PnpPackage>,
+    locations: Vec<(PathBuf, Locator)>,
+    fallback_pool: HashMap<String, Option<Locator>>,
+    enable_top_level_fallback: bool,
+    zips: Mutex<HashMap<PathBuf, Option<Arc<ZipIndex>>>>,
+}
+
+impl PnpManifest {
+    pub fn find(root: &Path) -> Option<Arc<Self>> {
+        root.ancestors().find_map(Self::load).map(Arc::new)
+    }
+
+    fn load(dir: &Path) -> Option<Self> {
+        let data = match std::fs::read_to_string(dir.join(PNP_DATA_FILE)) {
+            Ok(data) => data,
+            Err(_) => {
+                let runtime = std::fs::read_to_string(dir.join(PNP_RUNTIME_FILE)).ok()?;
+                extract_runtime_state(&runtime)?
+            }
+        };
+        let data: Value = serde_json::from_str(&data).ok()?;
+        Some(Self::from_data(&data, dir))
+    }
+
+    fn from_data(data: &Value, dir: &Path) -> Self {
+        let mut packages = HashMap::new();
+        let mut locations = Vec::new();
+
+        let registry = data.get("packageRegistryData").and_then(Value::as_array);
+        for entry in registry.into_iter().flatten() {
+            let name = entry.get(0).and_then(Value::as_str).map(str::to_string);
+            let references = entry.get(1).and_then(Value::as_array);
+
+            for store in references.into_iter().flatten() {
+                let reference = store.get(0).and_then(Value::as_str).map(str::to_string);
+                let Some(info) = store.get(1) else {
+                    continue;
+                };
+                let Some(location) = info.get("packageLocation").and_then(Value::as_str) else {
+                    continue;
+                };
+
+                let virtual_location = normalize_path(&dir.join(location));
+                let location = devirtualize(&virtual_location);
+                let dependencies = info
+                    .get("packageDependencies")
+                    .and_then(Value::as_array)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(parse_dependency)
+                    .collect();
+
+                let locator = (name.clone(), reference);
+                if location == virtual_location {
+                    locations.push((location.clone(), locator.clone()));
+                }
+                packages.insert(
+                    locator,
+                    PnpPackage {
+                        location,
+                        dependencies,
+                    },
+                );
+            }
+        }
+
+        locations.sort_by_key(|(location, _)| std::cmp::Reverse(location.components().count()));
+
+        let fallback_pool = data
+            .get("fallbackPool")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(parse_dependency)
+            .collect();
+
+        Self {
+            packages,
+            locations,
+            fallback_pool,
+            enable_top_level_fallback: data
+                .get("enableTopLevelFallback")
+                .and_then(Value::as_bool)
+                .unwrap_or(false),
+            zips: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn resolve_package_dir(&self, package_name: &str, from: &Path) -> Option<PathBuf> {
+        let issuer = self
+            .locations
+            .iter()
+            .find(|(location, _)| from.starts_with(location))
+            .map(|(_, locator)| locator)
+            .and_then(|locator| self.packages.get(locator));
+
+        let dependency = issuer
+            .and_then(|package| package.dependencies.get(package_name))
+            .or_else(|| {
+                if !self.enable_top_level_fallback {
+                    return None;
+                }
+                self.packages
+                    .get(&(None, None))
+                    .and_then(|top_level| top_level.dependencies.get(package_name))
+                    .or_else(|| self.fallback_pool.get(package_name))
+            })?;
+
+        let package = self.packages.get(dependency.as_ref()?)?;
+        Some(package.location.clone())
+    }
+
+    pub fn is_file(&self, path: &Path) -> Option<bool> {
+        let (zip, entry) = split_zip_path(path)?;
+        Some(
+            self.zip_index(&zip)
+                .is_some_and(|index| index.files.contains(&entry)),
+        )
+    }
+
+    pub fn is_dir(&self, path: &Path) -> Option<bool> {
+        let (zip, entry) = split_zip_path(path)?;
+        Some(
+            self.zip_index(&zip)
+                .is_some_and(|index| entry.is_empty() || index.dirs.contains(&entry)),
+        )
+    }
+
+    pub fn read_to_string(&self, path: &Path) -> Option<std::io::Result<String>> {
+        let (zip, entry) = split_zip_path(path)?;
+        Some(match self.zip_index(&zip) {
+            Some(index) => index.read(&entry),
+            None => Err(std::io::ErrorKind::NotFound.into()),
+        })
+    }
+
+    fn zip_index(&self, zip: &Path) -> Option<Arc<ZipIndex>> {
+        let mut zips = self.zips.lock().ok()?;
+        zips.entry(zip.to_path_buf())
+            .or_insert_with(|| index_zip(zip).map(Arc::new))
+            .clone()
+    }
+}
+
+fn parse_dependency(entry: &Value) -> Option<(String, Option<Locator>)> {
+    let name = entry.get(0)?.as_str()?.to_string();
+    let locator = match entry.get(1)? {
+        Value::String(reference) => Some((Some(name.clone()), Some(reference.clone()))),
+        Value::Array(alias) => {
+            let alias_name = alias.first()?.as_str()?.to_string();
+            let reference = alias.get(1)?.as_str()?.to_string();
+            Some((Some(alias_name), Some(reference)))
+        }
+        _ => None,
+    };
+    Some((name, locator))
+}
+
+fn devirtualize(path: &Path) -> PathBuf {
+    let components: Vec<Component> = path.components().collect();
+    let Some(index) = components
+        .iter()
+        .position(|component| component.as_os_str() == "__virtual__")
+    else {
+        return path.to_path_buf();
+    };
+
+    let depth = components
+        .get(index + 2)
+        .and_then(|component| component.as_os_str().to_str())
+        .and_then(|depth| depth.parse::<usize>().ok());
+    let Some(depth) = depth else {
+        return path.to_path_buf();
+    };
+
+    let base: PathBuf = components[..index].iter().collect();
+    let Some(base) = base.ancestors().nth(depth) else {
+        return path.to_path_buf();
+    };
+    base.join(components[index + 3..].iter().collect::<PathBuf>())
+}
+
+fn split_zip_path(path: &Path) -> Option<(PathBuf, String)> {
+    let mut zip = PathBuf::new();
+    let mut components = path.components();
+
+    for component in components.by_ref() {
+        zip.push(component);
+        if component.as_os_str().to_string_lossy().ends_with(".zip") {
+            let entry: Vec<String> = components
+                .map(|c| c.as_os_str().to_string_lossy().into_owned())
+                .collect();
+            return Some((zip, entry.join("/")));
+        }
+    }
+
+    None
+}
+
+impl ZipIndex {
+    fn read(&self, entry: &str) -> std::io::Result<String> {
+        let mut archive = self
+            .archive
+            .lock()
+            .map_err(|_| std::io::Error::other("zip archive lock poisoned"))?;
+        let mut file = archive.by_name(entry)?;
+        let mut content = String::new();
+        file.read_to_string(&mut content)?;
+        Ok(content)
+    }
+}
+
+fn index_zip(zip: &Path) -> Option<ZipIndex> {
+    let archive = zip::ZipArchive::new(File::open(zip).ok()?).ok()?;
+    let mut files = HashSet::new();
+    let mut dirs = HashSet::new();
+
+    for name in archive.file_names() {
+        let name = name.trim_end_matches('/');
+        let mut parent = name;
+        while let Some((dir, _)) = parent.rsplit_once('/') {
+            if !dirs.insert(dir.to_string()) {
+                break;
+            }
+            parent = dir;
+        }
+        files.insert(name.to_string());
+    }
+
+    files.retain(|file| !dirs.contains(file));
+    Some(ZipIndex {
+        files,
+        dirs,
+        archive: Mutex::new(archive),
+    })
+}
+
+fn extract_runtime_state(runtime: &str) -> Option<String> {
+    let start = runtime.find(RUNTIME_STATE_MARKER)?;
+    let rest = &runtime[start + RUNTIME_STATE_MARKER.len()..];
+    let rest = rest.trim_start().strip_prefix('=')?.trim_start();
+    let quote = rest
+        .chars()
+        .next()
+        .filter(|c| matches!(c, '\'' | '"' | '`'))?;
+
+    let mut value = String::new();
+    let mut chars = rest[1..].chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next()? {
+                '\n' => {}
+                'n' => value.push('\n'),
+                'r' => value.push('\r'),
+                't' => value.push('\t'),
+                'u' => {
+                    let hex: String = chars.by_ref().take(4).collect();
+                    value.push(char::from_u32(u32::from_str_radix(&hex, 16).ok()?)?);
+                }
+                'x' => {
+                    let hex: String = chars.by_ref().take(2).collect();
+                    value.push(char::from_u32(u32::from_str_radix(&hex, 16).ok()?)?);
+                }
+                escaped => value.push(escaped),
+            },
+            c if c == quote => return Some(value),
+            c => value.push(c),
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn write_zip(path: &Path, files: &[(&str, &str)]) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        let mut zip = zip::ZipWriter::new(File::create(path).unwrap());
+        for (name, content) in files {
+            zip.start_file(*name, zip::write::SimpleFileOptions::default())
+                .unwrap();
+            zip.write_all(content.as_bytes()).unwrap();
+        }
+        zip.finish().unwrap();
+    }
+
+    #[test]
+    fn test_extract_runtime_state() {
+        let runtime = "#!/usr/bin/env node\n/* eslint-disable */\n\"use strict\";\n\nconst RAW_RUNTIME_STATE =\n'{\\\n  \"__info\": [\"don\\'t edit\"],\\\n  \"enableTopLevelFallback\": true\\\n}';\n";
+        let state = extract_runtime_state(runtime).unwrap();
+        let data: Value = serde_json::from_str(&state).unwrap();
+        assert_eq!(data["__info"][0], "don't edit");
+        assert_eq!(data["enableTopLevelFallback"], true);
+    }
+
+    #[test]
+    fn test_resolve_packages_from_zip_cache() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = temp.path().to_path_buf();
+        let zip = root.join(".yarn/cache/react-npm-18.2.0-abc.zip");
+        write_zip(
+            &zip,
+            &[
+                ("node_modules/react/package.json", r#"{"main": "index.js"}"#),
+                ("node_modules/react/index.js", ""),
+            ],
+        );
+
+        let data = serde_json::json!({
+            "enableTopLevelFallback": true,
+            "fallbackPool": [],
+            "packageRegistryData": [
+                [null, [[null, {
+                    "packageLocation": "./",
+                    "packageDependencies": [["react", "npm:18.2.0"], ["ui", "workspace:packages/ui"]]
+                }]]],
+                ["react", [["npm:18.2.0", {
+                    "packageLocation": "./.yarn/cache/react-npm-18.2.0-abc.zip/node_modules/react/",
+                    "packageDependencies": [["react", "npm:18.2.0"]]
+                }]]],
+                ["ui", [
+                    ["workspace:packages/ui", {
+                        "packageLocation": "./packages/ui/",
+                        "packageDependencies": [["ui", "workspace:packages/ui"], ["react", null]]
+                    }],
+                    ["virtual:abc#workspace:packages/ui", {
+                        "packageLocation": "./.yarn/__virtual__/ui-virtual-abc/1/packages/ui/",
+                        "packageDependencies": [["react", "npm:18.2.0"]]
+                    }]
+                ]]
+            ]
+        });
+        let pnp = PnpManifest::from_data(&data, &root);
+        let app = root.join("src/main.ts");
+
+        let react = pnp.resolve_package_dir("react", &app).unwrap();
+        assert_eq!(react, zip.join("node_modules/react"));
+        assert_eq!(pnp.is_dir(&react), Some(true));
+        assert_eq!(pnp.is_file(&react.join("index.js")), Some(true));
+        assert_eq!(pnp.is_file(&react.join("index.ts")), Some(false));
+        assert_eq!(
+            pnp.read_to_string(&react.join("package.json"))
+                .unwrap()
+                .unwrap(),
+            r#"{"main": "index.js"}"#
+        );
+        assert_eq!(pnp.is_file(&app), None);
+
+        assert_eq!(
+            pnp.resolve_package_dir("ui", &app),
+            Some(root.join("packages/ui"))
+        );
+        assert_eq!(
+            pnp.resolve_package_dir("react", &root.join("packages/ui/index.ts")),
+            None
+        );
+        assert_eq!(pnp.resolve_package_dir("lodash", &app), None);
+
+        let resolver =
+            crate::resolver::ModuleResolver::new(root.clone()).with_pnp(Some(Arc::new(pnp)));
+        assert_eq!(
+            resolver.resolve("react", &app),
+            Some(zip.join("node_modules/react/index.js"))
+        );
+        assert_eq!(
+            resolver.read_package_json(&react).unwrap()["main"],
+            "index.js"
+        );
+    }
+
+    #[test]
+    fn test_fallback_requires_top_level_fallback() {
+        let root = PathBuf::from("/repo");
+        let data = |enabled: bool| {
+            serde_json::json!({
+                "enableTopLevelFallback": enabled,
+                "fallbackPool": [["lodash", "npm:4.17.21"]],
+                "packageRegistryData": [
+                    [null, [[null, {
+                        "packageLocation": "./",
+                        "packageDependencies": [["ui", "workspace:packages/ui"]]
+                    }]]],
+                    ["ui", [["workspace:packages/ui", {
+                        "packageLocation": "./packages/ui/",
+                        "packageDependencies": [["ui", "workspace:packages/ui"]]
+                    }]]],
+                    ["lodash", [["npm:4.17.21", {
+                        "packageLocation": "./.yarn/unplugged/lodash/node_modules/lodash/",
+                        "packageDependencies": []
+                    }]]]
+                ]
+            })
+        };
+        let from = root.join("packages/ui/index.ts");
+
+        let strict = PnpManifest::from_data(&data(false), &root);
+        assert_eq!(strict.resolve_package_dir("lodash", &from), None);
+
+        let loose = PnpManifest::from_data(&data(true), &root);
+        assert_eq!(
+            loose.resolve_package_dir("lodash", &from),
+            Some(root.join(".yarn/unplugged/lodash/node_modules/lodash"))
+        );
+    }
+}
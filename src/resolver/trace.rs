@@ -0,0 +1,31 @@
+use std::cell::RefCell;
+
+thread_local! {
+    static TRACE: RefCell<Option<Vec<String>>> = const { RefCell::new(None) };
+}
+
+pub(super) fn is_active() -> bool {
+    TRACE.with(|trace| trace.borrow().is_some())
+}
+
+pub(super) fn trace(message: impl FnOnce() -> String) {
+    TRACE.with(|trace| {
+        if let Some(lines) = trace.borrow_mut().as_mut() {
+            lines.push(message());
+        }
+    });
+}
+
+pub(super) fn collect<R>(run: impl FnOnce() -> R) -> (R, Vec<String>) {
+    let previous = TRACE.with(|trace| trace.replace(Some(Vec::new())));
+    let result = run();
+    let lines = TRACE.with(|trace| trace.replace(previous)).unwrap_or_default();
+    (result, lines)
+}
+
+pub(super) fn silence<R>(run: impl FnOnce() -> R) -> R {
+    let previous = TRACE.with(|trace| trace.replace(None));
+    let result = run();
+    TRACE.with(|trace| trace.replace(previous));
+    result
+}
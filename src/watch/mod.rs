@@ -9,6 +9,7 @@ use notify_debouncer_mini::{new_debouncer, DebouncedEventKind};
 pub struct WatchConfig {
     pub debounce_ms: u64,
     pub extensions: Vec<String>,
+    pub file_names: Vec<String>,
 }
 
 impl Default for WatchConfig {
@@ -22,7 +23,25 @@ impl Default for WatchConfig {
                 "jsx".to_string(),
                 "mjs".to_string(),
                 "cjs".to_string(),
+                "mts".to_string(),
+                "cts".to_string(),
+                "vue".to_string(),
+                "svelte".to_string(),
+                "astro".to_string(),
+                "mdx".to_string(),
+                "css".to_string(),
+                "scss".to_string(),
+                "sass".to_string(),
+                "less".to_string(),
                 "json".to_string(),
+                "yaml".to_string(),
+                "yml".to_string(),
+            ],
+            file_names: vec![
+                ".piorrc".to_string(),
+                ".eslintrc".to_string(),
+                ".babelrc".to_string(),
+                ".prettierrc".to_string(),
             ],
         }
     }
@@ -55,19 +74,16 @@ where
 
     callback(&[])?;
 
-    loop {
-        match rx.recv() {
-            Ok(paths) => {
-                let relevant_paths: Vec<PathBuf> = paths
-                    .into_iter()
-                    .filter(|p| is_relevant_file(p, &config.extensions))
-                    .collect();
+    while let Ok(paths) = rx.recv() {
+        let relevant_paths: Vec<PathBuf> = paths
+            .into_iter()
+            .filter(|p| {
+                is_relevant_file(p, &config.extensions) || is_watched_name(p, &config.file_names)
+            })
+            .collect();
 
-                if !relevant_paths.is_empty() {
-                    callback(&relevant_paths)?;
-                }
-            }
-            Err(_) => break,
+        if !relevant_paths.is_empty() {
+            callback(&relevant_paths)?;
         }
     }
 
@@ -82,6 +98,11 @@ fn is_relevant_file(path: &Path, extensions: &[String]) -> bool {
     false
 }
 
+fn is_watched_name(path: &Path, file_names: &[String]) -> bool {
+    path.file_name()
+        .is_some_and(|name| file_names.iter().any(|n| name == n.as_str()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -103,4 +124,12 @@ mod tests {
         assert!(!is_relevant_file(Path::new("baz.rs"), &extensions));
         assert!(!is_relevant_file(Path::new("no_extension"), &extensions));
     }
+
+    #[test]
+    fn test_is_watched_name() {
+        let config = WatchConfig::default();
+
+        assert!(is_watched_name(Path::new("/project/.piorrc"), &config.file_names));
+        assert!(!is_watched_name(Path::new("/project/.npmrc"), &config.file_names));
+    }
 }
@@ -149,7 +149,7 @@ fn discover_pnpm_workspaces(root: &Path) -> Result<Option<Vec<Workspace>>> {
     }
 
     let content = fs::read_to_string(&pnpm_workspace_path)
-        .with_context(|| format!("Failed to read pnpm-workspace.yaml"))?;
+        .with_context(|| "Failed to read pnpm-workspace.yaml")?;
 
     let patterns = parse_pnpm_workspace_yaml(&content)?;
     if patterns.is_empty() {